@@ -0,0 +1,20 @@
+use serde::{Serialize, Deserialize};
+use schemars::JsonSchema;
+
+/* Kind of a Python class, classified by its decorators and superclasses */
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ClassKind {
+    /* `@dataclass` */
+    Dataclass,
+    /* `NamedTuple` subclass or `namedtuple()` */
+    NamedTuple,
+    /* `TypedDict` subclass */
+    TypedDict,
+    /* `Protocol` subclass */
+    Protocol,
+    /* `ABC` subclass or `metaclass=ABCMeta` */
+    Abstract,
+    /* `Exception` subclass */
+    Exception,
+}
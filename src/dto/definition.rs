@@ -0,0 +1,25 @@
+use serde::{Serialize, Deserialize};
+use schemars::JsonSchema;
+
+/* C/C++ function prototype or function with body, Python stub or `@overload` signatures are declarations */
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DeclarationKind {
+    Declaration,
+    Definition,
+}
+
+/* Definition paired with a declaration, possibly in another file */
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DefinitionLocation {
+    path_file: String,
+    line_code: usize,
+}
+
+impl DefinitionLocation {
+
+    pub fn new(path_file: String, line_code: usize) -> Self {
+        Self { path_file, line_code }
+    }
+}
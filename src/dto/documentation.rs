@@ -0,0 +1,94 @@
+use serde::{Serialize, Deserialize};
+use schemars::JsonSchema;
+use crate::utils::doc_comment::join_description;
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ParamDocumentation {
+    name: String,
+    description: String,
+}
+
+impl ParamDocumentation {
+
+    pub fn new(name: String, description: String) -> Self {
+        Self { name, description }
+    }
+
+    pub fn append_description(&mut self, line: &str) {
+        self.description = join_description(&self.description, line);
+    }
+}
+
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ThrowsDocumentation {
+    exception: String,
+    description: String,
+}
+
+impl ThrowsDocumentation {
+
+    pub fn new(exception: String, description: String) -> Self {
+        Self { exception, description }
+    }
+
+    pub fn append_description(&mut self, line: &str) {
+        self.description = join_description(&self.description, line);
+    }
+}
+
+/* Javadoc, Doxygen, JSDoc comment or Python docstring attached to a declaration */
+#[derive(Default, Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Documentation {
+    raw: String,
+    summary: String,
+    params: Vec<ParamDocumentation>,
+    returns: Option<String>,
+    throws: Vec<ThrowsDocumentation>,
+    deprecated: Option<String>,
+}
+
+impl Documentation {
+
+    pub fn new(raw: String) -> Self {
+        Self { raw, ..Default::default() }
+    }
+
+    pub fn set_summary(&mut self, summary: String) {
+        self.summary = summary;
+    }
+
+    pub fn mut_params(&mut self) -> &mut Vec<ParamDocumentation> {
+        &mut self.params
+    }
+
+    pub fn add_param(&mut self, param: ParamDocumentation) {
+        self.params.push(param);
+    }
+
+    pub fn returns(&self) -> &Option<String> {
+        &self.returns
+    }
+
+    pub fn set_returns(&mut self, returns: String) {
+        self.returns = Some(returns);
+    }
+
+    pub fn mut_throws(&mut self) -> &mut Vec<ThrowsDocumentation> {
+        &mut self.throws
+    }
+
+    pub fn add_throws(&mut self, throws: ThrowsDocumentation) {
+        self.throws.push(throws);
+    }
+
+    pub fn deprecated(&self) -> &Option<String> {
+        &self.deprecated
+    }
+
+    pub fn set_deprecated(&mut self, deprecated: String) {
+        self.deprecated = Some(deprecated);
+    }
+}
@@ -1,7 +1,8 @@
 use serde::Serialize;
-use crate::dto::object_description::MethodDescription;
+use schemars::JsonSchema;
+use crate::dto::object_description::{MethodDescription, Description};
 
-#[derive(Default, Debug, Serialize)]
+#[derive(Default, Debug, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct RepositoryImportDeclaration {
     package_name: String,
@@ -14,6 +15,10 @@ impl RepositoryImportDeclaration {
         Self { package_name, class_import_list: vec![] }
     }
 
+    pub fn package_name(&self) -> &String {
+        &self.package_name
+    }
+
     pub fn add_class(&mut self, class_name: String) {
         if !self.class_import_list.contains(&class_name) {
             self.class_import_list.push(class_name)
@@ -31,9 +36,12 @@ impl Eq for RepositoryImportDeclaration {}
 
 
 
-#[derive(Default, Debug, Serialize)]
+#[derive(Default, Debug, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct InvocationStructure {
+    /// Output schema version, absent in schema version 1
+    #[serde(skip_serializing_if = "Option::is_none")]
+    schema_version: Option<u32>,
     repository_import_declarations: Vec<RepositoryImportDeclaration>,
     method_descriptions: Vec<MethodDescription>,
     type_codes: Vec<String>,
@@ -44,10 +52,55 @@ impl InvocationStructure {
     pub fn new(repository_import_declarations: Vec<RepositoryImportDeclaration>,
                method_descriptions: Vec<MethodDescription>, type_codes: Vec<String>) -> Self {
         Self {
+            schema_version: None,
             repository_import_declarations,
             method_descriptions,
             type_codes,
         }
     }
 
-}
\ No newline at end of file
+    pub fn set_schema_version(&mut self, schema_version: Option<u32>) {
+        self.schema_version = schema_version;
+    }
+
+    /* Marks links which target deprecated methods of the same file */
+    pub fn mark_deprecated_links(&mut self, deprecated_methods: &[DeprecatedMethod]) {
+
+        if deprecated_methods.is_empty() {
+            return;
+        }
+
+        for link in self.method_descriptions.iter_mut() {
+            if deprecated_methods.iter().any(|method| method.is_target_of(link)) {
+                link.set_deprecated(true);
+            }
+        }
+    }
+
+}
+
+/* Deprecated method collected by invocation visitor, overloads differ by number of parameters */
+pub struct DeprecatedMethod {
+    class_name: String,
+    method_name: String,
+    /* Parameters with default values may be omitted */
+    min_count_of_params: usize,
+    /* None for variadic parameters */
+    max_count_of_params: Option<usize>,
+}
+
+impl DeprecatedMethod {
+
+    pub fn new(class_name: String, method_name: String, min_count_of_params: usize,
+               max_count_of_params: Option<usize>) -> Self {
+        Self { class_name, method_name, min_count_of_params, max_count_of_params }
+    }
+
+    fn is_target_of(&self, link: &MethodDescription) -> bool {
+        let count_of_params = link.count_param_input();
+        &self.method_name == link.method_name()
+            && &self.class_name == link.class_name()
+            && count_of_params >= self.min_count_of_params
+            && self.max_count_of_params.is_none_or(|max| count_of_params <= max)
+    }
+}
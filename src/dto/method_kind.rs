@@ -0,0 +1,17 @@
+use serde::{Serialize, Deserialize};
+use schemars::JsonSchema;
+
+/* How a Python method is bound, classified by its decorators */
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum MethodKind {
+    Instance,
+    /* `@staticmethod` */
+    Static,
+    /* `@classmethod` */
+    Class,
+    /* `@property`, `@cached_property` and `@name.setter`/`@name.deleter` accessors */
+    Property,
+    /* `@abstractmethod` */
+    Abstract,
+}
@@ -1,3 +1,11 @@
 pub mod repository_method_dto;
 pub mod object_description;
-pub mod invocation_structure;
\ No newline at end of file
+pub mod invocation_structure;
+pub mod documentation;
+pub mod visibility;
+pub mod modifier;pub mod schema;
+pub mod parse_options;
+pub mod definition;
+pub mod method_kind;
+pub mod route;
+pub mod class_kind;
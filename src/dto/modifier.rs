@@ -0,0 +1,85 @@
+use serde::{Serialize, Deserialize};
+use schemars::JsonSchema;
+
+/* Normalized declaration modifier, access modifiers are reported as visibility */
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Modifier {
+    Static,
+    Abstract,
+    Final,
+    Virtual,
+    /* C++ pure virtual `= 0` */
+    Pure,
+    Override,
+    Const,
+    Constexpr,
+    Inline,
+    Extern,
+    Explicit,
+    Noexcept,
+    Async,
+    Generator,
+    Getter,
+    Setter,
+    Synchronized,
+    Native,
+    Transient,
+    Volatile,
+    Strictfp,
+    Default,
+}
+
+impl Modifier {
+
+    /* Maps source keyword (Java, C++, JS, Python) to modifier */
+    pub fn from_keyword(keyword: &str) -> Option<Modifier> {
+        match keyword {
+            "static" => Some(Modifier::Static),
+            "abstract" => Some(Modifier::Abstract),
+            "final" => Some(Modifier::Final),
+            "virtual" => Some(Modifier::Virtual),
+            "pure" => Some(Modifier::Pure),
+            "override" | "@Override" => Some(Modifier::Override),
+            "const" => Some(Modifier::Const),
+            "constexpr" => Some(Modifier::Constexpr),
+            "inline" => Some(Modifier::Inline),
+            "extern" => Some(Modifier::Extern),
+            "explicit" => Some(Modifier::Explicit),
+            "noexcept" => Some(Modifier::Noexcept),
+            "async" => Some(Modifier::Async),
+            "*" => Some(Modifier::Generator),
+            "get" => Some(Modifier::Getter),
+            "set" => Some(Modifier::Setter),
+            "synchronized" => Some(Modifier::Synchronized),
+            "native" => Some(Modifier::Native),
+            "transient" => Some(Modifier::Transient),
+            "volatile" => Some(Modifier::Volatile),
+            "strictfp" => Some(Modifier::Strictfp),
+            "default" => Some(Modifier::Default),
+            _ => None
+        }
+    }
+
+    pub fn from_keywords(keywords: &[String]) -> Vec<Modifier> {
+        let mut modifiers = vec![];
+        for modifier in keywords.iter().filter_map(|keyword| Modifier::from_keyword(keyword)) {
+            if !modifiers.contains(&modifier) {
+                modifiers.push(modifier);
+            }
+        }
+        modifiers
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[serde(untagged)]
+pub enum Modifiers {
+    /* JSON-encoded array of source keywords, kept for existing consumers */
+    Legacy(String),
+    Normalized(Vec<Modifier>),
+}
+
+impl Default for Modifiers {
+    fn default() -> Self { Modifiers::Normalized(vec![]) }
+}
@@ -1,4 +1,5 @@
 use serde::Serialize;
+use schemars::JsonSchema;
 
 pub trait Description {
 
@@ -23,7 +24,7 @@ pub trait Description {
     fn set_position(&mut self, position: usize);
 }
 
-#[derive(Default, Debug, Serialize)]
+#[derive(Default, Debug, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct DescriptionData {
     package_name: String,
@@ -81,13 +82,21 @@ impl DescriptionData {
 
 }
 
-#[derive(Default, Debug, Serialize)]
+#[derive(Default, Debug, Serialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct MethodDescription {
     description_data: DescriptionData,
     var_name: String,
     method_name: String,
     count_param_input: usize,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    deprecated: bool,
+    /// Explicit template arguments of the call, e.g. `["Foo"]` for `make<Foo>()`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    template_arguments: Vec<String>,
+    /// Overrides in subclasses which a virtual call through pointer or reference may reach
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    dispatch_candidates: Vec<DispatchCandidate>,
 }
 
 
@@ -99,10 +108,21 @@ impl MethodDescription {
             description_data: DescriptionData::new(package_name, class_name, line, position),
             var_name,
             method_name,
-            count_param_input
+            count_param_input,
+            deprecated: false,
+            template_arguments: vec![],
+            dispatch_candidates: vec![]
         }
     }
 
+    pub fn method_name(&self) -> &String {
+        &self.method_name
+    }
+
+    pub fn count_param_input(&self) -> usize {
+        self.count_param_input
+    }
+
     pub fn set_var_name(&mut self, var_name: String) {
         self.var_name = var_name;
     }
@@ -115,6 +135,18 @@ impl MethodDescription {
         self.count_param_input = count_param_input;
     }
 
+    pub fn set_deprecated(&mut self, deprecated: bool) {
+        self.deprecated = deprecated;
+    }
+
+    pub fn set_template_arguments(&mut self, template_arguments: Vec<String>) {
+        self.template_arguments = template_arguments;
+    }
+
+    pub fn set_dispatch_candidates(&mut self, dispatch_candidates: Vec<DispatchCandidate>) {
+        self.dispatch_candidates = dispatch_candidates;
+    }
+
 }
 
 impl Description for MethodDescription {
@@ -160,6 +192,25 @@ impl Description for MethodDescription {
     }
 }
 
+/* Class overriding the called method */
+#[derive(Debug, Clone, Serialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DispatchCandidate {
+    package_name: String,
+    class_name: String,
+}
+
+impl DispatchCandidate {
+
+    pub fn new(package_name: String, class_name: String) -> Self {
+        Self { package_name, class_name }
+    }
+
+    pub fn class_name(&self) -> &String {
+        &self.class_name
+    }
+}
+
 #[derive(Default)]
 pub struct PackageDescription {
     description_data: DescriptionData,
@@ -236,6 +287,9 @@ impl Description for PackageDescription {
 pub struct VarDescription {
     description_data: DescriptionData,
     var_name: String,
+    /* C/C++ pointer or reference, calls through it are dispatched dynamically */
+    #[serde(skip)]
+    indirect: bool,
 }
 
 impl VarDescription {
@@ -243,7 +297,8 @@ impl VarDescription {
     pub fn new(package_name: String, class_name: String, line: usize, position: usize, var_name: String) -> Self {
         VarDescription {
             description_data: DescriptionData::new(package_name, class_name, line, position),
-            var_name
+            var_name,
+            indirect: false
         }
     }
 
@@ -258,6 +313,14 @@ impl VarDescription {
     pub fn set_var_name(&mut self, var_name: String) {
         self.var_name = var_name;
     }
+
+    pub fn indirect(&self) -> bool {
+        self.indirect
+    }
+
+    pub fn set_indirect(&mut self, indirect: bool) {
+        self.indirect = indirect;
+    }
 }
 
 impl Description for VarDescription {
@@ -0,0 +1,254 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+use crate::dto::schema::SchemaVersion;
+
+/* Tuning of parsing behaviour, passed by the caller as JSON. Missing fields take default values */
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ParseOptions {
+    /* Identifiers and type names longer than this are dropped */
+    max_token_length: usize,
+    /* Source code of declarations is emitted as blob data */
+    capture_body: bool,
+    /* Zero args constructor is emitted for every class */
+    synthetic_constructors: bool,
+    minified_file_detection: MinifiedFileDetection,
+    extractors: Extractors,
+    schema_version: u32,
+    /* C/C++ include directories relative to repository root */
+    include_directories: Vec<String>,
+    /* Qualified class name to path of the file declaring it, built by caller from repository declarations */
+    symbol_table: HashMap<String, String>,
+    /* C/C++ enums of the repository, qualified uses `Mode::Dark` of enums declared in other files are enumerators */
+    enum_names: Vec<String>,
+    preprocessor: Preprocessor,
+    /* Python source roots relative to repository root, modules are named relative to them */
+    source_roots: Vec<String>,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            max_token_length: 250,
+            capture_body: false,
+            synthetic_constructors: true,
+            minified_file_detection: MinifiedFileDetection::default(),
+            extractors: Extractors::default(),
+            schema_version: SchemaVersion::CURRENT,
+            include_directories: vec![],
+            symbol_table: HashMap::new(),
+            enum_names: vec![],
+            preprocessor: Preprocessor::default(),
+            source_roots: vec![],
+        }
+    }
+}
+
+impl ParseOptions {
+
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    pub fn max_token_length(&self) -> usize {
+        self.max_token_length
+    }
+
+    pub fn capture_body(&self) -> bool {
+        self.capture_body
+    }
+
+    pub fn synthetic_constructors(&self) -> bool {
+        self.synthetic_constructors
+    }
+
+    pub fn minified_file_detection(&self) -> &MinifiedFileDetection {
+        &self.minified_file_detection
+    }
+
+    pub fn extractors(&self) -> &Extractors {
+        &self.extractors
+    }
+
+    pub fn schema_version(&self) -> u32 {
+        self.schema_version
+    }
+
+    pub fn set_schema_version(&mut self, schema_version: u32) {
+        self.schema_version = schema_version;
+    }
+
+    pub fn include_directories(&self) -> &Vec<String> {
+        &self.include_directories
+    }
+
+    pub fn symbol_table(&self) -> &HashMap<String, String> {
+        &self.symbol_table
+    }
+
+    pub fn enum_names(&self) -> &Vec<String> {
+        &self.enum_names
+    }
+
+    pub fn preprocessor(&self) -> &Preprocessor {
+        &self.preprocessor
+    }
+
+    pub fn source_roots(&self) -> &Vec<String> {
+        &self.source_roots
+    }
+}
+
+/* Heuristics which skip minified and bundled JS files */
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MinifiedFileDetection {
+    enabled: bool,
+    /* Files whose name contains one of markers are skipped */
+    file_name_markers: Vec<String>,
+    max_line_length: usize,
+    max_line_length_with_small_line_number: usize,
+    required_line_number: usize,
+}
+
+impl Default for MinifiedFileDetection {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            file_name_markers: vec![".min".to_string(), ".dev".to_string()],
+            max_line_length: 500,
+            max_line_length_with_small_line_number: 200,
+            required_line_number: 10,
+        }
+    }
+}
+
+impl MinifiedFileDetection {
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn file_name_markers(&self) -> &Vec<String> {
+        &self.file_name_markers
+    }
+
+    pub fn max_line_length(&self) -> usize {
+        self.max_line_length
+    }
+
+    pub fn max_line_length_with_small_line_number(&self) -> usize {
+        self.max_line_length_with_small_line_number
+    }
+
+    pub fn required_line_number(&self) -> usize {
+        self.required_line_number
+    }
+}
+
+/* Optional extractors, all but routes enabled by default */
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Extractors {
+    documentation: bool,
+    /* Deprecation of declarations and deprecated links of invocation structure */
+    deprecation: bool,
+    /* Flask/FastAPI route decorators and Django `urls.py` entries */
+    routes: bool,
+}
+
+impl Default for Extractors {
+    fn default() -> Self {
+        Self { documentation: true, deprecation: true, routes: false }
+    }
+}
+
+impl Extractors {
+
+    pub fn documentation(&self) -> bool {
+        self.documentation
+    }
+
+    pub fn deprecation(&self) -> bool {
+        self.deprecation
+    }
+
+    pub fn routes(&self) -> bool {
+        self.routes
+    }
+}
+
+/* C/C++ build configuration of the repository */
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Preprocessor {
+    /* Macro name to value, empty for `-DNAME`. Without configuration all branches are parsed */
+    defined_macros: Option<HashMap<String, String>>,
+    /* Branches inactive for defined macros are skipped, otherwise only tagged with their condition */
+    skip_inactive_branches: bool,
+}
+
+impl Default for Preprocessor {
+    fn default() -> Self {
+        Self { defined_macros: None, skip_inactive_branches: true }
+    }
+}
+
+impl Preprocessor {
+
+    pub fn defined_macros(&self) -> &Option<HashMap<String, String>> {
+        &self.defined_macros
+    }
+
+    pub fn skip_inactive_branches(&self) -> bool {
+        self.skip_inactive_branches
+    }
+}
+
+#[cfg(test)]
+mod parse_options_tests {
+    use crate::dto::parse_options::ParseOptions;
+    use crate::dto::schema::SchemaVersion;
+
+    #[test]
+    pub fn test_from_json() {
+        let options = ParseOptions::from_json("{}").unwrap();
+        assert_eq!(options.max_token_length(), 250);
+        assert!(options.synthetic_constructors());
+        assert!(options.extractors().documentation());
+        assert_eq!(options.minified_file_detection().max_line_length(), 500);
+        assert_eq!(options.schema_version(), SchemaVersion::CURRENT);
+
+        let options = ParseOptions::from_json(r#"{
+            "maxTokenLength": 100,
+            "captureBody": true,
+            "syntheticConstructors": false,
+            "minifiedFileDetection": { "enabled": false },
+            "extractors": { "documentation": false },
+            "schemaVersion": 1
+        }"#).unwrap();
+        assert_eq!(options.max_token_length(), 100);
+        assert!(options.capture_body());
+        assert!(!options.synthetic_constructors());
+        assert!(!options.minified_file_detection().enabled());
+        assert_eq!(options.minified_file_detection().required_line_number(), 10);
+        assert!(!options.extractors().documentation());
+        assert!(options.extractors().deprecation());
+        assert!(!options.extractors().routes());
+        assert_eq!(options.schema_version(), SchemaVersion::V1);
+        assert!(options.preprocessor().defined_macros().is_none());
+        assert!(options.source_roots().is_empty());
+
+        let options = ParseOptions::from_json(r#"{
+            "preprocessor": { "definedMacros": { "_WIN32": "", "LEVEL": "2" }, "skipInactiveBranches": false }
+        }"#).unwrap();
+        let defined_macros = options.preprocessor().defined_macros().as_ref().unwrap();
+        assert_eq!(defined_macros.get("LEVEL"), Some(&"2".to_string()));
+        assert!(!options.preprocessor().skip_inactive_branches());
+
+        let options = ParseOptions::from_json(r#"{ "sourceRoots": ["src", "services/api"] }"#).unwrap();
+        assert_eq!(options.source_roots(), &vec!["src".to_string(), "services/api".to_string()]);
+
+        assert!(ParseOptions::from_json(r#"{ "maxTokenLength": "long" }"#).is_err());
+    }
+}
@@ -1,19 +1,95 @@
-use serde::Serialize;
+use serde::{Serialize, Deserialize};
+use schemars::JsonSchema;
+use crate::dto::documentation::Documentation;
+use crate::dto::visibility::Visibility;
+use crate::dto::modifier::{Modifier, Modifiers};
+use crate::dto::definition::{DeclarationKind, DefinitionLocation};
+use crate::dto::method_kind::MethodKind;
+use crate::dto::class_kind::ClassKind;
+use crate::dto::route::Route;
 
-#[derive(Debug, Default, Serialize)]
+#[derive(Debug, Default, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct RepositoryMethodDto {
+    /// Repository identifier passed by the caller
     repository_id: i32,
+    /// Path of the parsed file
     path_file: String,
+    /// Package, namespace or module path of the declaration
     package_name: String,
+    /// One-based line of the declaration
     line_code: usize,
+    /// Enclosing class name, empty for free functions
     class_name: String,
+    /// Declared function or method name
     method_name: String,
+    /// Source code of the declaration, captured on request
     blob_data: String,
-    modifiers: String,
+    /// Normalized modifiers; JSON-encoded keyword list in schema version 1
+    modifiers: Modifiers,
+    #[serde(skip)]
+    legacy_modifiers: String,
+    /// Code type of the declaration, e.g. `PYTHON_METHOD` or `CPP_CLASS`
     #[serde(rename = "type")]
     method_type: String,
     count_of_parameters: usize,
+    visibility: Visibility,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    documentation: Option<Documentation>,
+    deprecated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deprecation_message: Option<String>,
+    /// C/C++ name with enclosing namespaces and classes, e.g. `ns::Foo::bar`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    qualified_name: Option<String>,
+    /// C/C++ function prototype or function with body, Python stub or `@overload` signature is a declaration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    declaration_kind: Option<DeclarationKind>,
+    /// Definition paired with this declaration by repository-level pairing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    definition: Option<DefinitionLocation>,
+    /// Names of C++ template parameters, empty for explicit specialization
+    #[serde(skip_serializing_if = "Option::is_none")]
+    template_parameters: Option<Vec<String>>,
+    /// Arguments of C++ template specialization, e.g. `["int"]` for `Foo<int>`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    template_arguments: Option<Vec<String>>,
+    /// Name of primary C++ template of the specialization
+    #[serde(skip_serializing_if = "Option::is_none")]
+    primary_template: Option<String>,
+    /// Type named by C++ typedef or alias declaration, e.g. `geo::Shape*`, or by Python type alias
+    #[serde(skip_serializing_if = "Option::is_none")]
+    aliased_type: Option<String>,
+    /// C/C++ preprocessor condition of enclosing `#if` branches, e.g. `defined(_WIN32) && !defined(NDEBUG)`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preprocessor_condition: Option<String>,
+    /// Underlying type of C++ enum, e.g. `unsigned char` for `enum class Color : unsigned char`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    underlying_type: Option<String>,
+    /// Value expression of C++ enumerator, e.g. `Red + 2`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value_expression: Option<String>,
+    /// Definition re-exported by Python package `__init__.py`, e.g. `mypkg.engine.Engine`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reexported_from: Option<String>,
+    /// Python decorators as written without `@`, e.g. `app.route("/users")`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    decorators: Option<Vec<String>>,
+    /// Binding of Python method classified by its decorators
+    #[serde(skip_serializing_if = "Option::is_none")]
+    method_kind: Option<MethodKind>,
+    /// Web framework route of `PYTHON_ROUTE` declaration
+    #[serde(skip_serializing_if = "Option::is_none")]
+    route: Option<Route>,
+    /// Annotation of Python variable, e.g. `Final[int]`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    annotation_type: Option<String>,
+    /// Kind of Python class, e.g. `dataclass` or `protocol`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    class_kind: Option<ClassKind>,
+    /// Parameters and return annotation of Python function as written, e.g. `(key: str) -> bool`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<String>,
 }
 
 impl RepositoryMethodDto {
@@ -21,7 +97,7 @@ impl RepositoryMethodDto {
     pub fn new(repository_id: i32, path_file: String,
                package_name: String, line_code: usize,
                class_name: String, method_name: String,
-               blob_data: String, modifiers: String,
+               blob_data: String, legacy_modifiers: String,
                method_type: String, count_of_parameters: usize) -> Self {
 
         Self {
@@ -32,12 +108,184 @@ impl RepositoryMethodDto {
             class_name,
             method_name,
             blob_data,
-            modifiers,
+            modifiers: Modifiers::default(),
+            legacy_modifiers,
             method_type,
-            count_of_parameters
+            count_of_parameters,
+            visibility: Visibility::Public,
+            documentation: None,
+            deprecated: false,
+            deprecation_message: None,
+            qualified_name: None,
+            declaration_kind: None,
+            definition: None,
+            template_parameters: None,
+            template_arguments: None,
+            primary_template: None,
+            aliased_type: None,
+            preprocessor_condition: None,
+            underlying_type: None,
+            value_expression: None,
+            reexported_from: None,
+            decorators: None,
+            method_kind: None,
+            route: None,
+            annotation_type: None,
+            class_kind: None,
+            signature: None,
         }
     }
 
+    pub fn package_name(&self) -> &String {
+        &self.package_name
+    }
+
+    pub fn class_name(&self) -> &String {
+        &self.class_name
+    }
+
+    pub fn method_name(&self) -> &String {
+        &self.method_name
+    }
+
+    pub fn path_file(&self) -> &String {
+        &self.path_file
+    }
+
+    pub fn line_code(&self) -> usize {
+        self.line_code
+    }
+
+    pub fn count_of_parameters(&self) -> usize {
+        self.count_of_parameters
+    }
+
+    pub fn set_modifiers(&mut self, modifiers: Vec<Modifier>) {
+        self.modifiers = Modifiers::Normalized(modifiers);
+    }
+
+    /* Emits modifiers as JSON-encoded string of source keywords */
+    pub fn use_legacy_modifiers(&mut self) {
+        self.modifiers = Modifiers::Legacy(std::mem::take(&mut self.legacy_modifiers));
+    }
+
+    pub fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+
+    pub fn set_documentation(&mut self, documentation: Option<Documentation>) {
+        self.documentation = documentation;
+    }
+
+    pub fn set_deprecated(&mut self, deprecated: bool) {
+        self.deprecated = deprecated;
+    }
+
+    pub fn set_deprecation_message(&mut self, deprecation_message: Option<String>) {
+        self.deprecation_message = deprecation_message;
+    }
+
+    pub fn qualified_name(&self) -> &Option<String> {
+        &self.qualified_name
+    }
+
+    pub fn declaration_kind(&self) -> Option<DeclarationKind> {
+        self.declaration_kind
+    }
+
+    pub fn set_declaration_kind(&mut self, declaration_kind: Option<DeclarationKind>) {
+        self.declaration_kind = declaration_kind;
+    }
+
+    pub fn set_definition(&mut self, definition: Option<DefinitionLocation>) {
+        self.definition = definition;
+    }
+
+    pub fn set_reexported_from(&mut self, reexported_from: Option<String>) {
+        self.reexported_from = reexported_from;
+    }
+
+    pub fn set_decorators(&mut self, decorators: Option<Vec<String>>) {
+        self.decorators = decorators;
+    }
+
+    pub fn set_method_kind(&mut self, method_kind: Option<MethodKind>) {
+        self.method_kind = method_kind;
+    }
+
+    pub fn set_route(&mut self, route: Option<Route>) {
+        self.route = route;
+    }
+
+    pub fn set_aliased_type(&mut self, aliased_type: Option<String>) {
+        self.aliased_type = aliased_type;
+    }
+
+    pub fn set_annotation_type(&mut self, annotation_type: Option<String>) {
+        self.annotation_type = annotation_type;
+    }
+
+    pub fn set_class_kind(&mut self, class_kind: Option<ClassKind>) {
+        self.class_kind = class_kind;
+    }
+
+    pub fn signature(&self) -> &Option<String> {
+        &self.signature
+    }
+
+    pub fn set_signature(&mut self, signature: Option<String>) {
+        self.signature = signature;
+    }
+
+}
+
+/* Declaration of schema version 1: fields of the first plugin builds, modifiers as JSON-encoded keyword list */
+#[derive(Debug, Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryMethodDtoV1 {
+    /// Repository identifier passed by the caller
+    repository_id: i32,
+    /// Path of the parsed file
+    path_file: String,
+    /// Package, namespace or module path of the declaration
+    package_name: String,
+    /// One-based line of the declaration
+    line_code: usize,
+    /// Enclosing class name, empty for free functions
+    class_name: String,
+    /// Declared function or method name
+    method_name: String,
+    /// Source code of the declaration, captured on request
+    blob_data: String,
+    /// JSON-encoded list of source keywords
+    modifiers: String,
+    /// Code type of the declaration, e.g. `PYTHON_METHOD` or `CPP_CLASS`
+    #[serde(rename = "type")]
+    method_type: String,
+    count_of_parameters: usize,
+}
+
+impl From<RepositoryMethodDto> for RepositoryMethodDtoV1 {
+
+    fn from(dto: RepositoryMethodDto) -> Self {
+        let modifiers = match dto.modifiers {
+            Modifiers::Legacy(modifiers) => modifiers,
+            Modifiers::Normalized(_) => dto.legacy_modifiers
+        };
+
+        Self {
+            repository_id: dto.repository_id,
+            path_file: dto.path_file,
+            package_name: dto.package_name,
+            line_code: dto.line_code,
+            class_name: dto.class_name,
+            method_name: dto.method_name,
+            blob_data: dto.blob_data,
+            modifiers,
+            method_type: dto.method_type,
+            count_of_parameters: dto.count_of_parameters,
+        }
+    }
 }
 
 #[derive(Default)]
@@ -49,9 +297,23 @@ pub struct RepositoryMethodDtoBuilder {
     class_name: String,
     method_name: String,
     blob_data: String,
-    modifiers: String,
+    modifiers: Vec<Modifier>,
+    legacy_modifiers: String,
     method_type: String,
     count_of_parameters: usize,
+    visibility: Visibility,
+    documentation: Option<Documentation>,
+    deprecated: bool,
+    deprecation_message: Option<String>,
+    qualified_name: Option<String>,
+    declaration_kind: Option<DeclarationKind>,
+    template_parameters: Option<Vec<String>>,
+    template_arguments: Option<Vec<String>>,
+    primary_template: Option<String>,
+    aliased_type: Option<String>,
+    preprocessor_condition: Option<String>,
+    underlying_type: Option<String>,
+    value_expression: Option<String>,
 }
 
 impl RepositoryMethodDtoBuilder {
@@ -86,11 +348,21 @@ impl RepositoryMethodDtoBuilder {
         self
     }
 
-    pub fn modifiers(mut self, modifiers: String) -> RepositoryMethodDtoBuilder {
+    pub fn blob_data(mut self, blob_data: String) -> RepositoryMethodDtoBuilder {
+        self.blob_data = blob_data;
+        self
+    }
+
+    pub fn modifiers(mut self, modifiers: Vec<Modifier>) -> RepositoryMethodDtoBuilder {
         self.modifiers = modifiers;
         self
     }
 
+    pub fn legacy_modifiers(mut self, legacy_modifiers: String) -> RepositoryMethodDtoBuilder {
+        self.legacy_modifiers = legacy_modifiers;
+        self
+    }
+
     pub fn method_type(mut self, method_type: String) -> RepositoryMethodDtoBuilder {
         self.method_type = method_type;
         self
@@ -101,6 +373,71 @@ impl RepositoryMethodDtoBuilder {
         self
     }
 
+    pub fn visibility(mut self, visibility: Visibility) -> RepositoryMethodDtoBuilder {
+        self.visibility = visibility;
+        self
+    }
+
+    pub fn documentation(mut self, documentation: Option<Documentation>) -> RepositoryMethodDtoBuilder {
+        self.documentation = documentation;
+        self
+    }
+
+    pub fn deprecated(mut self, deprecated: bool) -> RepositoryMethodDtoBuilder {
+        self.deprecated = deprecated;
+        self
+    }
+
+    pub fn deprecation_message(mut self, deprecation_message: Option<String>) -> RepositoryMethodDtoBuilder {
+        self.deprecation_message = deprecation_message;
+        self
+    }
+
+    pub fn qualified_name(mut self, qualified_name: Option<String>) -> RepositoryMethodDtoBuilder {
+        self.qualified_name = qualified_name;
+        self
+    }
+
+    pub fn declaration_kind(mut self, declaration_kind: Option<DeclarationKind>) -> RepositoryMethodDtoBuilder {
+        self.declaration_kind = declaration_kind;
+        self
+    }
+
+    pub fn template_parameters(mut self, template_parameters: Option<Vec<String>>) -> RepositoryMethodDtoBuilder {
+        self.template_parameters = template_parameters;
+        self
+    }
+
+    pub fn template_arguments(mut self, template_arguments: Option<Vec<String>>) -> RepositoryMethodDtoBuilder {
+        self.template_arguments = template_arguments;
+        self
+    }
+
+    pub fn primary_template(mut self, primary_template: Option<String>) -> RepositoryMethodDtoBuilder {
+        self.primary_template = primary_template;
+        self
+    }
+
+    pub fn aliased_type(mut self, aliased_type: Option<String>) -> RepositoryMethodDtoBuilder {
+        self.aliased_type = aliased_type;
+        self
+    }
+
+    pub fn preprocessor_condition(mut self, preprocessor_condition: Option<String>) -> RepositoryMethodDtoBuilder {
+        self.preprocessor_condition = preprocessor_condition;
+        self
+    }
+
+    pub fn underlying_type(mut self, underlying_type: Option<String>) -> RepositoryMethodDtoBuilder {
+        self.underlying_type = underlying_type;
+        self
+    }
+
+    pub fn value_expression(mut self, value_expression: Option<String>) -> RepositoryMethodDtoBuilder {
+        self.value_expression = value_expression;
+        self
+    }
+
     pub fn build(self) -> RepositoryMethodDto {
 
         RepositoryMethodDto {
@@ -111,9 +448,31 @@ impl RepositoryMethodDtoBuilder {
             class_name: self.class_name,
             method_name: self.method_name,
             blob_data: self.blob_data,
-            modifiers: self.modifiers,
+            modifiers: Modifiers::Normalized(self.modifiers),
+            legacy_modifiers: self.legacy_modifiers,
             method_type: self.method_type,
-            count_of_parameters: self.count_of_parameters
+            count_of_parameters: self.count_of_parameters,
+            visibility: self.visibility,
+            documentation: self.documentation,
+            deprecated: self.deprecated,
+            deprecation_message: self.deprecation_message,
+            qualified_name: self.qualified_name,
+            declaration_kind: self.declaration_kind,
+            definition: None,
+            template_parameters: self.template_parameters,
+            template_arguments: self.template_arguments,
+            primary_template: self.primary_template,
+            aliased_type: self.aliased_type,
+            preprocessor_condition: self.preprocessor_condition,
+            underlying_type: self.underlying_type,
+            value_expression: self.value_expression,
+            reexported_from: None,
+            decorators: None,
+            method_kind: None,
+            route: None,
+            annotation_type: None,
+            class_kind: None,
+            signature: None,
         }
     }
 }
\ No newline at end of file
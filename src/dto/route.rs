@@ -0,0 +1,21 @@
+use serde::{Serialize, Deserialize};
+use schemars::JsonSchema;
+
+/* Web framework route of a Python handler */
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Route {
+    /* Path as written, e.g. `/users/<int:id>` or `users/<int:pk>/` */
+    path: String,
+    /* Upper case HTTP methods, empty when any method is routed */
+    http_methods: Vec<String>,
+    /* Qualified name of the handler, e.g. `shop.views.user_detail` */
+    handler: String,
+}
+
+impl Route {
+
+    pub fn new(path: String, http_methods: Vec<String>, handler: String) -> Self {
+        Self { path, http_methods, handler }
+    }
+}
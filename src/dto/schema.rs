@@ -0,0 +1,138 @@
+use serde::{Serialize, Deserialize};
+use schemars::JsonSchema;
+use schemars::schema_for;
+use crate::dto::repository_method_dto::{RepositoryMethodDto, RepositoryMethodDtoV1};
+use crate::dto::invocation_structure::InvocationStructure;
+
+pub struct SchemaVersion;
+impl SchemaVersion {
+    /* Bare declaration array of the first plugin builds, modifiers as JSON-encoded string, no version marker.
+       Invocation output is not versioned: it only drops `schemaVersion`, fields added later are written in V1 too */
+    pub const V1: u32 = 1;
+    /* Versioned declaration envelope, normalized modifiers */
+    pub const V2: u32 = 2;
+    pub const CURRENT: u32 = SchemaVersion::V2;
+
+    pub fn is_supported(schema_version: u32) -> bool {
+        (SchemaVersion::V1..=SchemaVersion::CURRENT).contains(&schema_version)
+    }
+}
+
+pub struct OutputTypes;
+impl OutputTypes {
+    pub const DECLARATIONS: &'static str = "DECLARATIONS";
+    pub const INVOCATIONS: &'static str = "INVOCATIONS";
+}
+
+/* Top-level declaration output since schema version 2 */
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeclarationOutput {
+    /// Output schema version
+    schema_version: u32,
+    declarations: Vec<RepositoryMethodDto>,
+}
+
+impl DeclarationOutput {
+
+    pub fn new(schema_version: u32, declarations: Vec<RepositoryMethodDto>) -> Self {
+        Self { schema_version, declarations }
+    }
+}
+
+/* Declarations are read back either as bare array or in the versioned envelope */
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum DeclarationInput {
+    Versioned(DeclarationOutput),
+    Bare(Vec<RepositoryMethodDto>),
+}
+
+pub fn declarations_from_json(json: &str) -> serde_json::Result<Vec<RepositoryMethodDto>> {
+    match serde_json::from_str(json)? {
+        DeclarationInput::Versioned(output) => Ok(output.declarations),
+        DeclarationInput::Bare(declarations) => Ok(declarations),
+    }
+}
+
+pub fn declarations_to_json(declarations: Vec<RepositoryMethodDto>, schema_version: u32) -> String {
+    if schema_version == SchemaVersion::V1 {
+        let declarations: Vec<RepositoryMethodDtoV1> = declarations.into_iter().map(RepositoryMethodDtoV1::from).collect();
+        return serde_json::to_string(&declarations).unwrap();
+    }
+
+    serde_json::to_string(&DeclarationOutput::new(schema_version, declarations)).unwrap()
+}
+
+/* JSON Schema of output type in the requested schema version */
+pub fn get_json_schema(output_type: &str, schema_version: u32) -> Option<String> {
+    if !SchemaVersion::is_supported(schema_version) {
+        return None;
+    }
+
+    let schema = match output_type {
+        OutputTypes::DECLARATIONS if schema_version == SchemaVersion::V1 => schema_for!(Vec<RepositoryMethodDtoV1>),
+        OutputTypes::DECLARATIONS => schema_for!(DeclarationOutput),
+        OutputTypes::INVOCATIONS => schema_for!(InvocationStructure),
+        _ => return None
+    };
+
+    Some(serde_json::to_string_pretty(&schema).unwrap())
+}
+
+#[cfg(test)]
+mod schema_tests {
+    use crate::dto::schema::{get_json_schema, declarations_to_json, declarations_from_json, SchemaVersion, OutputTypes};
+    use crate::dto::repository_method_dto::RepositoryMethodDtoBuilder;
+
+    #[test]
+    pub fn test_declarations_to_json() {
+        let declarations = || vec![RepositoryMethodDtoBuilder::default().method_name("run".to_string()).build()];
+
+        let json: serde_json::Value = serde_json::from_str(&declarations_to_json(declarations(), SchemaVersion::V1)).unwrap();
+        assert_eq!(json[0]["methodName"], "run");
+        /* Fields added since version 1 are not written */
+        assert!(json[0].get("visibility").is_none());
+        assert!(json[0].get("deprecated").is_none());
+        assert!(json[0]["modifiers"].is_string());
+
+        let json: serde_json::Value = serde_json::from_str(&declarations_to_json(declarations(), SchemaVersion::CURRENT)).unwrap();
+        assert_eq!(json["schemaVersion"], SchemaVersion::CURRENT);
+        assert_eq!(json["declarations"][0]["methodName"], "run");
+    }
+
+    #[test]
+    pub fn test_declarations_from_json() {
+        let declarations = || vec![RepositoryMethodDtoBuilder::default().method_name("run".to_string()).build()];
+
+        let bare = serde_json::to_string(&declarations()).unwrap();
+        let versioned = declarations_to_json(declarations(), SchemaVersion::CURRENT);
+        for json in [bare, versioned].iter() {
+            let declarations = declarations_from_json(json).unwrap();
+            assert_eq!(declarations.len(), 1);
+            assert_eq!(declarations[0].method_name(), "run");
+        }
+    }
+
+    #[test]
+    pub fn test_get_json_schema() {
+        let schema: serde_json::Value = serde_json::from_str(
+            &get_json_schema(OutputTypes::DECLARATIONS, SchemaVersion::CURRENT).unwrap()).unwrap();
+        assert!(schema["required"].as_array().unwrap().contains(&serde_json::json!("schemaVersion")));
+        assert!(schema["definitions"]["RepositoryMethodDto"]["properties"]["type"].is_object());
+
+        let schema: serde_json::Value = serde_json::from_str(
+            &get_json_schema(OutputTypes::DECLARATIONS, SchemaVersion::V1).unwrap()).unwrap();
+        assert_eq!(schema["type"], "array");
+        let properties = schema["definitions"]["RepositoryMethodDtoV1"]["properties"].as_object().unwrap();
+        assert_eq!(properties.len(), 10);
+        assert_eq!(properties["modifiers"]["type"], "string");
+
+        let schema: serde_json::Value = serde_json::from_str(
+            &get_json_schema(OutputTypes::INVOCATIONS, SchemaVersion::CURRENT).unwrap()).unwrap();
+        assert!(schema["properties"]["methodDescriptions"].is_object());
+
+        assert!(get_json_schema("UNKNOWN", SchemaVersion::CURRENT).is_none());
+        assert!(get_json_schema(OutputTypes::DECLARATIONS, 0).is_none());
+    }
+}
@@ -0,0 +1,16 @@
+use serde::{Serialize, Deserialize};
+use schemars::JsonSchema;
+
+/* Normalized access level of a declaration */
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Visibility {
+    #[default]
+    Public,
+    Protected,
+    /* Java package-private */
+    Package,
+    Private,
+    /* Not visible outside of file/module: C++ internal linkage, Python `_name` module members */
+    Internal,
+}
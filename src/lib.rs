@@ -1,4 +1,6 @@
-use crate::parser::{parse_file_get_dto, parse_file_get_invocation_structure};
+use crate::parser::{parse_file_get_dto, parse_file_get_invocation_structure, pair_declarations, detect_source_roots};
+use crate::dto::schema::{SchemaVersion, declarations_to_json, declarations_from_json, get_json_schema};
+use crate::dto::parse_options::ParseOptions;
 use jni::objects::{JClass, JObject, JString};
 use jni::JNIEnv;
 use jni::sys::{jstring, jint};
@@ -29,7 +31,11 @@ pub extern "system" fn Java_io_smartforce_plugin_bitbucket_navigation_parser_Rus
     let panic_buffer = setup_panic_buffer();
 
     /* Perform parsing */
-    let result = panic::catch_unwind(|| { declaration_parse_get_json(&env, repository_id, file_data, path, language) });
+    /* Existing plugin builds expect schema version 1 */
+    let result = panic::catch_unwind(|| {
+        let options = get_options_for_schema_version(SchemaVersion::V1);
+        declaration_parse_get_json(&env, repository_id, file_data, path, language, options)
+    });
 
     /* Setting back default panic hook */
     panic::set_hook(old_panic_hook);
@@ -54,7 +60,213 @@ pub extern "system" fn Java_io_smartforce_plugin_bitbucket_navigation_parser_Rus
     let panic_buffer = setup_panic_buffer();
 
     /* Perform parsing */
-    let result = panic::catch_unwind(|| { invocation_parse_get_json(&env, file_data, path, language) });
+    let result = panic::catch_unwind(|| {
+        let options = get_options_for_schema_version(SchemaVersion::V1);
+        invocation_parse_get_json(&env, file_data, path, language, options)
+    });
+
+    /* Setting back default panic hook */
+    panic::set_hook(old_panic_hook);
+
+    let java_json_string = unwrap_result_log_errors(&env, error_callback, result, panic_buffer);
+
+    /* Extract raw pointer to return. */
+    return java_json_string.into_inner();
+}
+
+#[no_mangle]
+#[allow(non_snake_case, unused)]
+pub extern "system" fn Java_io_smartforce_plugin_bitbucket_navigation_parser_RustNativeParser_declarationParseGetJsonVersioned(env: JNIEnv,
+                                                                                                                               class: JClass,
+                                                                                                                               repository_id: jint,
+                                                                                                                               file_data: JString,
+                                                                                                                               path: JString,
+                                                                                                                               language: JString,
+                                                                                                                               schema_version: jint,
+                                                                                                                               error_callback: JObject) -> jstring {
+    /* Setting up panic buffer in order to catch panic messages */
+    let old_panic_hook = panic::take_hook();
+
+    let panic_buffer = setup_panic_buffer();
+
+    /* Perform parsing */
+    let result = panic::catch_unwind(|| {
+        let options = get_options_for_schema_version(get_schema_version(schema_version));
+        declaration_parse_get_json(&env, repository_id, file_data, path, language, options)
+    });
+
+    /* Setting back default panic hook */
+    panic::set_hook(old_panic_hook);
+
+    let java_json_string = unwrap_result_log_errors(&env, error_callback, result, panic_buffer);
+
+    /* Extract raw pointer to return. */
+    return java_json_string.into_inner();
+}
+
+#[no_mangle]
+#[allow(non_snake_case, unused)]
+pub extern "system" fn Java_io_smartforce_plugin_bitbucket_navigation_parser_RustNativeParser_invocationParseGetJsonVersioned(env: JNIEnv,
+                                                                                                                              class: JClass,
+                                                                                                                              file_data: JString,
+                                                                                                                              path: JString,
+                                                                                                                              language: JString,
+                                                                                                                              schema_version: jint,
+                                                                                                                              error_callback: JObject) -> jstring {
+    /* Setting up panic buffer in order to catch panic messages */
+    let old_panic_hook = panic::take_hook();
+
+    let panic_buffer = setup_panic_buffer();
+
+    /* Perform parsing */
+    let result = panic::catch_unwind(|| {
+        let options = get_options_for_schema_version(get_schema_version(schema_version));
+        invocation_parse_get_json(&env, file_data, path, language, options)
+    });
+
+    /* Setting back default panic hook */
+    panic::set_hook(old_panic_hook);
+
+    let java_json_string = unwrap_result_log_errors(&env, error_callback, result, panic_buffer);
+
+    /* Extract raw pointer to return. */
+    return java_json_string.into_inner();
+}
+
+#[no_mangle]
+#[allow(non_snake_case, unused)]
+pub extern "system" fn Java_io_smartforce_plugin_bitbucket_navigation_parser_RustNativeParser_declarationParseGetJsonWithOptions(env: JNIEnv,
+                                                                                                                                 class: JClass,
+                                                                                                                                 repository_id: jint,
+                                                                                                                                 file_data: JString,
+                                                                                                                                 path: JString,
+                                                                                                                                 language: JString,
+                                                                                                                                 options: JString,
+                                                                                                                                 error_callback: JObject) -> jstring {
+    /* Setting up panic buffer in order to catch panic messages */
+    let old_panic_hook = panic::take_hook();
+
+    let panic_buffer = setup_panic_buffer();
+
+    /* Perform parsing */
+    let result = panic::catch_unwind(|| {
+        let options = get_options_from_json(&env, options);
+        declaration_parse_get_json(&env, repository_id, file_data, path, language, options)
+    });
+
+    /* Setting back default panic hook */
+    panic::set_hook(old_panic_hook);
+
+    let java_json_string = unwrap_result_log_errors(&env, error_callback, result, panic_buffer);
+
+    /* Extract raw pointer to return. */
+    return java_json_string.into_inner();
+}
+
+#[no_mangle]
+#[allow(non_snake_case, unused)]
+pub extern "system" fn Java_io_smartforce_plugin_bitbucket_navigation_parser_RustNativeParser_invocationParseGetJsonWithOptions(env: JNIEnv,
+                                                                                                                                class: JClass,
+                                                                                                                                file_data: JString,
+                                                                                                                                path: JString,
+                                                                                                                                language: JString,
+                                                                                                                                options: JString,
+                                                                                                                                error_callback: JObject) -> jstring {
+    /* Setting up panic buffer in order to catch panic messages */
+    let old_panic_hook = panic::take_hook();
+
+    let panic_buffer = setup_panic_buffer();
+
+    /* Perform parsing */
+    let result = panic::catch_unwind(|| {
+        let options = get_options_from_json(&env, options);
+        invocation_parse_get_json(&env, file_data, path, language, options)
+    });
+
+    /* Setting back default panic hook */
+    panic::set_hook(old_panic_hook);
+
+    let java_json_string = unwrap_result_log_errors(&env, error_callback, result, panic_buffer);
+
+    /* Extract raw pointer to return. */
+    return java_json_string.into_inner();
+}
+
+#[no_mangle]
+#[allow(non_snake_case, unused)]
+pub extern "system" fn Java_io_smartforce_plugin_bitbucket_navigation_parser_RustNativeParser_getJsonSchema(env: JNIEnv,
+                                                                                                           class: JClass,
+                                                                                                           output_type: JString,
+                                                                                                           schema_version: jint,
+                                                                                                           error_callback: JObject) -> jstring {
+    /* Setting up panic buffer in order to catch panic messages */
+    let old_panic_hook = panic::take_hook();
+
+    let panic_buffer = setup_panic_buffer();
+
+    let result = panic::catch_unwind(|| {
+        let output_type: String = env.get_string(output_type).expect("Couldn't get java string. Param name: output_type").into();
+        let schema_version = get_schema_version(schema_version);
+        let json_schema = get_json_schema(&output_type, schema_version).expect("Unknown output type");
+
+        env.new_string(json_schema).unwrap()
+    });
+
+    /* Setting back default panic hook */
+    panic::set_hook(old_panic_hook);
+
+    let java_json_string = unwrap_result_log_errors(&env, error_callback, result, panic_buffer);
+
+    /* Extract raw pointer to return. */
+    return java_json_string.into_inner();
+}
+
+#[no_mangle]
+#[allow(non_snake_case, unused)]
+pub extern "system" fn Java_io_smartforce_plugin_bitbucket_navigation_parser_RustNativeParser_pairDeclarationsGetJson(env: JNIEnv,
+                                                                                                                     class: JClass,
+                                                                                                                     declarations: JString,
+                                                                                                                     error_callback: JObject) -> jstring {
+    /* Setting up panic buffer in order to catch panic messages */
+    let old_panic_hook = panic::take_hook();
+
+    let panic_buffer = setup_panic_buffer();
+
+    let result = panic::catch_unwind(|| {
+        let declarations: String = env.get_string(declarations).expect("Couldn't get java string. Param name: declarations").into();
+        let mut declarations = declarations_from_json(&declarations).expect("Unable to parse declarations");
+        pair_declarations(&mut declarations);
+
+        env.new_string(declarations_to_json(declarations, SchemaVersion::CURRENT)).unwrap()
+    });
+
+    /* Setting back default panic hook */
+    panic::set_hook(old_panic_hook);
+
+    let java_json_string = unwrap_result_log_errors(&env, error_callback, result, panic_buffer);
+
+    /* Extract raw pointer to return. */
+    return java_json_string.into_inner();
+}
+
+#[no_mangle]
+#[allow(non_snake_case, unused)]
+pub extern "system" fn Java_io_smartforce_plugin_bitbucket_navigation_parser_RustNativeParser_detectSourceRootsGetJson(env: JNIEnv,
+                                                                                                                      class: JClass,
+                                                                                                                      paths: JString,
+                                                                                                                      error_callback: JObject) -> jstring {
+    /* Setting up panic buffer in order to catch panic messages */
+    let old_panic_hook = panic::take_hook();
+
+    let panic_buffer = setup_panic_buffer();
+
+    let result = panic::catch_unwind(|| {
+        let paths: String = env.get_string(paths).expect("Couldn't get java string. Param name: paths").into();
+        let paths: Vec<String> = serde_json::from_str(&paths).expect("Unable to parse paths");
+        let source_roots = detect_source_roots(&paths);
+
+        env.new_string(serde_json::to_string(&source_roots).unwrap()).unwrap()
+    });
 
     /* Setting back default panic hook */
     panic::set_hook(old_panic_hook);
@@ -67,29 +279,55 @@ pub extern "system" fn Java_io_smartforce_plugin_bitbucket_navigation_parser_Rus
 
 
 fn declaration_parse_get_json<'lifetime>(env: &'lifetime JNIEnv, repository_id: jint,
-                                         file_data: JString, path: JString, language: JString) -> JString<'lifetime> {
+                                         file_data: JString, path: JString, language: JString,
+                                         options: ParseOptions) -> JString<'lifetime> {
     let data: String = env.get_string(file_data).expect("Couldn't get java string. Param name: data").into();
     let path: String = env.get_string(path).expect("Couldn't get java string. Param name: path").into();
     let language: String = env.get_string(language).expect("Couldn't get java string. Param name: language").into();
 
-    let method_dto_vec = parse_file_get_dto(data, repository_id, path, language);
-    let json = serde_json::to_string(&method_dto_vec).unwrap();
+    let method_dto_vec = parse_file_get_dto(data, repository_id, path, language, &options);
+    let json = declarations_to_json(method_dto_vec, options.schema_version());
 
     env.new_string(json).unwrap()
 }
 
 fn invocation_parse_get_json<'lifetime>(env: &'lifetime JNIEnv, file_data: JString,
-                                        path: JString, language: JString) -> JString<'lifetime> {
+                                        path: JString, language: JString, options: ParseOptions) -> JString<'lifetime> {
     let data: String = env.get_string(file_data).expect("Couldn't get java string. Param name: data").into();
     let path: String = env.get_string(path).expect("Couldn't get java string. Param name: path").into();
     let language: String = env.get_string(language).expect("Couldn't get java string. Param name: lang").into();
 
-    let invocation_structure = parse_file_get_invocation_structure(data, path, language);
+    let invocation_structure = parse_file_get_invocation_structure(data, path, language, &options);
     let json = serde_json::to_string(&invocation_structure).unwrap();
 
     env.new_string(json).unwrap()
 }
 
+fn get_schema_version(schema_version: jint) -> u32 {
+    let schema_version = schema_version as u32;
+    if !SchemaVersion::is_supported(schema_version) {
+        panic!("Unsupported schema version");
+    }
+
+    schema_version
+}
+
+fn get_options_for_schema_version(schema_version: u32) -> ParseOptions {
+    let mut options = ParseOptions::default();
+    options.set_schema_version(schema_version);
+    options
+}
+
+fn get_options_from_json(env: &JNIEnv, options: JString) -> ParseOptions {
+    let options: String = env.get_string(options).expect("Couldn't get java string. Param name: options").into();
+    let options = ParseOptions::from_json(&options).expect("Unable to parse options");
+    if !SchemaVersion::is_supported(options.schema_version()) {
+        panic!("Unsupported schema version");
+    }
+
+    options
+}
+
 fn setup_panic_buffer() -> Arc<Mutex<String>> {
     let global_error_buffer = Arc::new(Mutex::new(String::new()));
 
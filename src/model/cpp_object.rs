@@ -2,17 +2,29 @@ use std::fmt::{Formatter, Debug, Display, Result as FormatResult};
 use erased_serde::serialize_trait_object;
 use std::any::Any;
 use serde::Serialize;
-use crate::model::cpp_object::CodeType::{CPP_CLASS, CPP_ENUM};
+use crate::model::cpp_object::CodeType::{CPP_CLASS, CPP_ENUM, CPP_ENUM_CLASS, CPP_ENUMERATOR, CPP_TYPE_ALIAS,
+                                         CPP_NAMESPACE_ALIAS};
 use crate::model::cpp_object::ObjectType::{Declaration, Definition};
+use crate::dto::documentation::Documentation;
+use crate::dto::visibility::Visibility;
 
-#[derive(Debug, Serialize, PartialEq)]
+#[derive(Debug, Default, Serialize, PartialEq)]
 #[allow(non_camel_case_types)]
 pub enum CodeType {
     CPP_PACKAGE,
     CPP_CLASS,
     CPP_ENUM,
+    CPP_ENUM_CLASS,
+    CPP_ENUMERATOR,
     CPP_CONSTRUCTOR,
+    CPP_DESTRUCTOR,
     CPP_METHOD,
+    CPP_OPERATOR,
+    CPP_CONVERSION_OPERATOR,
+    CPP_TYPE_ALIAS,
+    CPP_NAMESPACE_ALIAS,
+    CPP_LAMBDA,
+    #[default]
     Default
 }
 
@@ -22,8 +34,16 @@ impl CodeType {
         type_codes.push(CodeType::CPP_PACKAGE.to_string());
         type_codes.push(CodeType::CPP_CLASS.to_string());
         type_codes.push(CodeType::CPP_ENUM.to_string());
+        type_codes.push(CodeType::CPP_ENUM_CLASS.to_string());
+        type_codes.push(CodeType::CPP_ENUMERATOR.to_string());
         type_codes.push(CodeType::CPP_CONSTRUCTOR.to_string());
+        type_codes.push(CodeType::CPP_DESTRUCTOR.to_string());
         type_codes.push(CodeType::CPP_METHOD.to_string());
+        type_codes.push(CodeType::CPP_OPERATOR.to_string());
+        type_codes.push(CodeType::CPP_CONVERSION_OPERATOR.to_string());
+        type_codes.push(CodeType::CPP_TYPE_ALIAS.to_string());
+        type_codes.push(CodeType::CPP_NAMESPACE_ALIAS.to_string());
+        type_codes.push(CodeType::CPP_LAMBDA.to_string());
         type_codes
     }
 }
@@ -33,15 +53,12 @@ impl Display for CodeType {
     }
 }
 
-impl Default for CodeType{
-    fn default() -> Self { CodeType::Default }
-}
-
-#[derive(Debug, Serialize)]
+#[derive(Debug, Default, Serialize)]
 pub enum MethodReturnType {
     Value,
     Reference,
     Pointer,
+    #[default]
     Default
 }
 
@@ -51,13 +68,10 @@ impl Display for MethodReturnType {
     }
 }
 
-impl Default for MethodReturnType{
-    fn default() -> Self { MethodReturnType::Default }
-}
-
-#[derive(Debug, Serialize)]
+#[derive(Debug, Default, Serialize)]
 pub enum ObjectType {
     Declaration,
+    #[default]
     Definition
 }
 
@@ -67,10 +81,6 @@ impl Display for ObjectType {
     }
 }
 
-impl Default for ObjectType{
-    fn default() -> Self { Definition }
-}
-
 
 /* Object data (field inheritance) */
 #[derive(Serialize, Default)]
@@ -80,7 +90,19 @@ pub struct ObjectData {
     line_number: usize,
     type_code: CodeType,
     children: Vec<Box<dyn CppObject>>,
-    modifiers: Vec<String>
+    modifiers: Vec<String>,
+    documentation: Option<Documentation>,
+    deprecated: bool,
+    deprecation_message: Option<String>,
+    visibility: Visibility,
+    body: Option<String>,
+    qualified_name: String,
+    template_parameters: Option<Vec<String>>,
+    template_arguments: Option<Vec<String>>,
+    aliased_type: Option<String>,
+    preprocessor_condition: Option<String>,
+    underlying_type: Option<String>,
+    value_expression: Option<String>
 }
 
 impl ObjectData {
@@ -91,7 +113,19 @@ impl ObjectData {
             line_number,
             type_code,
             children: vec![],
-            modifiers: vec![]
+            modifiers: vec![],
+            documentation: None,
+            deprecated: false,
+            deprecation_message: None,
+            visibility: Visibility::Public,
+            body: None,
+            qualified_name: String::new(),
+            template_parameters: None,
+            template_arguments: None,
+            aliased_type: None,
+            preprocessor_condition: None,
+            underlying_type: None,
+            value_expression: None
         }
     }
 
@@ -144,6 +178,118 @@ impl ObjectData {
         self.modifiers.push(modifier);
     }
 
+    fn documentation(&self) -> &Option<Documentation> {
+        &self.documentation
+    }
+
+    fn set_documentation(&mut self, documentation: Option<Documentation>) {
+        self.documentation = documentation;
+    }
+
+    fn take_documentation(&mut self) -> Option<Documentation> {
+        std::mem::take(&mut self.documentation)
+    }
+
+    fn deprecated(&self) -> bool {
+        self.deprecated
+    }
+
+    fn set_deprecated(&mut self, deprecated: bool) {
+        self.deprecated = deprecated;
+    }
+
+    fn deprecation_message(&self) -> &Option<String> {
+        &self.deprecation_message
+    }
+
+    fn set_deprecation_message(&mut self, deprecation_message: Option<String>) {
+        self.deprecation_message = deprecation_message;
+    }
+
+    fn take_deprecation_message(&mut self) -> Option<String> {
+        std::mem::take(&mut self.deprecation_message)
+    }
+
+    fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+
+    fn set_body(&mut self, body: Option<String>) {
+        self.body = body;
+    }
+
+    fn take_body(&mut self) -> Option<String> {
+        std::mem::take(&mut self.body)
+    }
+
+    fn qualified_name(&self) -> &String {
+        &self.qualified_name
+    }
+
+    fn set_qualified_name(&mut self, qualified_name: String) {
+        self.qualified_name = qualified_name;
+    }
+
+    fn take_qualified_name(&mut self) -> String {
+        std::mem::take(&mut self.qualified_name)
+    }
+
+    fn set_template_parameters(&mut self, template_parameters: Option<Vec<String>>) {
+        self.template_parameters = template_parameters;
+    }
+
+    fn take_template_parameters(&mut self) -> Option<Vec<String>> {
+        std::mem::take(&mut self.template_parameters)
+    }
+
+    fn set_template_arguments(&mut self, template_arguments: Option<Vec<String>>) {
+        self.template_arguments = template_arguments;
+    }
+
+    fn take_template_arguments(&mut self) -> Option<Vec<String>> {
+        std::mem::take(&mut self.template_arguments)
+    }
+
+    fn set_aliased_type(&mut self, aliased_type: Option<String>) {
+        self.aliased_type = aliased_type;
+    }
+
+    fn take_aliased_type(&mut self) -> Option<String> {
+        std::mem::take(&mut self.aliased_type)
+    }
+
+    fn preprocessor_condition(&self) -> &Option<String> {
+        &self.preprocessor_condition
+    }
+
+    fn set_preprocessor_condition(&mut self, preprocessor_condition: Option<String>) {
+        self.preprocessor_condition = preprocessor_condition;
+    }
+
+    fn take_preprocessor_condition(&mut self) -> Option<String> {
+        std::mem::take(&mut self.preprocessor_condition)
+    }
+
+    fn set_underlying_type(&mut self, underlying_type: Option<String>) {
+        self.underlying_type = underlying_type;
+    }
+
+    fn take_underlying_type(&mut self) -> Option<String> {
+        std::mem::take(&mut self.underlying_type)
+    }
+
+    fn set_value_expression(&mut self, value_expression: Option<String>) {
+        self.value_expression = value_expression;
+    }
+
+    fn take_value_expression(&mut self) -> Option<String> {
+        std::mem::take(&mut self.value_expression)
+    }
+
     fn take(self) -> (String, usize, CodeType, Vec<Box<dyn CppObject>>, Vec<String>) {
         (self.name, self.line_number, self.type_code, self.children, self.modifiers)
     }
@@ -182,6 +328,60 @@ pub trait CppObject: erased_serde::Serialize {
 
     fn add_modifier(& mut self, modifier: String);
 
+    fn documentation(&self) -> &Option<Documentation>;
+
+    fn set_documentation(&mut self, documentation: Option<Documentation>);
+
+    fn take_documentation(&mut self) -> Option<Documentation>;
+
+    fn deprecated(&self) -> bool;
+
+    fn set_deprecated(&mut self, deprecated: bool);
+
+    fn deprecation_message(&self) -> &Option<String>;
+
+    fn set_deprecation_message(&mut self, deprecation_message: Option<String>);
+
+    fn take_deprecation_message(&mut self) -> Option<String>;
+
+    fn visibility(&self) -> Visibility;
+
+    fn set_visibility(&mut self, visibility: Visibility);
+
+    fn set_body(&mut self, body: Option<String>);
+
+    fn take_body(&mut self) -> Option<String>;
+
+    fn qualified_name(&self) -> &String;
+
+    fn set_qualified_name(&mut self, qualified_name: String);
+
+    fn take_qualified_name(&mut self) -> String;
+
+    fn set_template_parameters(&mut self, template_parameters: Option<Vec<String>>);
+
+    fn take_template_parameters(&mut self) -> Option<Vec<String>>;
+
+    fn set_template_arguments(&mut self, template_arguments: Option<Vec<String>>);
+
+    fn take_template_arguments(&mut self) -> Option<Vec<String>>;
+
+    fn take_aliased_type(&mut self) -> Option<String>;
+
+    fn preprocessor_condition(&self) -> &Option<String>;
+
+    fn set_preprocessor_condition(&mut self, preprocessor_condition: Option<String>);
+
+    fn take_preprocessor_condition(&mut self) -> Option<String>;
+
+    fn set_underlying_type(&mut self, underlying_type: Option<String>);
+
+    fn take_underlying_type(&mut self) -> Option<String>;
+
+    fn set_value_expression(&mut self, value_expression: Option<String>);
+
+    fn take_value_expression(&mut self) -> Option<String>;
+
     fn to_json(&self) -> String;
 
 }
@@ -250,6 +450,114 @@ impl CppObject for PackageObject {
         self.object_data.add_modifier(modifier);
     }
 
+    fn documentation(&self) -> &Option<Documentation> {
+        self.object_data.documentation()
+    }
+
+    fn set_documentation(&mut self, documentation: Option<Documentation>) {
+        self.object_data.set_documentation(documentation)
+    }
+
+    fn take_documentation(&mut self) -> Option<Documentation> {
+        self.object_data.take_documentation()
+    }
+
+    fn deprecated(&self) -> bool {
+        self.object_data.deprecated()
+    }
+
+    fn set_deprecated(&mut self, deprecated: bool) {
+        self.object_data.set_deprecated(deprecated)
+    }
+
+    fn deprecation_message(&self) -> &Option<String> {
+        self.object_data.deprecation_message()
+    }
+
+    fn set_deprecation_message(&mut self, deprecation_message: Option<String>) {
+        self.object_data.set_deprecation_message(deprecation_message)
+    }
+
+    fn take_deprecation_message(&mut self) -> Option<String> {
+        self.object_data.take_deprecation_message()
+    }
+
+    fn visibility(&self) -> Visibility {
+        self.object_data.visibility()
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.object_data.set_visibility(visibility)
+    }
+
+    fn set_body(&mut self, body: Option<String>) {
+        self.object_data.set_body(body)
+    }
+
+    fn take_body(&mut self) -> Option<String> {
+        self.object_data.take_body()
+    }
+
+    fn qualified_name(&self) -> &String {
+        self.object_data.qualified_name()
+    }
+
+    fn set_qualified_name(&mut self, qualified_name: String) {
+        self.object_data.set_qualified_name(qualified_name)
+    }
+
+    fn take_qualified_name(&mut self) -> String {
+        self.object_data.take_qualified_name()
+    }
+
+    fn set_template_parameters(&mut self, template_parameters: Option<Vec<String>>) {
+        self.object_data.set_template_parameters(template_parameters)
+    }
+
+    fn take_template_parameters(&mut self) -> Option<Vec<String>> {
+        self.object_data.take_template_parameters()
+    }
+
+    fn set_template_arguments(&mut self, template_arguments: Option<Vec<String>>) {
+        self.object_data.set_template_arguments(template_arguments)
+    }
+
+    fn take_template_arguments(&mut self) -> Option<Vec<String>> {
+        self.object_data.take_template_arguments()
+    }
+
+    fn take_aliased_type(&mut self) -> Option<String> {
+        self.object_data.take_aliased_type()
+    }
+
+    fn preprocessor_condition(&self) -> &Option<String> {
+        self.object_data.preprocessor_condition()
+    }
+
+    fn set_preprocessor_condition(&mut self, preprocessor_condition: Option<String>) {
+        self.object_data.set_preprocessor_condition(preprocessor_condition)
+    }
+
+    fn take_preprocessor_condition(&mut self) -> Option<String> {
+        self.object_data.take_preprocessor_condition()
+    }
+
+    fn set_underlying_type(&mut self, underlying_type: Option<String>) {
+        self.object_data.set_underlying_type(underlying_type)
+    }
+
+    fn take_underlying_type(&mut self) -> Option<String> {
+        self.object_data.take_underlying_type()
+    }
+
+    fn set_value_expression(&mut self, value_expression: Option<String>) {
+        self.object_data.set_value_expression(value_expression)
+    }
+
+    fn take_value_expression(&mut self) -> Option<String> {
+        self.object_data.take_value_expression()
+    }
+
     fn to_json(&self) -> String {
         serde_json::to_string(self).unwrap_or("".to_string())
     }
@@ -264,7 +572,19 @@ impl PackageObject {
                 line_number: 0,
                 type_code: CodeType::CPP_PACKAGE,
                 children: vec![],
-                modifiers: vec![]
+                modifiers: vec![],
+                documentation: None,
+                deprecated: false,
+                deprecation_message: None,
+                visibility: Visibility::Public,
+                body: None,
+                qualified_name: String::new(),
+                template_parameters: None,
+                template_arguments: None,
+                aliased_type: None,
+                preprocessor_condition: None,
+                underlying_type: None,
+                value_expression: None
             }
         }
     }
@@ -351,6 +671,114 @@ impl CppObject for ClassObject {
         self.object_data.add_modifier(modifier);
     }
 
+    fn documentation(&self) -> &Option<Documentation> {
+        self.object_data.documentation()
+    }
+
+    fn set_documentation(&mut self, documentation: Option<Documentation>) {
+        self.object_data.set_documentation(documentation)
+    }
+
+    fn take_documentation(&mut self) -> Option<Documentation> {
+        self.object_data.take_documentation()
+    }
+
+    fn deprecated(&self) -> bool {
+        self.object_data.deprecated()
+    }
+
+    fn set_deprecated(&mut self, deprecated: bool) {
+        self.object_data.set_deprecated(deprecated)
+    }
+
+    fn deprecation_message(&self) -> &Option<String> {
+        self.object_data.deprecation_message()
+    }
+
+    fn set_deprecation_message(&mut self, deprecation_message: Option<String>) {
+        self.object_data.set_deprecation_message(deprecation_message)
+    }
+
+    fn take_deprecation_message(&mut self) -> Option<String> {
+        self.object_data.take_deprecation_message()
+    }
+
+    fn visibility(&self) -> Visibility {
+        self.object_data.visibility()
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.object_data.set_visibility(visibility)
+    }
+
+    fn set_body(&mut self, body: Option<String>) {
+        self.object_data.set_body(body)
+    }
+
+    fn take_body(&mut self) -> Option<String> {
+        self.object_data.take_body()
+    }
+
+    fn qualified_name(&self) -> &String {
+        self.object_data.qualified_name()
+    }
+
+    fn set_qualified_name(&mut self, qualified_name: String) {
+        self.object_data.set_qualified_name(qualified_name)
+    }
+
+    fn take_qualified_name(&mut self) -> String {
+        self.object_data.take_qualified_name()
+    }
+
+    fn set_template_parameters(&mut self, template_parameters: Option<Vec<String>>) {
+        self.object_data.set_template_parameters(template_parameters)
+    }
+
+    fn take_template_parameters(&mut self) -> Option<Vec<String>> {
+        self.object_data.take_template_parameters()
+    }
+
+    fn set_template_arguments(&mut self, template_arguments: Option<Vec<String>>) {
+        self.object_data.set_template_arguments(template_arguments)
+    }
+
+    fn take_template_arguments(&mut self) -> Option<Vec<String>> {
+        self.object_data.take_template_arguments()
+    }
+
+    fn take_aliased_type(&mut self) -> Option<String> {
+        self.object_data.take_aliased_type()
+    }
+
+    fn preprocessor_condition(&self) -> &Option<String> {
+        self.object_data.preprocessor_condition()
+    }
+
+    fn set_preprocessor_condition(&mut self, preprocessor_condition: Option<String>) {
+        self.object_data.set_preprocessor_condition(preprocessor_condition)
+    }
+
+    fn take_preprocessor_condition(&mut self) -> Option<String> {
+        self.object_data.take_preprocessor_condition()
+    }
+
+    fn set_underlying_type(&mut self, underlying_type: Option<String>) {
+        self.object_data.set_underlying_type(underlying_type)
+    }
+
+    fn take_underlying_type(&mut self) -> Option<String> {
+        self.object_data.take_underlying_type()
+    }
+
+    fn set_value_expression(&mut self, value_expression: Option<String>) {
+        self.object_data.set_value_expression(value_expression)
+    }
+
+    fn take_value_expression(&mut self) -> Option<String> {
+        self.object_data.take_value_expression()
+    }
+
     fn to_json(&self) -> String {
         serde_json::to_string(self).unwrap_or(String::new())
     }
@@ -372,6 +800,33 @@ impl ClassObject {
         }
     }
 
+    /* Scoped `enum class` or `enum struct` */
+    pub fn new_enum_class(name: String, line_number: usize) -> Self {
+        Self {
+            object_data: ObjectData::new(name, CPP_ENUM_CLASS, line_number),
+            class_type: Declaration
+        }
+    }
+
+    pub fn new_type_alias(name: String, aliased_type: String, line_number: usize) -> Self {
+        let mut object_data = ObjectData::new(name, CPP_TYPE_ALIAS, line_number);
+        object_data.set_aliased_type(Some(aliased_type));
+        Self {
+            object_data,
+            class_type: Declaration
+        }
+    }
+
+    /* `namespace fs = std::filesystem;` with aliased namespace as aliasedType */
+    pub fn new_namespace_alias(name: String, aliased_namespace: String, line_number: usize) -> Self {
+        let mut object_data = ObjectData::new(name, CPP_NAMESPACE_ALIAS, line_number);
+        object_data.set_aliased_type(Some(aliased_namespace));
+        Self {
+            object_data,
+            class_type: Declaration
+        }
+    }
+
     pub fn set_object_type(& mut self, object_type: ObjectType){
         self.class_type = object_type;
     }
@@ -463,6 +918,114 @@ impl CppObject for MethodObject {
         self.object_data.add_modifier(modifier);
     }
 
+    fn documentation(&self) -> &Option<Documentation> {
+        self.object_data.documentation()
+    }
+
+    fn set_documentation(&mut self, documentation: Option<Documentation>) {
+        self.object_data.set_documentation(documentation)
+    }
+
+    fn take_documentation(&mut self) -> Option<Documentation> {
+        self.object_data.take_documentation()
+    }
+
+    fn deprecated(&self) -> bool {
+        self.object_data.deprecated()
+    }
+
+    fn set_deprecated(&mut self, deprecated: bool) {
+        self.object_data.set_deprecated(deprecated)
+    }
+
+    fn deprecation_message(&self) -> &Option<String> {
+        self.object_data.deprecation_message()
+    }
+
+    fn set_deprecation_message(&mut self, deprecation_message: Option<String>) {
+        self.object_data.set_deprecation_message(deprecation_message)
+    }
+
+    fn take_deprecation_message(&mut self) -> Option<String> {
+        self.object_data.take_deprecation_message()
+    }
+
+    fn visibility(&self) -> Visibility {
+        self.object_data.visibility()
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.object_data.set_visibility(visibility)
+    }
+
+    fn set_body(&mut self, body: Option<String>) {
+        self.object_data.set_body(body)
+    }
+
+    fn take_body(&mut self) -> Option<String> {
+        self.object_data.take_body()
+    }
+
+    fn qualified_name(&self) -> &String {
+        self.object_data.qualified_name()
+    }
+
+    fn set_qualified_name(&mut self, qualified_name: String) {
+        self.object_data.set_qualified_name(qualified_name)
+    }
+
+    fn take_qualified_name(&mut self) -> String {
+        self.object_data.take_qualified_name()
+    }
+
+    fn set_template_parameters(&mut self, template_parameters: Option<Vec<String>>) {
+        self.object_data.set_template_parameters(template_parameters)
+    }
+
+    fn take_template_parameters(&mut self) -> Option<Vec<String>> {
+        self.object_data.take_template_parameters()
+    }
+
+    fn set_template_arguments(&mut self, template_arguments: Option<Vec<String>>) {
+        self.object_data.set_template_arguments(template_arguments)
+    }
+
+    fn take_template_arguments(&mut self) -> Option<Vec<String>> {
+        self.object_data.take_template_arguments()
+    }
+
+    fn take_aliased_type(&mut self) -> Option<String> {
+        self.object_data.take_aliased_type()
+    }
+
+    fn preprocessor_condition(&self) -> &Option<String> {
+        self.object_data.preprocessor_condition()
+    }
+
+    fn set_preprocessor_condition(&mut self, preprocessor_condition: Option<String>) {
+        self.object_data.set_preprocessor_condition(preprocessor_condition)
+    }
+
+    fn take_preprocessor_condition(&mut self) -> Option<String> {
+        self.object_data.take_preprocessor_condition()
+    }
+
+    fn set_underlying_type(&mut self, underlying_type: Option<String>) {
+        self.object_data.set_underlying_type(underlying_type)
+    }
+
+    fn take_underlying_type(&mut self) -> Option<String> {
+        self.object_data.take_underlying_type()
+    }
+
+    fn set_value_expression(&mut self, value_expression: Option<String>) {
+        self.object_data.set_value_expression(value_expression)
+    }
+
+    fn take_value_expression(&mut self) -> Option<String> {
+        self.object_data.take_value_expression()
+    }
+
     fn to_json(&self) -> String {
         serde_json::to_string(self).unwrap_or("None".parse().unwrap())
     }
@@ -475,7 +1038,7 @@ impl MethodObject {
                line_code: usize, method_type: ObjectType) -> Self {
 
         Self {
-            object_data: ObjectData { name, line_number: line_code, type_code, children: vec![], modifiers: vec![] },
+            object_data: ObjectData { name, line_number: line_code, type_code, children: vec![], modifiers: vec![], documentation: None, deprecated: false, deprecation_message: None, visibility: Visibility::Public, body: None, qualified_name: String::new(), template_parameters: None, template_arguments: None, aliased_type: None, preprocessor_condition: None, underlying_type: None, value_expression: None },
             namespace,
             parameters,
             output_parameter,
@@ -485,6 +1048,13 @@ impl MethodObject {
 
     }
 
+    pub fn new_enumerator(name: String, value_expression: Option<String>, line_code: usize) -> Self {
+        let mut method_object = Self::new(name, CPP_ENUMERATOR, String::new(), vec![], String::new(),
+                                          MethodReturnType::Value, line_code, Definition);
+        method_object.set_value_expression(value_expression);
+        method_object
+    }
+
     pub fn take(self) -> (String, usize, CodeType, Vec<Box<dyn CppObject>>,
                           Vec<String>, Vec<String>, String, String, MethodReturnType, ObjectType) {
 
@@ -2,6 +2,8 @@ use serde::{Serialize};
 use erased_serde::serialize_trait_object;
 use std::any::Any;
 use std::fmt::{Formatter, Debug, Display, Result as FormatResult};
+use crate::dto::documentation::Documentation;
+use crate::dto::visibility::Visibility;
 
 #[derive(Debug, Serialize, PartialEq)]
 #[allow(non_camel_case_types)]
@@ -47,7 +49,12 @@ struct ObjectData {
     line_code: usize,
     type_code: CodeType,
     children: Vec<Box<dyn JavaObject>>,
-    modifiers: Vec<String>
+    modifiers: Vec<String>,
+    documentation: Option<Documentation>,
+    deprecated: bool,
+    deprecation_message: Option<String>,
+    visibility: Visibility,
+    body: Option<String>
 }
 
 impl ObjectData {
@@ -58,7 +65,12 @@ impl ObjectData {
             type_code,
             children: vec![],
             name: String::new(),
-            modifiers: vec![]
+            modifiers: vec![],
+            documentation: None,
+            deprecated: false,
+            deprecation_message: None,
+            visibility: Visibility::Public,
+            body: None
         }
     }
     
@@ -68,7 +80,12 @@ impl ObjectData {
             type_code,
             children: vec![],
             name,
-            modifiers: vec![]
+            modifiers: vec![],
+            documentation: None,
+            deprecated: false,
+            deprecation_message: None,
+            visibility: Visibility::Public,
+            body: None
         }
     }
 
@@ -117,6 +134,54 @@ impl ObjectData {
         self.modifiers.push(modifier);
     }
 
+    fn documentation(&self) -> &Option<Documentation> {
+        &self.documentation
+    }
+
+    fn set_documentation(&mut self, documentation: Option<Documentation>) {
+        self.documentation = documentation;
+    }
+
+    fn take_documentation(&mut self) -> Option<Documentation> {
+        std::mem::take(&mut self.documentation)
+    }
+
+    fn deprecated(&self) -> bool {
+        self.deprecated
+    }
+
+    fn set_deprecated(&mut self, deprecated: bool) {
+        self.deprecated = deprecated;
+    }
+
+    fn deprecation_message(&self) -> &Option<String> {
+        &self.deprecation_message
+    }
+
+    fn set_deprecation_message(&mut self, deprecation_message: Option<String>) {
+        self.deprecation_message = deprecation_message;
+    }
+
+    fn take_deprecation_message(&mut self) -> Option<String> {
+        std::mem::take(&mut self.deprecation_message)
+    }
+
+    fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+
+    fn set_body(&mut self, body: Option<String>) {
+        self.body = body;
+    }
+
+    fn take_body(&mut self) -> Option<String> {
+        std::mem::take(&mut self.body)
+    }
+
     fn take(self) -> (String, usize, CodeType, Vec<Box<dyn JavaObject>>, Vec<String>) {
         (self.name, self.line_code, self.type_code, self.children, self.modifiers)
     }
@@ -156,6 +221,30 @@ pub trait JavaObject: erased_serde::Serialize {
 
     fn add_modifier(& mut self, modifier: String);
 
+    fn documentation(&self) -> &Option<Documentation>;
+
+    fn set_documentation(&mut self, documentation: Option<Documentation>);
+
+    fn take_documentation(&mut self) -> Option<Documentation>;
+
+    fn deprecated(&self) -> bool;
+
+    fn set_deprecated(&mut self, deprecated: bool);
+
+    fn deprecation_message(&self) -> &Option<String>;
+
+    fn set_deprecation_message(&mut self, deprecation_message: Option<String>);
+
+    fn take_deprecation_message(&mut self) -> Option<String>;
+
+    fn visibility(&self) -> Visibility;
+
+    fn set_visibility(&mut self, visibility: Visibility);
+
+    fn set_body(&mut self, body: Option<String>);
+
+    fn take_body(&mut self) -> Option<String>;
+
     fn to_json(&self) -> String;
 }
 
@@ -225,6 +314,54 @@ impl JavaObject for PackageObject {
         self.object_data.add_modifier(modifier);
     }
 
+    fn documentation(&self) -> &Option<Documentation> {
+        self.object_data.documentation()
+    }
+
+    fn set_documentation(&mut self, documentation: Option<Documentation>) {
+        self.object_data.set_documentation(documentation)
+    }
+
+    fn take_documentation(&mut self) -> Option<Documentation> {
+        self.object_data.take_documentation()
+    }
+
+    fn deprecated(&self) -> bool {
+        self.object_data.deprecated()
+    }
+
+    fn set_deprecated(&mut self, deprecated: bool) {
+        self.object_data.set_deprecated(deprecated)
+    }
+
+    fn deprecation_message(&self) -> &Option<String> {
+        self.object_data.deprecation_message()
+    }
+
+    fn set_deprecation_message(&mut self, deprecation_message: Option<String>) {
+        self.object_data.set_deprecation_message(deprecation_message)
+    }
+
+    fn take_deprecation_message(&mut self) -> Option<String> {
+        self.object_data.take_deprecation_message()
+    }
+
+    fn visibility(&self) -> Visibility {
+        self.object_data.visibility()
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.object_data.set_visibility(visibility)
+    }
+
+    fn set_body(&mut self, body: Option<String>) {
+        self.object_data.set_body(body)
+    }
+
+    fn take_body(&mut self) -> Option<String> {
+        self.object_data.take_body()
+    }
+
     fn to_json(&self) -> String {
         serde_json::to_string(self).unwrap_or("None".parse().unwrap())
     }
@@ -308,6 +445,54 @@ impl JavaObject for ClassObject {
         self.object_data.add_modifier(modifier);
     }
 
+    fn documentation(&self) -> &Option<Documentation> {
+        self.object_data.documentation()
+    }
+
+    fn set_documentation(&mut self, documentation: Option<Documentation>) {
+        self.object_data.set_documentation(documentation)
+    }
+
+    fn take_documentation(&mut self) -> Option<Documentation> {
+        self.object_data.take_documentation()
+    }
+
+    fn deprecated(&self) -> bool {
+        self.object_data.deprecated()
+    }
+
+    fn set_deprecated(&mut self, deprecated: bool) {
+        self.object_data.set_deprecated(deprecated)
+    }
+
+    fn deprecation_message(&self) -> &Option<String> {
+        self.object_data.deprecation_message()
+    }
+
+    fn set_deprecation_message(&mut self, deprecation_message: Option<String>) {
+        self.object_data.set_deprecation_message(deprecation_message)
+    }
+
+    fn take_deprecation_message(&mut self) -> Option<String> {
+        self.object_data.take_deprecation_message()
+    }
+
+    fn visibility(&self) -> Visibility {
+        self.object_data.visibility()
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.object_data.set_visibility(visibility)
+    }
+
+    fn set_body(&mut self, body: Option<String>) {
+        self.object_data.set_body(body)
+    }
+
+    fn take_body(&mut self) -> Option<String> {
+        self.object_data.take_body()
+    }
+
     fn to_json(&self) -> String {
         serde_json::to_string(self).unwrap_or(String::new())
     }
@@ -390,6 +575,54 @@ impl JavaObject for MethodObject {
         self.object_data.add_modifier(modifier);
     }
 
+    fn documentation(&self) -> &Option<Documentation> {
+        self.object_data.documentation()
+    }
+
+    fn set_documentation(&mut self, documentation: Option<Documentation>) {
+        self.object_data.set_documentation(documentation)
+    }
+
+    fn take_documentation(&mut self) -> Option<Documentation> {
+        self.object_data.take_documentation()
+    }
+
+    fn deprecated(&self) -> bool {
+        self.object_data.deprecated()
+    }
+
+    fn set_deprecated(&mut self, deprecated: bool) {
+        self.object_data.set_deprecated(deprecated)
+    }
+
+    fn deprecation_message(&self) -> &Option<String> {
+        self.object_data.deprecation_message()
+    }
+
+    fn set_deprecation_message(&mut self, deprecation_message: Option<String>) {
+        self.object_data.set_deprecation_message(deprecation_message)
+    }
+
+    fn take_deprecation_message(&mut self) -> Option<String> {
+        self.object_data.take_deprecation_message()
+    }
+
+    fn visibility(&self) -> Visibility {
+        self.object_data.visibility()
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.object_data.set_visibility(visibility)
+    }
+
+    fn set_body(&mut self, body: Option<String>) {
+        self.object_data.set_body(body)
+    }
+
+    fn take_body(&mut self) -> Option<String> {
+        self.object_data.take_body()
+    }
+
     fn to_json(&self) -> String {
         serde_json::to_string(self).unwrap_or("None".parse().unwrap())
     }
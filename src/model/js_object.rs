@@ -2,6 +2,8 @@ use serde::{Serialize};
 use erased_serde::serialize_trait_object;
 use std::any::Any;
 use std::fmt::{Display, Formatter, Debug, Result as FormatResult};
+use crate::dto::documentation::Documentation;
+use crate::dto::visibility::Visibility;
 
 #[derive(Debug, Serialize, PartialEq)]
 #[allow(non_camel_case_types)]
@@ -42,7 +44,13 @@ struct ObjectData {
     name: String,
     line_code: usize,
     type_code: CodeType,
-    children: Vec<Box<dyn JsObject>>
+    children: Vec<Box<dyn JsObject>>,
+    documentation: Option<Documentation>,
+    deprecated: bool,
+    deprecation_message: Option<String>,
+    visibility: Visibility,
+    modifiers: Vec<String>,
+    body: Option<String>
 }
 
 impl ObjectData {
@@ -53,6 +61,12 @@ impl ObjectData {
             type_code,
             children: vec![],
             name,
+            documentation: None,
+            deprecated: false,
+            deprecation_message: None,
+            visibility: Visibility::Public,
+            modifiers: vec![],
+            body: None,
         }
     }
 
@@ -92,6 +106,62 @@ impl ObjectData {
         self.line_code
     }
 
+    fn documentation(&self) -> &Option<Documentation> {
+        &self.documentation
+    }
+
+    fn set_documentation(&mut self, documentation: Option<Documentation>) {
+        self.documentation = documentation;
+    }
+
+    fn take_documentation(&mut self) -> Option<Documentation> {
+        std::mem::take(&mut self.documentation)
+    }
+
+    fn deprecated(&self) -> bool {
+        self.deprecated
+    }
+
+    fn set_deprecated(&mut self, deprecated: bool) {
+        self.deprecated = deprecated;
+    }
+
+    fn deprecation_message(&self) -> &Option<String> {
+        &self.deprecation_message
+    }
+
+    fn set_deprecation_message(&mut self, deprecation_message: Option<String>) {
+        self.deprecation_message = deprecation_message;
+    }
+
+    fn take_deprecation_message(&mut self) -> Option<String> {
+        std::mem::take(&mut self.deprecation_message)
+    }
+
+    fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+
+    fn set_modifiers(&mut self, modifiers: Vec<String>) {
+        self.modifiers = modifiers;
+    }
+
+    fn take_modifiers(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.modifiers)
+    }
+
+    fn set_body(&mut self, body: Option<String>) {
+        self.body = body;
+    }
+
+    fn take_body(&mut self) -> Option<String> {
+        std::mem::take(&mut self.body)
+    }
+
     fn take(self) -> (String, usize, CodeType, Vec<Box<dyn JsObject>>) {
         (self.name, self.line_code, self.type_code, self.children)
     }
@@ -123,6 +193,34 @@ pub trait JsObject: erased_serde::Serialize {
 
     fn get_line_code(&self) -> usize;
 
+    fn documentation(&self) -> &Option<Documentation>;
+
+    fn set_documentation(&mut self, documentation: Option<Documentation>);
+
+    fn take_documentation(&mut self) -> Option<Documentation>;
+
+    fn deprecated(&self) -> bool;
+
+    fn set_deprecated(&mut self, deprecated: bool);
+
+    fn deprecation_message(&self) -> &Option<String>;
+
+    fn set_deprecation_message(&mut self, deprecation_message: Option<String>);
+
+    fn take_deprecation_message(&mut self) -> Option<String>;
+
+    fn visibility(&self) -> Visibility;
+
+    fn set_visibility(&mut self, visibility: Visibility);
+
+    fn set_modifiers(&mut self, modifiers: Vec<String>);
+
+    fn take_modifiers(&mut self) -> Vec<String>;
+
+    fn set_body(&mut self, body: Option<String>);
+
+    fn take_body(&mut self) -> Option<String>;
+
     fn to_json(&self) -> String;
 
     fn to_any(self: Box<Self>) -> Box<dyn Any>;
@@ -184,6 +282,62 @@ impl JsObject for PackageObject {
         self.object_data.get_line_code()
     }
 
+    fn documentation(&self) -> &Option<Documentation> {
+        self.object_data.documentation()
+    }
+
+    fn set_documentation(&mut self, documentation: Option<Documentation>) {
+        self.object_data.set_documentation(documentation)
+    }
+
+    fn take_documentation(&mut self) -> Option<Documentation> {
+        self.object_data.take_documentation()
+    }
+
+    fn deprecated(&self) -> bool {
+        self.object_data.deprecated()
+    }
+
+    fn set_deprecated(&mut self, deprecated: bool) {
+        self.object_data.set_deprecated(deprecated)
+    }
+
+    fn deprecation_message(&self) -> &Option<String> {
+        self.object_data.deprecation_message()
+    }
+
+    fn set_deprecation_message(&mut self, deprecation_message: Option<String>) {
+        self.object_data.set_deprecation_message(deprecation_message)
+    }
+
+    fn take_deprecation_message(&mut self) -> Option<String> {
+        self.object_data.take_deprecation_message()
+    }
+
+    fn visibility(&self) -> Visibility {
+        self.object_data.visibility()
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.object_data.set_visibility(visibility)
+    }
+
+    fn set_modifiers(&mut self, modifiers: Vec<String>) {
+        self.object_data.set_modifiers(modifiers)
+    }
+
+    fn take_modifiers(&mut self) -> Vec<String> {
+        self.object_data.take_modifiers()
+    }
+
+    fn set_body(&mut self, body: Option<String>) {
+        self.object_data.set_body(body)
+    }
+
+    fn take_body(&mut self) -> Option<String> {
+        self.object_data.take_body()
+    }
+
     fn to_json(&self) -> String {
         serde_json::to_string_pretty(self).unwrap_or("None".parse().unwrap())
     }
@@ -267,6 +421,62 @@ impl JsObject for ClassObject {
         self.object_data.get_line_code()
     }
 
+    fn documentation(&self) -> &Option<Documentation> {
+        self.object_data.documentation()
+    }
+
+    fn set_documentation(&mut self, documentation: Option<Documentation>) {
+        self.object_data.set_documentation(documentation)
+    }
+
+    fn take_documentation(&mut self) -> Option<Documentation> {
+        self.object_data.take_documentation()
+    }
+
+    fn deprecated(&self) -> bool {
+        self.object_data.deprecated()
+    }
+
+    fn set_deprecated(&mut self, deprecated: bool) {
+        self.object_data.set_deprecated(deprecated)
+    }
+
+    fn deprecation_message(&self) -> &Option<String> {
+        self.object_data.deprecation_message()
+    }
+
+    fn set_deprecation_message(&mut self, deprecation_message: Option<String>) {
+        self.object_data.set_deprecation_message(deprecation_message)
+    }
+
+    fn take_deprecation_message(&mut self) -> Option<String> {
+        self.object_data.take_deprecation_message()
+    }
+
+    fn visibility(&self) -> Visibility {
+        self.object_data.visibility()
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.object_data.set_visibility(visibility)
+    }
+
+    fn set_modifiers(&mut self, modifiers: Vec<String>) {
+        self.object_data.set_modifiers(modifiers)
+    }
+
+    fn take_modifiers(&mut self) -> Vec<String> {
+        self.object_data.take_modifiers()
+    }
+
+    fn set_body(&mut self, body: Option<String>) {
+        self.object_data.set_body(body)
+    }
+
+    fn take_body(&mut self) -> Option<String> {
+        self.object_data.take_body()
+    }
+
     fn to_json(&self) -> String {
         serde_json::to_string_pretty(self).unwrap_or("None".parse().unwrap())
     }
@@ -352,6 +562,62 @@ impl JsObject for MethodObject {
         self.object_data.get_line_code()
     }
 
+    fn documentation(&self) -> &Option<Documentation> {
+        self.object_data.documentation()
+    }
+
+    fn set_documentation(&mut self, documentation: Option<Documentation>) {
+        self.object_data.set_documentation(documentation)
+    }
+
+    fn take_documentation(&mut self) -> Option<Documentation> {
+        self.object_data.take_documentation()
+    }
+
+    fn deprecated(&self) -> bool {
+        self.object_data.deprecated()
+    }
+
+    fn set_deprecated(&mut self, deprecated: bool) {
+        self.object_data.set_deprecated(deprecated)
+    }
+
+    fn deprecation_message(&self) -> &Option<String> {
+        self.object_data.deprecation_message()
+    }
+
+    fn set_deprecation_message(&mut self, deprecation_message: Option<String>) {
+        self.object_data.set_deprecation_message(deprecation_message)
+    }
+
+    fn take_deprecation_message(&mut self) -> Option<String> {
+        self.object_data.take_deprecation_message()
+    }
+
+    fn visibility(&self) -> Visibility {
+        self.object_data.visibility()
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.object_data.set_visibility(visibility)
+    }
+
+    fn set_modifiers(&mut self, modifiers: Vec<String>) {
+        self.object_data.set_modifiers(modifiers)
+    }
+
+    fn take_modifiers(&mut self) -> Vec<String> {
+        self.object_data.take_modifiers()
+    }
+
+    fn set_body(&mut self, body: Option<String>) {
+        self.object_data.set_body(body)
+    }
+
+    fn take_body(&mut self) -> Option<String> {
+        self.object_data.take_body()
+    }
+
     fn to_json(&self) -> String {
         serde_json::to_string_pretty(self).unwrap_or("None".parse().unwrap())
     }
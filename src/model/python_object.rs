@@ -2,6 +2,12 @@ use std::fmt::{Formatter, Debug, Display, Result as FormatResult};
 use erased_serde::serialize_trait_object;
 use std::any::Any;
 use serde::Serialize;
+use crate::dto::documentation::Documentation;
+use crate::dto::visibility::Visibility;
+use crate::dto::method_kind::MethodKind;
+use crate::dto::class_kind::ClassKind;
+use crate::dto::definition::DeclarationKind;
+use crate::dto::route::Route;
 
 #[derive(Debug, Serialize, PartialEq)]
 #[allow(non_camel_case_types)]
@@ -11,6 +17,11 @@ pub enum CodeType {
     PYTHON_ENUM,
     PYTHON_CONSTRUCTOR,
     PYTHON_METHOD,
+    PYTHON_REEXPORT,
+    PYTHON_ROUTE,
+    PYTHON_VARIABLE,
+    PYTHON_CONSTANT,
+    PYTHON_TYPE_ALIAS,
     DEFAULT
 }
 
@@ -21,7 +32,12 @@ impl CodeType {
             CodeType::PYTHON_PACKAGE.to_string(),
             CodeType::PYTHON_CLASS.to_string(),
             CodeType::PYTHON_ENUM.to_string(),
-            CodeType::PYTHON_CONSTRUCTOR.to_string()
+            CodeType::PYTHON_CONSTRUCTOR.to_string(),
+            CodeType::PYTHON_REEXPORT.to_string(),
+            CodeType::PYTHON_ROUTE.to_string(),
+            CodeType::PYTHON_VARIABLE.to_string(),
+            CodeType::PYTHON_CONSTANT.to_string(),
+            CodeType::PYTHON_TYPE_ALIAS.to_string()
         ]
     }
 }
@@ -43,6 +59,21 @@ pub struct ObjectData {
     line_number: usize,
     type_code: CodeType,
     children: Vec<Box<dyn PythonObject>>,
+    documentation: Option<Documentation>,
+    deprecated: bool,
+    deprecation_message: Option<String>,
+    visibility: Visibility,
+    modifiers: Vec<String>,
+    body: Option<String>,
+    reexported_from: Option<String>,
+    decorators: Vec<String>,
+    method_kind: Option<MethodKind>,
+    route: Option<Route>,
+    annotation_type: Option<String>,
+    aliased_type: Option<String>,
+    class_kind: Option<ClassKind>,
+    declaration_kind: Option<DeclarationKind>,
+    signature: Option<String>
 }
 
 impl ObjectData {
@@ -52,7 +83,22 @@ impl ObjectData {
             line_number: 0,
             type_code,
             children: vec![],
-            name
+            name,
+            documentation: None,
+            deprecated: false,
+            deprecation_message: None,
+            visibility: Visibility::Public,
+            modifiers: vec![],
+            body: None,
+            reexported_from: None,
+            decorators: vec![],
+            method_kind: None,
+            route: None,
+            annotation_type: None,
+            aliased_type: None,
+            class_kind: None,
+            declaration_kind: None,
+            signature: None
         }
     }
 
@@ -93,6 +139,134 @@ impl ObjectData {
         self.line_number
     }
 
+    fn documentation(&self) -> &Option<Documentation> {
+        &self.documentation
+    }
+
+    fn set_documentation(&mut self, documentation: Option<Documentation>) {
+        self.documentation = documentation;
+    }
+
+    fn take_documentation(&mut self) -> Option<Documentation> {
+        std::mem::take(&mut self.documentation)
+    }
+
+    fn deprecated(&self) -> bool {
+        self.deprecated
+    }
+
+    fn set_deprecated(&mut self, deprecated: bool) {
+        self.deprecated = deprecated;
+    }
+
+    fn deprecation_message(&self) -> &Option<String> {
+        &self.deprecation_message
+    }
+
+    fn set_deprecation_message(&mut self, deprecation_message: Option<String>) {
+        self.deprecation_message = deprecation_message;
+    }
+
+    fn take_deprecation_message(&mut self) -> Option<String> {
+        std::mem::take(&mut self.deprecation_message)
+    }
+
+    fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+
+    fn set_modifiers(&mut self, modifiers: Vec<String>) {
+        self.modifiers = modifiers;
+    }
+
+    fn take_modifiers(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.modifiers)
+    }
+
+    fn set_body(&mut self, body: Option<String>) {
+        self.body = body;
+    }
+
+    fn take_body(&mut self) -> Option<String> {
+        std::mem::take(&mut self.body)
+    }
+
+    fn set_reexported_from(&mut self, reexported_from: Option<String>) {
+        self.reexported_from = reexported_from;
+    }
+
+    fn take_reexported_from(&mut self) -> Option<String> {
+        std::mem::take(&mut self.reexported_from)
+    }
+
+    fn set_decorators(&mut self, decorators: Vec<String>) {
+        self.decorators = decorators;
+    }
+
+    fn take_decorators(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.decorators)
+    }
+
+    fn set_method_kind(&mut self, method_kind: Option<MethodKind>) {
+        self.method_kind = method_kind;
+    }
+
+    fn take_method_kind(&mut self) -> Option<MethodKind> {
+        std::mem::take(&mut self.method_kind)
+    }
+
+    fn set_route(&mut self, route: Option<Route>) {
+        self.route = route;
+    }
+
+    fn take_route(&mut self) -> Option<Route> {
+        std::mem::take(&mut self.route)
+    }
+
+    fn set_annotation_type(&mut self, annotation_type: Option<String>) {
+        self.annotation_type = annotation_type;
+    }
+
+    fn take_annotation_type(&mut self) -> Option<String> {
+        std::mem::take(&mut self.annotation_type)
+    }
+
+    fn set_aliased_type(&mut self, aliased_type: Option<String>) {
+        self.aliased_type = aliased_type;
+    }
+
+    fn take_aliased_type(&mut self) -> Option<String> {
+        std::mem::take(&mut self.aliased_type)
+    }
+
+    fn set_class_kind(&mut self, class_kind: Option<ClassKind>) {
+        self.class_kind = class_kind;
+    }
+
+    fn take_class_kind(&mut self) -> Option<ClassKind> {
+        std::mem::take(&mut self.class_kind)
+    }
+
+    fn set_declaration_kind(&mut self, declaration_kind: Option<DeclarationKind>) {
+        self.declaration_kind = declaration_kind;
+    }
+
+    fn take_declaration_kind(&mut self) -> Option<DeclarationKind> {
+        std::mem::take(&mut self.declaration_kind)
+    }
+
+    fn set_signature(&mut self, signature: Option<String>) {
+        self.signature = signature;
+    }
+
+    fn take_signature(&mut self) -> Option<String> {
+        std::mem::take(&mut self.signature)
+    }
+
     fn take(self) -> (String, usize, CodeType, Vec<Box<dyn PythonObject>>) {
         (self.name, self.line_number, self.type_code, self.children)
     }
@@ -126,6 +300,70 @@ pub trait PythonObject: erased_serde::Serialize {
 
     fn get_line_number(&self) -> usize;
 
+    fn documentation(&self) -> &Option<Documentation>;
+
+    fn set_documentation(&mut self, documentation: Option<Documentation>);
+
+    fn take_documentation(&mut self) -> Option<Documentation>;
+
+    fn deprecated(&self) -> bool;
+
+    fn set_deprecated(&mut self, deprecated: bool);
+
+    fn deprecation_message(&self) -> &Option<String>;
+
+    fn set_deprecation_message(&mut self, deprecation_message: Option<String>);
+
+    fn take_deprecation_message(&mut self) -> Option<String>;
+
+    fn visibility(&self) -> Visibility;
+
+    fn set_visibility(&mut self, visibility: Visibility);
+
+    fn set_modifiers(&mut self, modifiers: Vec<String>);
+
+    fn take_modifiers(&mut self) -> Vec<String>;
+
+    fn set_body(&mut self, body: Option<String>);
+
+    fn take_body(&mut self) -> Option<String>;
+
+    fn set_reexported_from(&mut self, reexported_from: Option<String>);
+
+    fn take_reexported_from(&mut self) -> Option<String>;
+
+    fn set_decorators(&mut self, decorators: Vec<String>);
+
+    fn take_decorators(&mut self) -> Vec<String>;
+
+    fn set_method_kind(&mut self, method_kind: Option<MethodKind>);
+
+    fn take_method_kind(&mut self) -> Option<MethodKind>;
+
+    fn set_route(&mut self, route: Option<Route>);
+
+    fn take_route(&mut self) -> Option<Route>;
+
+    fn set_annotation_type(&mut self, annotation_type: Option<String>);
+
+    fn take_annotation_type(&mut self) -> Option<String>;
+
+    fn set_aliased_type(&mut self, aliased_type: Option<String>);
+
+    fn take_aliased_type(&mut self) -> Option<String>;
+
+    fn set_class_kind(&mut self, class_kind: Option<ClassKind>);
+
+    fn take_class_kind(&mut self) -> Option<ClassKind>;
+
+    fn set_declaration_kind(&mut self, declaration_kind: Option<DeclarationKind>);
+
+    fn take_declaration_kind(&mut self) -> Option<DeclarationKind>;
+
+    fn set_signature(&mut self, signature: Option<String>);
+
+    fn take_signature(&mut self) -> Option<String>;
+
     fn to_json(&self) -> String;
 
 }
@@ -179,6 +417,134 @@ impl PythonObject for PackageObject {
         self.object_data.get_line_number()
     }
     
+    fn documentation(&self) -> &Option<Documentation> {
+        self.object_data.documentation()
+    }
+
+    fn set_documentation(&mut self, documentation: Option<Documentation>) {
+        self.object_data.set_documentation(documentation)
+    }
+
+    fn take_documentation(&mut self) -> Option<Documentation> {
+        self.object_data.take_documentation()
+    }
+
+    fn deprecated(&self) -> bool {
+        self.object_data.deprecated()
+    }
+
+    fn set_deprecated(&mut self, deprecated: bool) {
+        self.object_data.set_deprecated(deprecated)
+    }
+
+    fn deprecation_message(&self) -> &Option<String> {
+        self.object_data.deprecation_message()
+    }
+
+    fn set_deprecation_message(&mut self, deprecation_message: Option<String>) {
+        self.object_data.set_deprecation_message(deprecation_message)
+    }
+
+    fn take_deprecation_message(&mut self) -> Option<String> {
+        self.object_data.take_deprecation_message()
+    }
+
+    fn visibility(&self) -> Visibility {
+        self.object_data.visibility()
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.object_data.set_visibility(visibility)
+    }
+
+    fn set_modifiers(&mut self, modifiers: Vec<String>) {
+        self.object_data.set_modifiers(modifiers)
+    }
+
+    fn take_modifiers(&mut self) -> Vec<String> {
+        self.object_data.take_modifiers()
+    }
+
+    fn set_body(&mut self, body: Option<String>) {
+        self.object_data.set_body(body)
+    }
+
+    fn take_body(&mut self) -> Option<String> {
+        self.object_data.take_body()
+    }
+
+    fn set_reexported_from(&mut self, reexported_from: Option<String>) {
+        self.object_data.set_reexported_from(reexported_from)
+    }
+
+    fn take_reexported_from(&mut self) -> Option<String> {
+        self.object_data.take_reexported_from()
+    }
+
+    fn set_decorators(&mut self, decorators: Vec<String>) {
+        self.object_data.set_decorators(decorators)
+    }
+
+    fn take_decorators(&mut self) -> Vec<String> {
+        self.object_data.take_decorators()
+    }
+
+    fn set_method_kind(&mut self, method_kind: Option<MethodKind>) {
+        self.object_data.set_method_kind(method_kind)
+    }
+
+    fn take_method_kind(&mut self) -> Option<MethodKind> {
+        self.object_data.take_method_kind()
+    }
+
+    fn set_route(&mut self, route: Option<Route>) {
+        self.object_data.set_route(route)
+    }
+
+    fn take_route(&mut self) -> Option<Route> {
+        self.object_data.take_route()
+    }
+
+    fn set_annotation_type(&mut self, annotation_type: Option<String>) {
+        self.object_data.set_annotation_type(annotation_type)
+    }
+
+    fn take_annotation_type(&mut self) -> Option<String> {
+        self.object_data.take_annotation_type()
+    }
+
+    fn set_aliased_type(&mut self, aliased_type: Option<String>) {
+        self.object_data.set_aliased_type(aliased_type)
+    }
+
+    fn take_aliased_type(&mut self) -> Option<String> {
+        self.object_data.take_aliased_type()
+    }
+
+    fn set_class_kind(&mut self, class_kind: Option<ClassKind>) {
+        self.object_data.set_class_kind(class_kind)
+    }
+
+    fn take_class_kind(&mut self) -> Option<ClassKind> {
+        self.object_data.take_class_kind()
+    }
+
+    fn set_declaration_kind(&mut self, declaration_kind: Option<DeclarationKind>) {
+        self.object_data.set_declaration_kind(declaration_kind)
+    }
+
+    fn take_declaration_kind(&mut self) -> Option<DeclarationKind> {
+        self.object_data.take_declaration_kind()
+    }
+
+    fn set_signature(&mut self, signature: Option<String>) {
+        self.object_data.set_signature(signature)
+    }
+
+    fn take_signature(&mut self) -> Option<String> {
+        self.object_data.take_signature()
+    }
+
     fn to_json(&self) -> String {
         serde_json::to_string(self).unwrap_or("".to_string())
     }
@@ -253,6 +619,134 @@ impl PythonObject for ClassObject {
         self.object_data.get_line_number()
     }
 
+    fn documentation(&self) -> &Option<Documentation> {
+        self.object_data.documentation()
+    }
+
+    fn set_documentation(&mut self, documentation: Option<Documentation>) {
+        self.object_data.set_documentation(documentation)
+    }
+
+    fn take_documentation(&mut self) -> Option<Documentation> {
+        self.object_data.take_documentation()
+    }
+
+    fn deprecated(&self) -> bool {
+        self.object_data.deprecated()
+    }
+
+    fn set_deprecated(&mut self, deprecated: bool) {
+        self.object_data.set_deprecated(deprecated)
+    }
+
+    fn deprecation_message(&self) -> &Option<String> {
+        self.object_data.deprecation_message()
+    }
+
+    fn set_deprecation_message(&mut self, deprecation_message: Option<String>) {
+        self.object_data.set_deprecation_message(deprecation_message)
+    }
+
+    fn take_deprecation_message(&mut self) -> Option<String> {
+        self.object_data.take_deprecation_message()
+    }
+
+    fn visibility(&self) -> Visibility {
+        self.object_data.visibility()
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.object_data.set_visibility(visibility)
+    }
+
+    fn set_modifiers(&mut self, modifiers: Vec<String>) {
+        self.object_data.set_modifiers(modifiers)
+    }
+
+    fn take_modifiers(&mut self) -> Vec<String> {
+        self.object_data.take_modifiers()
+    }
+
+    fn set_body(&mut self, body: Option<String>) {
+        self.object_data.set_body(body)
+    }
+
+    fn take_body(&mut self) -> Option<String> {
+        self.object_data.take_body()
+    }
+
+    fn set_reexported_from(&mut self, reexported_from: Option<String>) {
+        self.object_data.set_reexported_from(reexported_from)
+    }
+
+    fn take_reexported_from(&mut self) -> Option<String> {
+        self.object_data.take_reexported_from()
+    }
+
+    fn set_decorators(&mut self, decorators: Vec<String>) {
+        self.object_data.set_decorators(decorators)
+    }
+
+    fn take_decorators(&mut self) -> Vec<String> {
+        self.object_data.take_decorators()
+    }
+
+    fn set_method_kind(&mut self, method_kind: Option<MethodKind>) {
+        self.object_data.set_method_kind(method_kind)
+    }
+
+    fn take_method_kind(&mut self) -> Option<MethodKind> {
+        self.object_data.take_method_kind()
+    }
+
+    fn set_route(&mut self, route: Option<Route>) {
+        self.object_data.set_route(route)
+    }
+
+    fn take_route(&mut self) -> Option<Route> {
+        self.object_data.take_route()
+    }
+
+    fn set_annotation_type(&mut self, annotation_type: Option<String>) {
+        self.object_data.set_annotation_type(annotation_type)
+    }
+
+    fn take_annotation_type(&mut self) -> Option<String> {
+        self.object_data.take_annotation_type()
+    }
+
+    fn set_aliased_type(&mut self, aliased_type: Option<String>) {
+        self.object_data.set_aliased_type(aliased_type)
+    }
+
+    fn take_aliased_type(&mut self) -> Option<String> {
+        self.object_data.take_aliased_type()
+    }
+
+    fn set_class_kind(&mut self, class_kind: Option<ClassKind>) {
+        self.object_data.set_class_kind(class_kind)
+    }
+
+    fn take_class_kind(&mut self) -> Option<ClassKind> {
+        self.object_data.take_class_kind()
+    }
+
+    fn set_declaration_kind(&mut self, declaration_kind: Option<DeclarationKind>) {
+        self.object_data.set_declaration_kind(declaration_kind)
+    }
+
+    fn take_declaration_kind(&mut self) -> Option<DeclarationKind> {
+        self.object_data.take_declaration_kind()
+    }
+
+    fn set_signature(&mut self, signature: Option<String>) {
+        self.object_data.set_signature(signature)
+    }
+
+    fn take_signature(&mut self) -> Option<String> {
+        self.object_data.take_signature()
+    }
+
     fn to_json(&self) -> String {
         serde_json::to_string(self).unwrap_or(String::new())
     }
@@ -324,6 +818,134 @@ impl PythonObject for MethodObject {
         self.object_data.get_line_number()
     }
 
+    fn documentation(&self) -> &Option<Documentation> {
+        self.object_data.documentation()
+    }
+
+    fn set_documentation(&mut self, documentation: Option<Documentation>) {
+        self.object_data.set_documentation(documentation)
+    }
+
+    fn take_documentation(&mut self) -> Option<Documentation> {
+        self.object_data.take_documentation()
+    }
+
+    fn deprecated(&self) -> bool {
+        self.object_data.deprecated()
+    }
+
+    fn set_deprecated(&mut self, deprecated: bool) {
+        self.object_data.set_deprecated(deprecated)
+    }
+
+    fn deprecation_message(&self) -> &Option<String> {
+        self.object_data.deprecation_message()
+    }
+
+    fn set_deprecation_message(&mut self, deprecation_message: Option<String>) {
+        self.object_data.set_deprecation_message(deprecation_message)
+    }
+
+    fn take_deprecation_message(&mut self) -> Option<String> {
+        self.object_data.take_deprecation_message()
+    }
+
+    fn visibility(&self) -> Visibility {
+        self.object_data.visibility()
+    }
+
+    fn set_visibility(&mut self, visibility: Visibility) {
+        self.object_data.set_visibility(visibility)
+    }
+
+    fn set_modifiers(&mut self, modifiers: Vec<String>) {
+        self.object_data.set_modifiers(modifiers)
+    }
+
+    fn take_modifiers(&mut self) -> Vec<String> {
+        self.object_data.take_modifiers()
+    }
+
+    fn set_body(&mut self, body: Option<String>) {
+        self.object_data.set_body(body)
+    }
+
+    fn take_body(&mut self) -> Option<String> {
+        self.object_data.take_body()
+    }
+
+    fn set_reexported_from(&mut self, reexported_from: Option<String>) {
+        self.object_data.set_reexported_from(reexported_from)
+    }
+
+    fn take_reexported_from(&mut self) -> Option<String> {
+        self.object_data.take_reexported_from()
+    }
+
+    fn set_decorators(&mut self, decorators: Vec<String>) {
+        self.object_data.set_decorators(decorators)
+    }
+
+    fn take_decorators(&mut self) -> Vec<String> {
+        self.object_data.take_decorators()
+    }
+
+    fn set_method_kind(&mut self, method_kind: Option<MethodKind>) {
+        self.object_data.set_method_kind(method_kind)
+    }
+
+    fn take_method_kind(&mut self) -> Option<MethodKind> {
+        self.object_data.take_method_kind()
+    }
+
+    fn set_route(&mut self, route: Option<Route>) {
+        self.object_data.set_route(route)
+    }
+
+    fn take_route(&mut self) -> Option<Route> {
+        self.object_data.take_route()
+    }
+
+    fn set_annotation_type(&mut self, annotation_type: Option<String>) {
+        self.object_data.set_annotation_type(annotation_type)
+    }
+
+    fn take_annotation_type(&mut self) -> Option<String> {
+        self.object_data.take_annotation_type()
+    }
+
+    fn set_aliased_type(&mut self, aliased_type: Option<String>) {
+        self.object_data.set_aliased_type(aliased_type)
+    }
+
+    fn take_aliased_type(&mut self) -> Option<String> {
+        self.object_data.take_aliased_type()
+    }
+
+    fn set_class_kind(&mut self, class_kind: Option<ClassKind>) {
+        self.object_data.set_class_kind(class_kind)
+    }
+
+    fn take_class_kind(&mut self) -> Option<ClassKind> {
+        self.object_data.take_class_kind()
+    }
+
+    fn set_declaration_kind(&mut self, declaration_kind: Option<DeclarationKind>) {
+        self.object_data.set_declaration_kind(declaration_kind)
+    }
+
+    fn take_declaration_kind(&mut self) -> Option<DeclarationKind> {
+        self.object_data.take_declaration_kind()
+    }
+
+    fn set_signature(&mut self, signature: Option<String>) {
+        self.object_data.set_signature(signature)
+    }
+
+    fn take_signature(&mut self) -> Option<String> {
+        self.object_data.take_signature()
+    }
+
     fn to_json(&self) -> String {
         serde_json::to_string(self).unwrap_or("None".parse().unwrap())
     }
@@ -338,7 +960,22 @@ impl MethodObject {
                 name,
                 line_number,
                 type_code,
-                children: vec![]
+                children: vec![],
+                documentation: None,
+                deprecated: false,
+                deprecation_message: None,
+                visibility: Visibility::Public,
+                modifiers: vec![],
+                body: None,
+                reexported_from: None,
+                decorators: vec![],
+                method_kind: None,
+                route: None,
+                annotation_type: None,
+                aliased_type: None,
+                class_kind: None,
+                declaration_kind: None,
+                signature: None
             },
             parameters,
             output_param
@@ -1,5 +1,7 @@
 use crate::dto::repository_method_dto::RepositoryMethodDto;
 use crate::dto::invocation_structure::InvocationStructure;
+use crate::dto::schema::SchemaVersion;
+use crate::dto::parse_options::ParseOptions;
 use crate::parser_impl::{java_parser, python_parser, ts_parser};
 use crate::parser_impl::js_parser;
 use crate::parser_impl::cpp_parser;
@@ -13,27 +15,58 @@ impl SupportedLanguages {
     pub const TS: &'static str = "TS";
 }
 
-pub fn parse_file_get_dto(file_data: String, rep_id: i32,
-                          path: String, language: String) -> Vec<RepositoryMethodDto> {
+pub fn parse_file_get_dto(file_data: String, rep_id: i32, path: String,
+                          language: String, options: &ParseOptions) -> Vec<RepositoryMethodDto> {
 
-    match language.as_str() {
-        SupportedLanguages::JS => js_parser::get_method_dto(file_data, rep_id, path),
-        SupportedLanguages::JAVA => java_parser::get_method_dto(file_data, rep_id, path),
-        SupportedLanguages::CPP => cpp_parser::get_method_dto(file_data, rep_id, path),
-        SupportedLanguages::PYTHON => python_parser::get_method_dto(file_data, rep_id, path),
+    let mut method_dto_vec = match language.as_str() {
+        SupportedLanguages::JS => js_parser::get_method_dto(file_data, rep_id, path, options),
+        SupportedLanguages::JAVA => java_parser::get_method_dto(file_data, rep_id, path, options),
+        SupportedLanguages::CPP => cpp_parser::get_method_dto(file_data, rep_id, path, options),
+        SupportedLanguages::PYTHON => python_parser::get_method_dto(file_data, rep_id, path, options),
         SupportedLanguages::TS => ts_parser::get_method_dto(file_data, rep_id, path),
         _ => vec![]
+    };
+
+    for method_dto in method_dto_vec.iter_mut() {
+        if options.schema_version() == SchemaVersion::V1 {
+            method_dto.use_legacy_modifiers();
+        }
+        /* Doc comments are read for deprecation even if documentation is not requested */
+        if !options.extractors().documentation() {
+            method_dto.set_documentation(None);
+        }
     }
+
+    method_dto_vec
+}
+
+/* Repository-level pass over declarations of all files, links C/C++ declarations to their definitions and
+   Python stubs to their implementations */
+pub fn pair_declarations(declarations: &mut [RepositoryMethodDto]) {
+    cpp_parser::pair_declarations(declarations);
+    python_parser::pair_declarations(declarations);
 }
 
-pub fn parse_file_get_invocation_structure(file_data: String, path: String, language: String) -> InvocationStructure {
-    match language.as_str() {
-        SupportedLanguages::JS => js_parser::get_invocation_structure(file_data, path),
-        SupportedLanguages::JAVA => java_parser::get_invocation_structure(file_data, path),
-        SupportedLanguages::CPP => cpp_parser::get_invocation_structure(file_data, path),
-        SupportedLanguages::PYTHON => python_parser::get_invocation_structure(file_data, path),
+/* Repository-level detection of Python source roots from paths of all repository files */
+pub fn detect_source_roots(paths: &[String]) -> Vec<String> {
+    python_parser::detect_source_roots(paths)
+}
+
+pub fn parse_file_get_invocation_structure(file_data: String, path: String,
+                                           language: String, options: &ParseOptions) -> InvocationStructure {
+    let mut invocation_structure = match language.as_str() {
+        SupportedLanguages::JS => js_parser::get_invocation_structure(file_data, path, options),
+        SupportedLanguages::JAVA => java_parser::get_invocation_structure(file_data, path, options),
+        SupportedLanguages::CPP => cpp_parser::get_invocation_structure(file_data, path, options),
+        SupportedLanguages::PYTHON => python_parser::get_invocation_structure(file_data, path, options),
         SupportedLanguages::TS => ts_parser::get_invocation_structure(file_data, path),
         _ => InvocationStructure::default()
+    };
+
+    if options.schema_version() != SchemaVersion::V1 {
+        invocation_structure.set_schema_version(Some(options.schema_version()));
     }
+
+    invocation_structure
 }
 
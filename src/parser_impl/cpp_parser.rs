@@ -1,10 +1,13 @@
 use tree_sitter::Parser;
 use crate::dto::invocation_structure::InvocationStructure;
 use crate::dto::repository_method_dto::RepositoryMethodDto;
+use crate::dto::parse_options::ParseOptions;
+use crate::dto::definition::{DeclarationKind, DefinitionLocation};
+use std::collections::HashMap;
 use crate::visitor::cpp_invocation_visitor::get_file_structure;
 use crate::visitor::cpp_declaration_visitor::get_repository_method_dto;
 
-pub fn get_invocation_structure(mut file_data: String, path: String) -> InvocationStructure {
+pub fn get_invocation_structure(mut file_data: String, path: String, options: &ParseOptions) -> InvocationStructure {
 
     let mut parser = Parser::new();
     parser
@@ -15,10 +18,10 @@ pub fn get_invocation_structure(mut file_data: String, path: String) -> Invocati
         .parse(& mut file_data, None)
         .expect(format!("ERROR: Error occurred during parsing. Path_file {} ", &path).as_str());
 
-    return get_file_structure(file_data, tree, path);
+    return get_file_structure(file_data, tree, path, options);
 }
 
-pub fn get_method_dto(mut file_data: String, rep_id: i32, path: String) -> Vec<RepositoryMethodDto>{
+pub fn get_method_dto(mut file_data: String, rep_id: i32, path: String, options: &ParseOptions) -> Vec<RepositoryMethodDto>{
 
     let mut parser = Parser::new();
     parser
@@ -29,5 +32,91 @@ pub fn get_method_dto(mut file_data: String, rep_id: i32, path: String) -> Vec<R
         .parse(& mut file_data, None)
         .expect(format!("ERROR: Error occurred during parsing. Path_file {} ", &path).as_str());
 
-    return get_repository_method_dto(file_data, tree, path, rep_id);
+    return get_repository_method_dto(file_data, tree, path, rep_id, options);
+}
+
+/* Links declarations of repository to definitions with the same qualified name and number of parameters */
+pub fn pair_declarations(declarations: &mut [RepositoryMethodDto]) {
+
+    let mut definitions: HashMap<(String, usize), DefinitionLocation> = HashMap::new();
+    for dto in declarations.iter() {
+        if dto.declaration_kind() != Some(DeclarationKind::Definition) { continue; }
+        let qualified_name = match dto.qualified_name() { Some(x) => x.clone(), None => continue };
+        definitions
+            .entry((qualified_name, dto.count_of_parameters()))
+            .or_insert_with(|| DefinitionLocation::new(dto.path_file().clone(), dto.line_code()));
+    }
+
+    for dto in declarations.iter_mut() {
+        if dto.declaration_kind() != Some(DeclarationKind::Declaration) { continue; }
+        let qualified_name = match dto.qualified_name() { Some(x) => x.clone(), None => continue };
+        let definition = definitions.get(&(qualified_name, dto.count_of_parameters())).cloned();
+        dto.set_definition(definition);
+    }
+}
+
+#[cfg(test)]
+mod cpp_parser_tests {
+    use crate::parser_impl::cpp_parser::{get_method_dto, get_invocation_structure, pair_declarations};
+    use crate::dto::parse_options::ParseOptions;
+    use crate::dto::repository_method_dto::RepositoryMethodDto;
+
+    #[test]
+    pub fn test_pair_declarations() {
+        let header = "namespace io {\n\
+                          class File {\n\
+                          public:\n\
+                              int read(char* buffer, int size);\n\
+                              void close();\n\
+                              void flush();\n\
+                          };\n\
+                      }".to_string();
+        let source = "#include \"file.h\"\n\
+                      namespace io {\n\
+                          int File::read(char* buffer, int size) { return 0; }\n\
+                      }\n\
+                      void io::File::close() {}\n\
+                      void io::File::flush(int mode) {}".to_string();
+
+        let mut declarations = get_method_dto(header, 0, "include/file.h".to_string(), &ParseOptions::default());
+        declarations.extend(get_method_dto(source, 0, "src/file.cpp".to_string(), &ParseOptions::default()));
+        /* Declarations of repository are passed back by caller as JSON */
+        let mut declarations: Vec<RepositoryMethodDto> = serde_json::from_str(
+            &serde_json::to_string(&declarations).unwrap()).unwrap();
+        pair_declarations(&mut declarations);
+
+        let json = serde_json::to_value(&declarations).unwrap();
+        let find = |method_name: &str| json.as_array().unwrap().iter()
+            .find(|x| x["methodName"] == method_name && x["declarationKind"] == "declaration").unwrap().clone();
+
+        assert_eq!(find("read")["definition"]["pathFile"], "src/file.cpp");
+        assert_eq!(find("read")["definition"]["lineCode"], 3);
+        assert_eq!(find("close")["definition"]["lineCode"], 5);
+        /* Overload with other number of parameters is not a definition */
+        assert!(find("flush").get("definition").is_none());
+    }
+
+    #[test]
+    pub fn test_mark_deprecated_overload_links() {
+        let source = "[[deprecated]] void send(int code, int flags = 0);\n\
+                      void send(int code, const char* text, int flags);\n\
+                      /// \\deprecated use send\n\
+                      void log(const char* format, ...);\n\
+                      void run() {\n\
+                          send(1);\n\
+                          send(1, 2);\n\
+                          send(1, \"text\", 2);\n\
+                          log(\"%d %d\", 1, 2);\n\
+                      }".to_string();
+
+        let structure = get_invocation_structure(source, "src/net.cpp".to_string(), &ParseOptions::default());
+        let json = serde_json::to_value(&structure).unwrap();
+        let deprecated: Vec<(&str, bool)> = json["methodDescriptions"].as_array().unwrap().iter()
+            .filter(|x| x["methodName"] == "send" || x["methodName"] == "log")
+            .map(|x| (x["methodName"].as_str().unwrap(), x.get("deprecated").is_some()))
+            .collect();
+
+        /* Default argument may be omitted, variadic function takes any number of arguments */
+        assert_eq!(deprecated, vec![("send", true), ("send", true), ("send", false), ("log", true)]);
+    }
 }
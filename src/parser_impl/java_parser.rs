@@ -1,10 +1,11 @@
 use tree_sitter::Parser;
 use crate::dto::invocation_structure::InvocationStructure;
 use crate::dto::repository_method_dto::RepositoryMethodDto;
+use crate::dto::parse_options::ParseOptions;
 use crate::visitor::java_invocation_visitor::get_file_structure;
 use crate::visitor::java_declaration_visitor::get_repository_method_dto;
 
-pub fn get_invocation_structure(mut file_data: String, path: String) -> InvocationStructure {
+pub fn get_invocation_structure(mut file_data: String, path: String, options: &ParseOptions) -> InvocationStructure {
 
     let mut parser = Parser::new();
     parser
@@ -15,10 +16,10 @@ pub fn get_invocation_structure(mut file_data: String, path: String) -> Invocati
         .parse(& mut file_data, None)
         .expect(format!("ERROR: Error occurred during parsing. Path_file {} ", &path).as_str());
 
-    return get_file_structure(file_data, tree, path);
+    return get_file_structure(file_data, tree, path, options);
 }
 
-pub fn get_method_dto(mut file_data: String, rep_id: i32, path: String) -> Vec<RepositoryMethodDto>{
+pub fn get_method_dto(mut file_data: String, rep_id: i32, path: String, options: &ParseOptions) -> Vec<RepositoryMethodDto>{
 
     let mut parser = Parser::new();
     parser
@@ -29,5 +30,51 @@ pub fn get_method_dto(mut file_data: String, rep_id: i32, path: String) -> Vec<R
         .parse(& mut file_data, None)
         .expect(format!("ERROR: Error occurred during parsing. Path_file {} ", &path).as_str());
 
-    return get_repository_method_dto(file_data, tree, path, rep_id);
+    return get_repository_method_dto(file_data, tree, path, rep_id, options);
+}
+
+#[cfg(test)]
+mod java_parser_tests {
+    use super::*;
+
+    #[test]
+    pub fn test_deprecated_links() {
+
+        let code = String::from(r#"
+            package test;
+
+            public class Calculator {
+                /** @deprecated use sum */
+                public int add(int a, int b) { return a + b; }
+
+                public int sum(int a, int b) { return a + b; }
+
+                @Deprecated
+                public int sumAll(int first, int... rest) { return first; }
+
+                public int sumAll(String text) { return 0; }
+
+                public int twice(int a) { return this.add(a, a) + this.sum(a, a) + this.sumAll(a, a, a); }
+            }
+        "#);
+
+        let options = ParseOptions::default();
+        let invocation_structure = get_invocation_structure(code.clone(), "test".to_string(), &options);
+        let json = serde_json::to_value(&invocation_structure).unwrap();
+        let is_deprecated = |method_name: &str| json["methodDescriptions"].as_array().unwrap().iter()
+            .find(|x| x["methodName"] == method_name)
+            .unwrap()
+            .get("deprecated")
+            .is_some();
+
+        assert!(is_deprecated("add"));
+        assert!(!is_deprecated("sum"));
+        /* Varargs take any number of arguments */
+        assert!(is_deprecated("sumAll"));
+
+        let options = ParseOptions::from_json(r#"{ "extractors": { "deprecation": false } }"#).unwrap();
+        let invocation_structure = get_invocation_structure(code, "test".to_string(), &options);
+        let json = serde_json::to_value(&invocation_structure).unwrap();
+        assert!(json["methodDescriptions"].as_array().unwrap().iter().all(|x| x.get("deprecated").is_none()));
+    }
 }
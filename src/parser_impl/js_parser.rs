@@ -4,12 +4,13 @@ use std::io::{BufReader, BufRead};
 use tree_sitter::Parser;
 use crate::dto::invocation_structure::InvocationStructure;
 use crate::dto::repository_method_dto::RepositoryMethodDto;
+use crate::dto::parse_options::ParseOptions;
 use crate::visitor::js_invocation_visitor::get_file_structure;
 use crate::visitor::js_declaration_visitor::get_repository_method_dto;
 
-pub fn get_invocation_structure(mut file_data: String, path: String) -> InvocationStructure {
+pub fn get_invocation_structure(mut file_data: String, path: String, options: &ParseOptions) -> InvocationStructure {
 
-    if !is_source_code_valid(&file_data, &path) {
+    if !is_source_code_valid(&file_data, &path, options) {
         return InvocationStructure::default();
     }
 
@@ -22,12 +23,12 @@ pub fn get_invocation_structure(mut file_data: String, path: String) -> Invocati
         .parse(& mut file_data, None)
         .expect(format!("ERROR: Error occurred during parsing. Path_file {} ", &path).as_str());
 
-    return get_file_structure(file_data, tree, path);
+    return get_file_structure(file_data, tree, path, options);
 }
 
-pub fn get_method_dto(mut file_data: String, rep_id: i32, path: String) -> Vec<RepositoryMethodDto>{
+pub fn get_method_dto(mut file_data: String, rep_id: i32, path: String, options: &ParseOptions) -> Vec<RepositoryMethodDto>{
 
-    if !is_source_code_valid(&file_data, &path) {
+    if !is_source_code_valid(&file_data, &path, options) {
         return vec![];
     }
 
@@ -40,10 +41,15 @@ pub fn get_method_dto(mut file_data: String, rep_id: i32, path: String) -> Vec<R
         .parse(& mut file_data, None)
         .expect(format!("ERROR: Error occurred during parsing. Path_file {} ", &path).as_str());
 
-    return get_repository_method_dto(file_data, tree, path, rep_id);
+    return get_repository_method_dto(file_data, tree, path, rep_id, options);
 }
 
-fn is_source_code_valid(source: &str, path_file: &str) -> bool {
+fn is_source_code_valid(source: &str, path_file: &str, options: &ParseOptions) -> bool {
+
+    let detection = options.minified_file_detection();
+    if !detection.enabled() {
+        return true;
+    }
 
     /* File name validation */
     let path = Path::new(path_file);
@@ -58,7 +64,7 @@ fn is_source_code_valid(source: &str, path_file: &str) -> bool {
         None => return false
     };
 
-    if name.contains(".min") || name.contains(".dev") {
+    if detection.file_name_markers().iter().any(|marker| name.contains(marker.as_str())) {
         return false;
     }
 
@@ -79,13 +85,10 @@ fn is_source_code_valid(source: &str, path_file: &str) -> bool {
             None => return false,
     };
 
-    const MAX_LINE_LENGTH: usize = 500;
-    const MAX_LINE_LENGTH_WITH_SMALL_LINE_NUMBER:usize = 200;
-    const REQUIRED_LINE_NUMBER:usize = 10;
-
-    if longest_line_length > MAX_LINE_LENGTH {
+    if longest_line_length > detection.max_line_length() {
         return false;
-    } else if line_number < REQUIRED_LINE_NUMBER  && longest_line_length > MAX_LINE_LENGTH_WITH_SMALL_LINE_NUMBER {
+    } else if line_number < detection.required_line_number()
+        && longest_line_length > detection.max_line_length_with_small_line_number() {
         return false;
     } else {
         return true;
@@ -99,14 +102,16 @@ mod js_parser_tests {
     #[test]
     pub fn test_js_validate_name(){
 
+        let options = ParseOptions::default();
+
         let min_path_file = String::from("./some_dir.min/some.dir_again/some_file.min.js");
-        assert!(!is_source_code_valid("", &min_path_file));
+        assert!(!is_source_code_valid("", &min_path_file, &options));
 
         let dev_path_file = String::from("./some_dir.dev/some.dir_again/some_file.dev.js");
-        assert!(!is_source_code_valid("", &dev_path_file));
+        assert!(!is_source_code_valid("", &dev_path_file, &options));
 
         let zero_lines_file = String::from("./normal_dir/file.js");
-        assert!(!is_source_code_valid("", &zero_lines_file));
+        assert!(!is_source_code_valid("", &zero_lines_file, &options));
 
         let normal_file = String::from("/normal_dir/normal_file.js");
         let normal_content = format!("{}\n", r#"
@@ -114,6 +119,15 @@ mod js_parser_tests {
                 console.log("Hello - ", name);
             }
         "#);
-        assert!(is_source_code_valid(&normal_content, &normal_file));
+        assert!(is_source_code_valid(&normal_content, &normal_file, &options));
+    }
+
+    #[test]
+    pub fn test_js_validate_disabled_detection(){
+
+        let options = ParseOptions::from_json(r#"{ "minifiedFileDetection": { "enabled": false } }"#).unwrap();
+
+        let min_path_file = String::from("./some_dir/some_file.min.js");
+        assert!(is_source_code_valid("var a=1;", &min_path_file, &options));
     }
 }
@@ -1,10 +1,14 @@
 use tree_sitter::Parser;
 use crate::dto::invocation_structure::InvocationStructure;
 use crate::dto::repository_method_dto::RepositoryMethodDto;
+use crate::dto::parse_options::ParseOptions;
+use crate::dto::definition::{DeclarationKind, DefinitionLocation};
+use std::collections::HashMap;
 use crate::visitor::python_invocation_visitor::get_file_structure;
 use crate::visitor::python_declaration_visitor::get_repository_method_dto;
+use crate::utils::python_module;
 
-pub fn get_invocation_structure(mut file_data: String, path: String) -> InvocationStructure {
+pub fn get_invocation_structure(mut file_data: String, path: String, options: &ParseOptions) -> InvocationStructure {
 
     let mut parser = Parser::new();
     parser
@@ -15,10 +19,48 @@ pub fn get_invocation_structure(mut file_data: String, path: String) -> Invocati
         .parse(& mut file_data, None)
         .expect(format!("ERROR: Error occurred during parsing. Path_file {} ", &path).as_str());
 
-    return get_file_structure(file_data, tree, path);
+    return get_file_structure(file_data, tree, path, options);
 }
 
-pub fn get_method_dto(mut file_data: String, rep_id: i32, path: String) -> Vec<RepositoryMethodDto>{
+pub fn detect_source_roots(paths: &[String]) -> Vec<String> {
+    python_module::detect_source_roots(paths)
+}
+
+/* Links declarations of `.pyi` stubs and `@overload` signatures to the implementation with the same module, class
+   and name. Implementation declared by a single stub signature takes its parameter and return types */
+pub fn pair_declarations(declarations: &mut [RepositoryMethodDto]) {
+
+    let mut implementations: HashMap<(String, String, String), DefinitionLocation> = HashMap::new();
+    let mut stub_signatures: HashMap<(String, String, String), Vec<Option<String>>> = HashMap::new();
+    for dto in declarations.iter() {
+        if !python_module::is_python_file(dto.path_file()) { continue; }
+        let key = (dto.package_name().clone(), dto.class_name().clone(), dto.method_name().clone());
+        if python_module::is_stub(dto.path_file()) {
+            if dto.signature().is_some() {
+                stub_signatures.entry(key).or_default().push(dto.signature().clone());
+            }
+        } else if dto.declaration_kind().is_none() {
+            implementations
+                .entry(key)
+                .or_insert_with(|| DefinitionLocation::new(dto.path_file().clone(), dto.line_code()));
+        }
+    }
+
+    for dto in declarations.iter_mut() {
+        if !python_module::is_python_file(dto.path_file()) { continue; }
+        let key = (dto.package_name().clone(), dto.class_name().clone(), dto.method_name().clone());
+        if python_module::is_stub(dto.path_file()) || dto.declaration_kind() == Some(DeclarationKind::Declaration) {
+            dto.set_definition(implementations.get(&key).cloned());
+        } else if dto.signature().is_some() {
+            /* Overloaded stub signatures stay separate declarations */
+            if let Some(signatures) = stub_signatures.get(&key).filter(|x| x.len() == 1) {
+                dto.set_signature(signatures[0].clone());
+            }
+        }
+    }
+}
+
+pub fn get_method_dto(mut file_data: String, rep_id: i32, path: String, options: &ParseOptions) -> Vec<RepositoryMethodDto>{
 
     let mut parser = Parser::new();
     parser
@@ -29,5 +71,95 @@ pub fn get_method_dto(mut file_data: String, rep_id: i32, path: String) -> Vec<R
         .parse(& mut file_data, None)
         .expect(format!("ERROR: Error occurred during parsing. Path_file {} ", &path).as_str());
 
-    return get_repository_method_dto(file_data, tree, path, rep_id);
-}
\ No newline at end of file
+    return get_repository_method_dto(file_data, tree, path, rep_id, options);
+}
+
+#[cfg(test)]
+mod python_parser_tests {
+    use crate::parser_impl::python_parser::{get_invocation_structure, get_method_dto, pair_declarations};
+    use crate::dto::parse_options::ParseOptions;
+    use crate::dto::repository_method_dto::RepositoryMethodDto;
+
+    #[test]
+    pub fn test_pair_declarations() {
+        let stub = "from typing import overload\n\
+                    class Client:\n\
+                    \x20   def get(self, url: str, timeout: float = ...) -> Response: ...\n\
+                    \x20   @overload\n\
+                    \x20   def send(self, data: str) -> int: ...\n\
+                    \x20   @overload\n\
+                    \x20   def send(self, data: bytes) -> int: ...\n\
+                    def connect(host: str) -> Client: ...\n\
+                    def close(client: Client, reason: str = \"  done  \",\n\
+                    \x20         force=False,) -> None: ...\n".to_string();
+        let source = "class Client:\n\
+                      \x20   def get(self, url, timeout=None):\n\
+                      \x20       pass\n\
+                      \x20   def send(self, data):\n\
+                      \x20       pass\n\
+                      @overload\n\
+                      def connect(host: str) -> Client: ...\n\
+                      def connect(host):\n\
+                      \x20   pass\n".to_string();
+
+        let mut declarations = get_method_dto(stub, 0, "src/http/client.pyi".to_string(), &ParseOptions::default());
+        declarations.extend(get_method_dto(source, 0, "src/http/client.py".to_string(), &ParseOptions::default()));
+        /* Declarations of repository are passed back by caller as JSON */
+        let mut declarations: Vec<RepositoryMethodDto> = serde_json::from_str(
+            &serde_json::to_string(&declarations).unwrap()).unwrap();
+        pair_declarations(&mut declarations);
+
+        let json = serde_json::to_value(&declarations).unwrap();
+        let find = |path: &str, method_name: &str| json.as_array().unwrap().iter()
+            .filter(|x| x["pathFile"] == path && x["methodName"] == method_name)
+            .cloned()
+            .collect::<Vec<serde_json::Value>>();
+
+        let get = find("src/http/client.pyi", "get");
+        assert_eq!(get[0]["declarationKind"], "declaration");
+        assert_eq!(get[0]["packageName"], "http.client");
+        assert_eq!(get[0]["definition"], serde_json::json!({ "pathFile": "src/http/client.py", "lineCode": 2 }));
+        /* Implementation takes types of its stub */
+        assert_eq!(find("src/http/client.py", "get")[0]["signature"], "(self, url: str, timeout: float = ...) -> Response");
+        assert!(find("src/http/client.py", "get")[0].get("definition").is_none());
+
+        /* Overloads are separate signatures of one implementation */
+        let send = find("src/http/client.pyi", "send");
+        assert_eq!(send.len(), 2);
+        assert_eq!(send[1]["signature"], "(self, data: bytes) -> int");
+        assert_eq!(send[1]["definition"]["lineCode"], 4);
+        assert_eq!(find("src/http/client.py", "send")[0]["signature"], "(self, data)");
+        /* Signature is built from parameters, whitespace of default values is kept */
+        assert_eq!(find("src/http/client.pyi", "close")[0]["signature"],
+                   "(client: Client, reason: str = \"  done  \", force=False) -> None");
+
+        let connect = find("src/http/client.py", "connect");
+        assert_eq!(connect[0]["declarationKind"], "declaration");
+        assert_eq!(connect[0]["definition"]["lineCode"], 8);
+        assert!(connect[1].get("declarationKind").is_none());
+        assert_eq!(find("src/http/client.pyi", "connect")[0]["definition"]["lineCode"], 8);
+    }
+
+    #[test]
+    pub fn test_mark_deprecated_links() {
+        let source = "import warnings\n\
+                      def fetch(url, timeout=None, *, retries=3):\n\
+                      \x20   warnings.warn(\"use get\", DeprecationWarning)\n\
+                      def log(first, *rest):\n\
+                      \x20   \"\"\".. deprecated:: 2.0\"\"\"\n\
+                      fetch(\"a\")\n\
+                      fetch(\"a\", 1, retries=2)\n\
+                      fetch(\"a\", 1, 2, 3)\n\
+                      log(1, 2, 3)\n".to_string();
+
+        let structure = get_invocation_structure(source, "src/net.py".to_string(), &ParseOptions::default());
+        let json = serde_json::to_value(&structure).unwrap();
+        let deprecated: Vec<(&str, bool)> = json["methodDescriptions"].as_array().unwrap().iter()
+            .filter(|x| x["methodName"] != "warn")
+            .map(|x| (x["methodName"].as_str().unwrap(), x.get("deprecated").is_some()))
+            .collect();
+
+        /* Default parameters may be omitted, `*rest` takes any number of arguments */
+        assert_eq!(deprecated, vec![("fetch", true), ("fetch", true), ("fetch", false), ("log", true)]);
+    }
+}
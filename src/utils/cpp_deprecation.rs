@@ -0,0 +1,180 @@
+/* Deprecation of C/C++ declarations by `[[deprecated]]` attributes and Doxygen `\deprecated` tags, shared by
+   declaration and invocation visitors so calls are marked without a second visit of the file */
+use tree_sitter::Node;
+use crate::utils::doc_comment::{is_doc_comment, parse_doc_comment};
+use crate::utils::node::{find_descendant_by_kind, get_child_node_by_kind};
+
+struct NodeKinds;
+impl NodeKinds {
+    const COMMENT: &'static str = "comment";
+    const TEMPLATE_DECLARATION: &'static str = "template_declaration";
+    const TEMPLATE_PARAMETER_LIST: &'static str = "template_parameter_list";
+    const FUNCTION_DECLARATOR: &'static str = "function_declarator";
+    const ATTRIBUTE: &'static str = "attribute";
+    const ATTRIBUTE_SPECIFIER: &'static str = "attribute_specifier";
+    const IDENTIFIER: &'static str = "identifier";
+    const CALL_EXPRESSION: &'static str = "call_expression";
+    const ARGUMENT_LIST: &'static str = "argument_list";
+    const STRING_LITERAL: &'static str = "string_literal";
+    const PARAMETER_DECLARATION: &'static str = "parameter_declaration";
+    const OPTIONAL_PARAMETER_DECLARATION: &'static str = "optional_parameter_declaration";
+    const VARIADIC_PARAMETER_DECLARATION: &'static str = "variadic_parameter_declaration";
+    const PRIMITIVE_TYPE: &'static str = "primitive_type";
+}
+
+struct NodeNames;
+impl NodeNames {
+    const DECLARATOR: &'static str = "declarator";
+    const FUNCTION: &'static str = "function";
+    const ARGUMENTS: &'static str = "arguments";
+    const TYPE: &'static str = "type";
+}
+
+struct KeyWords;
+impl KeyWords {
+    const LINE_DOC_COMMENT: &'static str = "///";
+    const LINE_DOC_COMMENT_EXCLAMATION: &'static str = "//!";
+    const MEMBER_DOC_COMMENT_SUFFIX: &'static str = "<";
+    const DEPRECATED: &'static str = "deprecated";
+    const GNU_DEPRECATED: &'static str = "__deprecated__";
+    const VARIADIC: &'static str = "...";
+    const VOID: &'static str = "void";
+}
+
+/* Block doc comment or sequence of consecutive `///` lines before declaration */
+pub fn get_doc_comment(node: &Node, source_code: &str) -> Option<String> {
+
+    let comment_node = node.prev_named_sibling();
+
+    /* Comment is placed before template<...> clause */
+    if comment_node.is_none_or(|x| x.kind() == NodeKinds::TEMPLATE_PARAMETER_LIST) {
+        let parent = node.parent()?;
+        if parent.kind() == NodeKinds::TEMPLATE_DECLARATION {
+            return get_doc_comment(&parent, source_code);
+        }
+    }
+
+    let mut comment_node = comment_node;
+    let mut comment_lines = vec![];
+    let mut next_row = node.start_position().row;
+
+    while let Some(comment) = comment_node {
+        if comment.kind() != NodeKinds::COMMENT || comment.end_position().row + 1 < next_row {
+            break;
+        }
+
+        let text = get_node_text(&comment, source_code);
+        let is_line_comment = text.starts_with(KeyWords::LINE_DOC_COMMENT)
+            || text.starts_with(KeyWords::LINE_DOC_COMMENT_EXCLAMATION);
+        if !is_doc_comment(text) || text[3..].starts_with(KeyWords::MEMBER_DOC_COMMENT_SUFFIX)
+            || (!comment_lines.is_empty() && !is_line_comment) {
+            break;
+        }
+
+        comment_lines.push(text);
+        if !is_line_comment {
+            break;
+        }
+
+        next_row = comment.start_position().row;
+        comment_node = comment.prev_named_sibling();
+    }
+
+    if comment_lines.is_empty() {
+        return None;
+    }
+
+    comment_lines.reverse();
+    Some(comment_lines.join("\n"))
+}
+
+/* `[[deprecated("message")]]` or `__attribute__((deprecated))` before declaration or after declarator,
+   None if declaration has no such attribute */
+pub fn get_attribute_deprecation(node: &Node, source_code: &str) -> Option<Option<String>> {
+
+    let mut attribute_nodes: Vec<Node> = node.named_children(&mut node.walk()).collect();
+    let function_decl_opt = node.child_by_field_name(NodeNames::DECLARATOR)
+        .and_then(|x| find_descendant_by_kind(x, NodeKinds::FUNCTION_DECLARATOR));
+    if let Some(function_decl) = function_decl_opt {
+        attribute_nodes.extend(function_decl.named_children(&mut function_decl.walk()));
+    }
+
+    attribute_nodes
+        .iter()
+        .filter(|x| x.kind() == NodeKinds::ATTRIBUTE || x.kind() == NodeKinds::ATTRIBUTE_SPECIFIER)
+        .find_map(|x| get_deprecation_from_attribute(x, source_code))
+}
+
+/* Deprecated by attribute or doc comment tag */
+pub fn is_deprecated(node: &Node, source_code: &str) -> bool {
+    get_attribute_deprecation(node, source_code).is_some()
+        || get_doc_comment(node, source_code).is_some_and(|x| parse_doc_comment(&x).deprecated().is_some())
+}
+
+/* Least and greatest number of arguments of call, None for `...` and parameter packs */
+pub fn get_count_of_params_range(parameters_node: &Node, source_code: &str) -> (usize, Option<usize>) {
+
+    let mut min_count_of_params = 0;
+    let mut max_count_of_params = Some(0);
+
+    for child in parameters_node.children(&mut parameters_node.walk()) {
+        match child.kind() {
+            /* `f(void)` takes no arguments */
+            NodeKinds::PARAMETER_DECLARATION if !is_void_parameter(&child, source_code) => {
+                min_count_of_params += 1;
+                max_count_of_params = max_count_of_params.map(|x| x + 1);
+            }
+            NodeKinds::OPTIONAL_PARAMETER_DECLARATION => max_count_of_params = max_count_of_params.map(|x| x + 1),
+            NodeKinds::VARIADIC_PARAMETER_DECLARATION | KeyWords::VARIADIC => max_count_of_params = None,
+            _ => {}
+        }
+    }
+
+    (min_count_of_params, max_count_of_params)
+}
+
+fn get_deprecation_from_attribute(node: &Node, source_code: &str) -> Option<Option<String>> {
+
+    for child in node.named_children(&mut node.walk()) {
+        match child.kind() {
+            NodeKinds::IDENTIFIER => {
+                if is_deprecated_identifier(&child, source_code) {
+                    return Some(None);
+                }
+            }
+            NodeKinds::CALL_EXPRESSION => {
+                let function_node = child.child_by_field_name(NodeNames::FUNCTION)?;
+                if !is_deprecated_identifier(&function_node, source_code) {
+                    continue;
+                }
+                let arguments = child.child_by_field_name(NodeNames::ARGUMENTS)?;
+                let message = get_child_node_by_kind(&arguments, NodeKinds::STRING_LITERAL)
+                    .map(|literal| get_node_text(&literal, source_code).trim_matches('"').to_string());
+                return Some(message);
+            }
+            NodeKinds::ARGUMENT_LIST => {
+                if let Some(deprecation) = get_deprecation_from_attribute(&child, source_code) {
+                    return Some(deprecation);
+                }
+            }
+            &_ => {}
+        }
+    }
+
+    None
+}
+
+fn is_void_parameter(node: &Node, source_code: &str) -> bool {
+    node.child_by_field_name(NodeNames::DECLARATOR).is_none()
+        && node.child_by_field_name(NodeNames::TYPE)
+            .is_some_and(|x| x.kind() == NodeKinds::PRIMITIVE_TYPE && get_node_text(&x, source_code) == KeyWords::VOID)
+}
+
+fn is_deprecated_identifier(node: &Node, source_code: &str) -> bool {
+    let value = get_node_text(node, source_code);
+    value == KeyWords::DEPRECATED || value == KeyWords::GNU_DEPRECATED
+}
+
+fn get_node_text<'a>(node: &Node, source_code: &'a str) -> &'a str {
+    &source_code[node.start_byte()..node.end_byte()]
+}
@@ -0,0 +1,91 @@
+/* Namespace aliases `namespace fs = std::filesystem;`, shared by declaration and invocation visitors.
+   The C++ grammar has no rule for them and parses the alias as ERROR node, so it is recognized by text */
+use std::collections::HashMap;
+use tree_sitter::Node;
+
+struct NodeKinds;
+impl NodeKinds {
+    const ERROR: &'static str = "ERROR";
+    const NAMESPACE_ALIAS_DEFINITION: &'static str = "namespace_alias_definition";
+}
+
+struct KeyWords;
+impl KeyWords {
+    const NAMESPACE: &'static str = "namespace";
+    const SCOPE_SEPARATOR: &'static str = "::";
+}
+
+/* Alias and aliased namespace of `namespace fs = std::filesystem;`, None for other nodes */
+pub fn get_namespace_alias(node: &Node, source_code: &str) -> Option<(String, String)> {
+
+    if node.kind() != NodeKinds::ERROR && node.kind() != NodeKinds::NAMESPACE_ALIAS_DEFINITION {
+        return None;
+    }
+    /* Error recovery may leave the aliased namespace `::geo` out of the error node, the alias ends with `;` */
+    let text = &source_code[node.start_byte()..];
+    let text = &text[..text.find(';').unwrap_or(node.end_byte() - node.start_byte())];
+    let definition = text.strip_prefix(KeyWords::NAMESPACE)?;
+    if !definition.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let (alias, namespace) = definition.split_once('=')?;
+    let alias = alias.trim();
+    let namespace = namespace.split_whitespace().collect::<String>();
+    let namespace = namespace.trim_start_matches(KeyWords::SCOPE_SEPARATOR);
+
+    let is_valid = is_identifier(alias)
+        && namespace.split(KeyWords::SCOPE_SEPARATOR).all(is_identifier);
+    if !is_valid {
+        return None;
+    }
+    Some((alias.to_string(), namespace.to_string()))
+}
+
+/* `fs` or `fs::detail` -> `std::filesystem` or `std::filesystem::detail` */
+pub fn resolve_namespace(namespace: &str, namespace_aliases: &HashMap<String, String>) -> String {
+
+    let (first_segment, rest) = match namespace.split_once(KeyWords::SCOPE_SEPARATOR) {
+        Some((first_segment, rest)) => (first_segment, Some(rest)),
+        None => (namespace, None)
+    };
+    match (namespace_aliases.get(first_segment), rest) {
+        (Some(aliased_namespace), Some(rest)) => format!("{}{}{}", aliased_namespace, KeyWords::SCOPE_SEPARATOR, rest),
+        (Some(aliased_namespace), None) => aliased_namespace.clone(),
+        (None, _) => namespace.to_string()
+    }
+}
+
+/* `fs::path` -> `std::filesystem::path`, also inside template arguments `std::vector<fs::path>` */
+pub fn expand_namespace_aliases(text: &str, namespace_aliases: &HashMap<String, String>) -> String {
+
+    if namespace_aliases.is_empty() {
+        return text.to_string();
+    }
+
+    let mut expanded = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(is_identifier_char) {
+        let (before, word) = rest.split_at(start);
+        let end = word.find(|c| !is_identifier_char(c)).unwrap_or(word.len());
+        let (word, after) = word.split_at(end);
+
+        /* Only the first segment of qualified name `fs::path` is an alias, not `ns::fs::path` */
+        let is_qualifier = after.starts_with(KeyWords::SCOPE_SEPARATOR) && !before.ends_with(KeyWords::SCOPE_SEPARATOR);
+        expanded.push_str(before);
+        match namespace_aliases.get(word) {
+            Some(namespace) if is_qualifier => expanded.push_str(namespace),
+            _ => expanded.push_str(word)
+        }
+        rest = after;
+    }
+    expanded.push_str(rest);
+    expanded
+}
+
+fn is_identifier(text: &str) -> bool {
+    !text.is_empty() && !text.starts_with(|c: char| c.is_ascii_digit()) && text.chars().all(is_identifier_char)
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
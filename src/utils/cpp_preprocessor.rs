@@ -0,0 +1,207 @@
+/* Evaluation of C/C++ `#if`, `#ifdef` and `#elif` branches for configured macros, shared by declaration and
+   invocation visitors so both skip the same inactive branches */
+use std::collections::HashMap;
+use tree_sitter::Node;
+
+struct NodeKinds;
+impl NodeKinds {
+    const PREPROC_IF: &'static str = "preproc_if";
+    const PREPROC_IFDEF: &'static str = "preproc_ifdef";
+    const PREPROC_ELIF: &'static str = "preproc_elif";
+    const PREPROC_ELSE: &'static str = "preproc_else";
+    const PREPROC_DEF: &'static str = "preproc_def";
+    const PREPROC_CALL: &'static str = "preproc_call";
+    const PREPROC_DEFINED: &'static str = "preproc_defined";
+    const IDENTIFIER: &'static str = "identifier";
+    const NUMBER_LITERAL: &'static str = "number_literal";
+    const TRUE: &'static str = "true";
+    const FALSE: &'static str = "false";
+    const PARENTHESIZED_EXPRESSION: &'static str = "parenthesized_expression";
+    const UNARY_EXPRESSION: &'static str = "unary_expression";
+    const BINARY_EXPRESSION: &'static str = "binary_expression";
+}
+
+struct NodeNames;
+impl NodeNames {
+    const NAME: &'static str = "name";
+    const CONDITION: &'static str = "condition";
+    const ALTERNATIVE: &'static str = "alternative";
+    const VALUE: &'static str = "value";
+    const DIRECTIVE: &'static str = "directive";
+    const ARGUMENT: &'static str = "argument";
+    const OPERATOR: &'static str = "operator";
+    const LEFT: &'static str = "left";
+    const RIGHT: &'static str = "right";
+}
+
+struct KeyWords;
+impl KeyWords {
+    const IFNDEF: &'static str = "#ifndef";
+    const UNDEF: &'static str = "#undef";
+}
+
+/* `#if`, `#ifdef` and `#ifndef` start a chain of branches linked by `alternative` */
+pub fn is_preproc_conditional(node: &Node) -> bool {
+    node.kind() == NodeKinds::PREPROC_IF || node.kind() == NodeKinds::PREPROC_IFDEF
+}
+
+/* Name or condition of `#if` chain branch and the following branch, not items of the branch */
+pub fn is_preproc_branch_header(branch: &Node, child: &Node) -> bool {
+
+    let is_branch = matches!(branch.kind(),
+        NodeKinds::PREPROC_IF | NodeKinds::PREPROC_IFDEF | NodeKinds::PREPROC_ELIF | NodeKinds::PREPROC_ELSE);
+    is_branch && [NodeNames::NAME, NodeNames::CONDITION, NodeNames::ALTERNATIVE].iter()
+        .any(|field_name| branch.child_by_field_name(field_name) == Some(*child))
+}
+
+/* Value of branch condition for configured macros, None if configuration or value is unknown */
+pub fn evaluate_preproc_branch(branch: &Node, source_code: &str,
+                               defined_macros: Option<&HashMap<String, String>>) -> Option<bool> {
+
+    if branch.kind() == NodeKinds::PREPROC_ELSE {
+        return Some(true);
+    }
+    let defined_macros = defined_macros?;
+
+    match branch.kind() {
+        NodeKinds::PREPROC_IFDEF => {
+            let name_node = branch.child_by_field_name(NodeNames::NAME)?;
+            let is_defined = defined_macros.contains_key(get_node_text(&name_node, source_code));
+            let directive = get_node_text(&branch.child(0)?, source_code);
+            Some(is_defined != (directive == KeyWords::IFNDEF))
+        }
+        NodeKinds::PREPROC_IF | NodeKinds::PREPROC_ELIF => {
+            let condition_node = branch.child_by_field_name(NodeNames::CONDITION)?;
+            evaluate_preproc_expression(&condition_node, source_code, defined_macros).map(|x| x != 0)
+        }
+        _ => None
+    }
+}
+
+/* Activity of branch and whether some branch of the chain is taken after it, from whether an earlier branch
+   is taken and value of branch condition. None if it is unknown */
+pub fn get_branch_activity(is_taken: Option<bool>, value: Option<bool>) -> (Option<bool>, Option<bool>) {
+
+    let is_active = match (is_taken, value) {
+        (Some(true), _) | (_, Some(false)) => Some(false),
+        (Some(false), Some(true)) => Some(true),
+        _ => None
+    };
+    let is_taken = match (is_taken, value) {
+        (Some(true), _) | (_, Some(true)) => Some(true),
+        (Some(false), Some(false)) => Some(false),
+        _ => None
+    };
+    (is_active, is_taken)
+}
+
+/* `#define NAME value` and `#undef NAME` of the file change configured macros */
+pub fn update_defined_macros(node: &Node, source_code: &str, defined_macros: &mut HashMap<String, String>) {
+
+    match node.kind() {
+        NodeKinds::PREPROC_DEF => {
+            let name_node = match node.child_by_field_name(NodeNames::NAME) {
+                Some(name_node) => name_node,
+                None => return
+            };
+            let value = match node.child_by_field_name(NodeNames::VALUE) {
+                Some(value_node) => get_node_text(&value_node, source_code).trim().to_string(),
+                None => String::new()
+            };
+            defined_macros.insert(get_node_text(&name_node, source_code).to_string(), value);
+        }
+        NodeKinds::PREPROC_CALL => {
+            let (directive_node, argument_node) = match (node.child_by_field_name(NodeNames::DIRECTIVE),
+                                                         node.child_by_field_name(NodeNames::ARGUMENT)) {
+                (Some(directive_node), Some(argument_node)) => (directive_node, argument_node),
+                _ => return
+            };
+            if get_node_text(&directive_node, source_code) == KeyWords::UNDEF {
+                defined_macros.remove(get_node_text(&argument_node, source_code).trim());
+            }
+        }
+        _ => {}
+    }
+}
+
+fn evaluate_preproc_expression(node: &Node, source_code: &str, defined_macros: &HashMap<String, String>) -> Option<i64> {
+
+    let evaluate = |child: Node| evaluate_preproc_expression(&child, source_code, defined_macros);
+
+    match node.kind() {
+        NodeKinds::NUMBER_LITERAL => parse_preproc_number(get_node_text(node, source_code)),
+        NodeKinds::TRUE => Some(1),
+        NodeKinds::FALSE => Some(0),
+
+        /* Identifier which is not a macro is replaced by 0 */
+        NodeKinds::IDENTIFIER => match defined_macros.get(get_node_text(node, source_code)) {
+            Some(value) => parse_preproc_number(value),
+            None => Some(0)
+        },
+
+        NodeKinds::PREPROC_DEFINED => {
+            let name_node = node.named_children(&mut node.walk()).find(|x| x.kind() == NodeKinds::IDENTIFIER)?;
+            Some(defined_macros.contains_key(get_node_text(&name_node, source_code)) as i64)
+        }
+
+        NodeKinds::PARENTHESIZED_EXPRESSION => evaluate(node.named_child(0)?),
+
+        NodeKinds::UNARY_EXPRESSION => {
+            let operator = get_node_text(&node.child_by_field_name(NodeNames::OPERATOR)?, source_code);
+            let argument = evaluate(node.child_by_field_name(NodeNames::ARGUMENT)?)?;
+            match operator {
+                "!" => Some((argument == 0) as i64),
+                "-" => Some(argument.wrapping_neg()),
+                "+" => Some(argument),
+                "~" => Some(!argument),
+                _ => None
+            }
+        }
+
+        NodeKinds::BINARY_EXPRESSION => {
+            let operator = get_node_text(&node.child_by_field_name(NodeNames::OPERATOR)?, source_code);
+            let left = evaluate(node.child_by_field_name(NodeNames::LEFT)?);
+            let right = evaluate(node.child_by_field_name(NodeNames::RIGHT)?);
+            match (operator, left, right) {
+                /* Logical operators are known if one known operand decides */
+                ("&&", Some(0), _) | ("&&", _, Some(0)) => Some(0),
+                ("||", Some(x), _) | ("||", _, Some(x)) if x != 0 => Some(1),
+                ("&&", Some(_), Some(_)) => Some(1),
+                ("||", Some(_), Some(_)) => Some(0),
+                (_, Some(x), Some(y)) => match operator {
+                    "==" => Some((x == y) as i64),
+                    "!=" => Some((x != y) as i64),
+                    "<" => Some((x < y) as i64),
+                    ">" => Some((x > y) as i64),
+                    "<=" => Some((x <= y) as i64),
+                    ">=" => Some((x >= y) as i64),
+                    "+" => Some(x.wrapping_add(y)),
+                    "-" => Some(x.wrapping_sub(y)),
+                    "*" => Some(x.wrapping_mul(y)),
+                    "/" => x.checked_div(y),
+                    "%" => x.checked_rem(y),
+                    "&" => Some(x & y),
+                    "|" => Some(x | y),
+                    "^" => Some(x ^ y),
+                    _ => None
+                },
+                _ => None
+            }
+        }
+
+        _ => None
+    }
+}
+
+fn parse_preproc_number(text: &str) -> Option<i64> {
+
+    let number = text.trim().trim_end_matches(['u', 'U', 'l', 'L']);
+    if let Some(hex) = number.strip_prefix("0x").or_else(|| number.strip_prefix("0X")) {
+        return i64::from_str_radix(hex, 16).ok();
+    }
+    number.parse::<i64>().ok()
+}
+
+fn get_node_text<'a>(node: &Node, source_code: &'a str) -> &'a str {
+    &source_code[node.start_byte()..node.end_byte()]
+}
@@ -0,0 +1,470 @@
+use crate::dto::documentation::{Documentation, ParamDocumentation, ThrowsDocumentation};
+
+struct Tags;
+impl Tags {
+    const PARAM: &'static str = "param";
+    const RETURN: &'static str = "return";
+    const RETURNS: &'static str = "returns";
+    const THROWS: &'static str = "throws";
+    const EXCEPTION: &'static str = "exception";
+    const RAISES: &'static str = "raises";
+    const RAISE: &'static str = "raise";
+    const DEPRECATED: &'static str = "deprecated";
+    const BRIEF: &'static str = "brief";
+    const SHORT: &'static str = "short";
+    const DETAILS: &'static str = "details";
+}
+
+struct GoogleSections;
+impl GoogleSections {
+    const ARGS: &'static str = "Args:";
+    const ARGUMENTS: &'static str = "Arguments:";
+    const PARAMETERS: &'static str = "Parameters:";
+    const RETURNS: &'static str = "Returns:";
+    const RAISES: &'static str = "Raises:";
+}
+
+/* Javadoc / Doxygen / JSDoc: `/** ... */`, `/*! ... */`, `/// ...` and `//! ...` comments */
+pub fn is_doc_comment(comment: &str) -> bool {
+    (comment.starts_with("/**") && comment != "/**/") || comment.starts_with("/*!")
+        || (comment.starts_with("///") && !comment.starts_with("////")) || comment.starts_with("//!")
+}
+
+pub fn parse_doc_comment(raw: &str) -> Documentation {
+
+    let lines: Vec<String> = raw
+        .lines()
+        .map(|line| strip_comment_markers(line.trim()))
+        .collect();
+
+    let mut documentation = Documentation::new(raw.to_string());
+    parse_tagged_lines(&trim_empty_lines(lines), &mut documentation);
+    documentation
+}
+
+/* Python docstring: reST (`:param x:`) and Google (`Args:`) styles */
+pub fn parse_docstring(raw: &str) -> Documentation {
+
+    let mut documentation = Documentation::new(raw.to_string());
+    let lines = trim_empty_lines(dedent(strip_string_quotes(raw)));
+
+    let mut description = vec![];
+    let mut section = DocstringSection::None;
+    let mut last_tag = LastTag::None;
+
+    for line in &lines {
+        let trimmed = line.trim();
+
+        if let Some(rest) = trimmed.strip_prefix(':') {
+            section = DocstringSection::None;
+            last_tag = add_rest_field(rest, &mut documentation);
+            continue;
+        }
+
+        if let Some(rest) = trimmed.strip_prefix(".. deprecated::") {
+            documentation.set_deprecated(rest.trim().to_string());
+            last_tag = LastTag::Deprecated;
+            continue;
+        }
+
+        match trimmed {
+            GoogleSections::ARGS | GoogleSections::ARGUMENTS | GoogleSections::PARAMETERS => {
+                section = DocstringSection::Args;
+                continue;
+            }
+            GoogleSections::RETURNS => {
+                section = DocstringSection::Returns;
+                continue;
+            }
+            GoogleSections::RAISES => {
+                section = DocstringSection::Raises;
+                continue;
+            }
+            _ => {}
+        }
+
+        if trimmed.is_empty() {
+            if section == DocstringSection::None && last_tag == LastTag::None {
+                description.push(String::new());
+            }
+            continue;
+        }
+
+        match section {
+            DocstringSection::Args => add_google_arg(trimmed, &mut documentation),
+            DocstringSection::Returns => append_returns(trimmed, &mut documentation),
+            DocstringSection::Raises => add_google_raises(trimmed, &mut documentation),
+            DocstringSection::None => {
+                if last_tag == LastTag::None {
+                    description.push(trimmed.to_string());
+                } else {
+                    append_to_last_tag(&last_tag, trimmed, &mut documentation);
+                }
+            }
+        }
+    }
+
+    documentation.set_summary(get_summary(&description));
+    documentation
+}
+
+#[derive(PartialEq)]
+enum DocstringSection {
+    None,
+    Args,
+    Returns,
+    Raises,
+}
+
+#[derive(PartialEq)]
+enum LastTag {
+    None,
+    Param,
+    Returns,
+    Throws,
+    Deprecated,
+}
+
+fn parse_tagged_lines(lines: &[String], documentation: &mut Documentation) {
+
+    let mut description = vec![];
+    let mut last_tag = LastTag::None;
+
+    for line in lines {
+        let trimmed = line.trim();
+
+        let tag_opt = trimmed
+            .strip_prefix('@')
+            .or_else(|| trimmed.strip_prefix('\\'));
+
+        if let Some(tag_line) = tag_opt {
+            let (tag, rest) = split_first_word(tag_line);
+            last_tag = match tag {
+                /* Doxygen `\brief` and `\details` are the description itself, not a tag of it */
+                Tags::BRIEF | Tags::SHORT => {
+                    description.push(rest.to_string());
+                    LastTag::None
+                }
+                /* Detailed description is a paragraph of its own so the summary stays the brief one */
+                Tags::DETAILS => {
+                    description.push(String::new());
+                    description.push(rest.to_string());
+                    LastTag::None
+                }
+                _ => add_tag(tag_line, documentation)
+            };
+            continue;
+        }
+
+        if last_tag == LastTag::None {
+            description.push(trimmed.to_string());
+        } else if !trimmed.is_empty() {
+            append_to_last_tag(&last_tag, trimmed, documentation);
+        }
+    }
+
+    documentation.set_summary(get_summary(&description));
+}
+
+fn add_tag(tag_line: &str, documentation: &mut Documentation) -> LastTag {
+
+    let (tag, rest) = split_first_word(tag_line);
+
+    /* Doxygen direction: @param[in] */
+    let tag = tag.split('[').next().unwrap_or(tag);
+    let rest = skip_type_expression(rest);
+
+    match tag {
+        Tags::PARAM => {
+            let (name, description) = split_first_word(rest);
+            let name = name.trim_start_matches('[').trim_end_matches(']');
+            let name = name.split('=').next().unwrap_or(name);
+            /* JSDoc separator: @param {Type} name - description */
+            let description = description.strip_prefix("- ").unwrap_or(description);
+            documentation.add_param(ParamDocumentation::new(name.to_string(), description.to_string()));
+            LastTag::Param
+        }
+        Tags::RETURN | Tags::RETURNS => {
+            documentation.set_returns(rest.to_string());
+            LastTag::Returns
+        }
+        Tags::THROWS | Tags::EXCEPTION => {
+            let (exception, description) = split_first_word(rest);
+            documentation.add_throws(ThrowsDocumentation::new(exception.to_string(), description.to_string()));
+            LastTag::Throws
+        }
+        Tags::DEPRECATED => {
+            documentation.set_deprecated(rest.to_string());
+            LastTag::Deprecated
+        }
+        _ => LastTag::None
+    }
+}
+
+fn add_rest_field(field: &str, documentation: &mut Documentation) -> LastTag {
+
+    /* `:param int x: description` -> ("param int x", "description") */
+    let mut parts = field.splitn(2, ':');
+    let field_name = parts.next().unwrap_or("").trim();
+    let description = parts.next().unwrap_or("").trim().to_string();
+    let words: Vec<&str> = field_name.split_whitespace().collect();
+
+    match words.first().copied().unwrap_or("") {
+        Tags::PARAM => {
+            let name = words.last().copied().unwrap_or("").to_string();
+            documentation.add_param(ParamDocumentation::new(name, description));
+            LastTag::Param
+        }
+        Tags::RETURN | Tags::RETURNS => {
+            documentation.set_returns(description);
+            LastTag::Returns
+        }
+        Tags::RAISES | Tags::RAISE | Tags::THROWS => {
+            let exception = words.get(1).copied().unwrap_or("").to_string();
+            documentation.add_throws(ThrowsDocumentation::new(exception, description));
+            LastTag::Throws
+        }
+        _ => LastTag::None
+    }
+}
+
+fn add_google_arg(line: &str, documentation: &mut Documentation) {
+
+    /* `name (type): description` */
+    match line.split_once(':') {
+        Some((name, description)) if !name.trim().contains(' ') || name.contains('(') => {
+            let name = name.split('(').next().unwrap_or(name).trim().to_string();
+            documentation.add_param(ParamDocumentation::new(name, description.trim().to_string()));
+        }
+        _ => append_to_last_tag(&LastTag::Param, line, documentation)
+    }
+}
+
+fn add_google_raises(line: &str, documentation: &mut Documentation) {
+
+    match line.split_once(':') {
+        Some((exception, description)) if !exception.trim().contains(' ') => {
+            documentation.add_throws(ThrowsDocumentation::new(
+                exception.trim().to_string(),
+                description.trim().to_string(),
+            ));
+        }
+        _ => append_to_last_tag(&LastTag::Throws, line, documentation)
+    }
+}
+
+fn append_returns(line: &str, documentation: &mut Documentation) {
+    let returns = match documentation.returns() {
+        Some(returns) if !returns.is_empty() => format!("{} {}", returns, line),
+        _ => line.to_string(),
+    };
+    documentation.set_returns(returns);
+}
+
+fn append_to_last_tag(last_tag: &LastTag, line: &str, documentation: &mut Documentation) {
+
+    match last_tag {
+        LastTag::Param => {
+            if let Some(param) = documentation.mut_params().last_mut() {
+                param.append_description(line);
+            }
+        }
+        LastTag::Returns => append_returns(line, documentation),
+        LastTag::Throws => {
+            if let Some(throws) = documentation.mut_throws().last_mut() {
+                throws.append_description(line);
+            }
+        }
+        LastTag::Deprecated => {
+            let deprecated = join_description(&documentation.deprecated().clone().unwrap_or_default(), line);
+            documentation.set_deprecated(deprecated);
+        }
+        LastTag::None => {}
+    }
+}
+
+pub fn join_description(description: &str, line: &str) -> String {
+    if description.is_empty() { line.to_string() } else { format!("{} {}", description, line) }
+}
+
+/* Helpers */
+fn strip_comment_markers(line: &str) -> String {
+
+    let mut line = line;
+    for prefix in ["/**", "/*!", "///<", "///", "//!"].iter() {
+        if let Some(stripped) = line.strip_prefix(prefix) {
+            line = stripped;
+            break;
+        }
+    }
+
+    let line = line.strip_suffix("*/").unwrap_or(line).trim_end();
+    let line = line.trim_start();
+    let line = line.strip_prefix('*').unwrap_or(line);
+
+    line.strip_prefix(' ').unwrap_or(line).to_string()
+}
+
+fn strip_string_quotes(raw: &str) -> &str {
+
+    let string = raw.trim_start_matches(|c: char| c.is_ascii_alphabetic());
+
+    for quotes in ["\"\"\"", "'''", "\"", "'"].iter() {
+        if string.starts_with(quotes) && string.ends_with(quotes) && string.len() >= 2 * quotes.len() {
+            return &string[quotes.len()..string.len() - quotes.len()];
+        }
+    }
+    string
+}
+
+fn dedent(text: &str) -> Vec<String> {
+
+    /* Same rules as `inspect.cleandoc`: the first line is not taken into account */
+    let lines: Vec<&str> = text.lines().collect();
+    let indent = lines
+        .iter()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.chars().take_while(|c| c.is_whitespace()).count())
+        .min()
+        .unwrap_or(0);
+
+    /* Indent is counted in characters, `line[indent..]` could cut a multibyte whitespace */
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| match line.char_indices().nth(indent) {
+            Some((offset, _)) if index > 0 => line[offset..].trim_end().to_string(),
+            _ => line.trim().to_string()
+        })
+        .collect()
+}
+
+fn trim_empty_lines(lines: Vec<String>) -> Vec<String> {
+    let start = lines.iter().position(|line| !line.trim().is_empty()).unwrap_or(lines.len());
+    let end = lines.iter().rposition(|line| !line.trim().is_empty()).map(|x| x + 1).unwrap_or(start);
+    lines[start..end].to_vec()
+}
+
+fn get_summary(description: &[String]) -> String {
+
+    let paragraph = description
+        .iter()
+        .map(|line| line.trim())
+        .skip_while(|line| line.is_empty())
+        .take_while(|line| !line.is_empty())
+        .collect::<Vec<&str>>()
+        .join(" ");
+
+    /* First sentence of the first paragraph */
+    match paragraph.find(". ") {
+        Some(index) => paragraph[..index + 1].to_string(),
+        None => paragraph
+    }
+}
+
+fn split_first_word(text: &str) -> (&str, &str) {
+    let text = text.trim();
+    match text.find(char::is_whitespace) {
+        Some(index) => (&text[..index], text[index..].trim()),
+        None => (text, "")
+    }
+}
+
+fn skip_type_expression(text: &str) -> &str {
+
+    /* JSDoc type expression: `@param {string} name` */
+    if !text.starts_with('{') {
+        return text;
+    }
+
+    let mut depth = 0;
+    for (index, char) in text.char_indices() {
+        match char {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return text[index + 1..].trim();
+                }
+            }
+            _ => {}
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod doc_comment_tests {
+
+    use super::*;
+    use serde_json::Value;
+
+    fn to_json(documentation: &Documentation) -> Value {
+        serde_json::to_value(documentation).unwrap()
+    }
+
+    #[test]
+    pub fn test_parse_doc_comment() {
+
+        let javadoc = "/**\n * Saves the user. Second sentence.\n *\n * @param user the user\n *        to save\n * @return saved id\n * @throws IOException when disk is full\n * @deprecated use {@link #store}\n */";
+        let documentation = parse_doc_comment(javadoc);
+        let json = to_json(&documentation);
+        assert_eq!(json["summary"], "Saves the user.");
+        assert_eq!(json["params"].as_array().unwrap().len(), 1);
+        assert_eq!(json["params"][0]["name"], "user");
+        assert_eq!(json["params"][0]["description"], "the user to save");
+        assert_eq!(documentation.returns(), &Some("saved id".to_string()));
+        assert_eq!(json["throws"][0]["exception"], "IOException");
+        assert_eq!(documentation.deprecated(), &Some("use {@link #store}".to_string()));
+
+        let jsdoc = "/** Greets.\n * @param {string} [name=World] who\n * @returns {string} greeting */";
+        let documentation = parse_doc_comment(jsdoc);
+        let json = to_json(&documentation);
+        assert_eq!(json["params"][0]["name"], "name");
+        assert_eq!(documentation.returns(), &Some("greeting".to_string()));
+
+        let doxygen = "/// Adds numbers\n/// \\param[in] a first\n/// \\return sum";
+        let documentation = parse_doc_comment(doxygen);
+        let json = to_json(&documentation);
+        assert_eq!(json["summary"], "Adds numbers");
+        assert_eq!(json["params"][0]["name"], "a");
+        assert_eq!(documentation.returns(), &Some("sum".to_string()));
+
+        let brief = "/**\n * @brief Opens the file\n * @details Fails when the file is locked.\n * @param path file path\n */";
+        let documentation = parse_doc_comment(brief);
+        let json = to_json(&documentation);
+        assert_eq!(json["summary"], "Opens the file");
+        assert_eq!(json["params"][0]["name"], "path");
+
+        let details = "/// \\details Merges two sorted lists. Keeps order.";
+        let json = to_json(&parse_doc_comment(details));
+        assert_eq!(json["summary"], "Merges two sorted lists.");
+    }
+
+    #[test]
+    pub fn test_parse_docstring() {
+
+        let rest = "\"\"\"Loads config.\n\n    :param path: file path\n    :returns: config\n    :raises IOError: missing file\n    \"\"\"";
+        let documentation = parse_docstring(rest);
+        let json = to_json(&documentation);
+        assert_eq!(json["summary"], "Loads config.");
+        assert_eq!(json["params"][0]["name"], "path");
+        assert_eq!(documentation.returns(), &Some("config".to_string()));
+        assert_eq!(json["throws"][0]["exception"], "IOError");
+
+        let google = "'''Loads config.\n\n    Args:\n        path (str): file path\n\n    Returns:\n        Parsed config.\n    '''";
+        let documentation = parse_docstring(google);
+        let json = to_json(&documentation);
+        assert_eq!(json["params"][0]["name"], "path");
+        assert_eq!(json["params"][0]["description"], "file path");
+        assert_eq!(documentation.returns(), &Some("Parsed config.".to_string()));
+
+        /* Ideographic space is multibyte whitespace, indent of two characters does not cut it */
+        let wide_indent = "\"\"\"Loads config.\n\n  :param path: file path\n\u{3000}\u{3000}:returns: config\"\"\"";
+        let documentation = parse_docstring(wide_indent);
+        let json = to_json(&documentation);
+        assert_eq!(json["summary"], "Loads config.");
+        assert_eq!(documentation.returns(), &Some("config".to_string()));
+    }
+}
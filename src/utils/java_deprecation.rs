@@ -0,0 +1,83 @@
+/* Deprecation of Java declarations by `@Deprecated` annotations and Javadoc `@deprecated` tags, shared by
+   declaration and invocation visitors so calls are marked without a second visit of the file */
+use tree_sitter::Node;
+use crate::utils::doc_comment::{is_doc_comment, parse_doc_comment};
+use crate::utils::node::get_child_node_by_kind;
+
+struct NodeKinds;
+impl NodeKinds {
+    const COMMENT: &'static str = "comment";
+    const MODIFIERS: &'static str = "modifiers";
+    const ANNOTATION: &'static str = "annotation";
+    const MARKER_ANNOTATION: &'static str = "marker_annotation";
+    const FORMAL_PARAMETER: &'static str = "formal_parameter";
+    const SPREAD_PARAMETER: &'static str = "spread_parameter";
+}
+
+struct NodeNames;
+impl NodeNames {
+    const NAME: &'static str = "name";
+}
+
+struct KeyWords;
+impl KeyWords {
+    const DEPRECATED_ANNOTATION: &'static str = "Deprecated";
+}
+
+/* Javadoc comment is the previous sibling of the declaration (annotations belong to modifiers) */
+pub fn get_doc_comment(node: &Node, source_code: &str) -> Option<String> {
+
+    let comment_node = node.prev_named_sibling()?;
+    if comment_node.kind() != NodeKinds::COMMENT {
+        return None;
+    }
+
+    let comment = get_node_text(&comment_node, source_code);
+    if !is_doc_comment(comment) {
+        return None;
+    }
+
+    Some(comment.to_string())
+}
+
+/* `@Deprecated` or `@java.lang.Deprecated` among modifiers of declaration */
+pub fn has_deprecated_annotation(node: &Node, source_code: &str) -> bool {
+
+    let modifiers_node = match get_child_node_by_kind(node, NodeKinds::MODIFIERS) {
+        Some(modifiers_node) => modifiers_node,
+        None => return false,
+    };
+
+    modifiers_node
+        .named_children(&mut modifiers_node.walk())
+        .filter(|x| x.kind() == NodeKinds::ANNOTATION || x.kind() == NodeKinds::MARKER_ANNOTATION)
+        .filter_map(|x| x.child_by_field_name(NodeNames::NAME))
+        .any(|x| get_node_text(&x, source_code).rsplit('.').next() == Some(KeyWords::DEPRECATED_ANNOTATION))
+}
+
+/* Deprecated by annotation or Javadoc tag */
+pub fn is_deprecated(node: &Node, source_code: &str) -> bool {
+    has_deprecated_annotation(node, source_code)
+        || get_doc_comment(node, source_code).is_some_and(|x| parse_doc_comment(&x).deprecated().is_some())
+}
+
+/* Least and greatest number of arguments of call, None for varargs */
+pub fn get_count_of_params_range(parameters_node: &Node) -> (usize, Option<usize>) {
+
+    let mut count_of_params = 0;
+
+    for child in parameters_node.named_children(&mut parameters_node.walk()) {
+        match child.kind() {
+            NodeKinds::FORMAL_PARAMETER => count_of_params += 1,
+            /* Varargs may be omitted or take any number of arguments */
+            NodeKinds::SPREAD_PARAMETER => return (count_of_params, None),
+            _ => {} /* Receiver parameter */
+        }
+    }
+
+    (count_of_params, Some(count_of_params))
+}
+
+fn get_node_text<'a>(node: &Node, source_code: &'a str) -> &'a str {
+    &source_code[node.start_byte()..node.end_byte()]
+}
@@ -0,0 +1,72 @@
+/* Deprecation of JavaScript declarations by JSDoc `@deprecated` tags, shared by declaration and invocation
+   visitors so calls are marked without a second visit of the file */
+use tree_sitter::Node;
+use crate::utils::doc_comment::{is_doc_comment, parse_doc_comment};
+
+struct NodeKinds;
+impl NodeKinds {
+    const COMMENT: &'static str = "comment";
+    const VARIABLE_DECLARATOR: &'static str = "variable_declarator";
+    const LEXICAL_DECLARATION: &'static str = "lexical_declaration";
+    const VARIABLE_DECLARATION: &'static str = "variable_declaration";
+    const EXPORT_STATEMENT: &'static str = "export_statement";
+    const ASSIGNMENT_PATTERN: &'static str = "assignment_pattern";
+    const REST_PATTERN: &'static str = "rest_pattern";
+}
+
+struct KeyWords;
+impl KeyWords {
+    const JS_DOC_START: &'static str = "/**";
+}
+
+/* JSDoc comment is placed before the whole statement: export, const/let/var declaration */
+pub fn get_doc_comment(node: &Node, source_code: &str) -> Option<String> {
+
+    let mut statement_node = *node;
+    while let Some(parent_node) = statement_node.parent() {
+        match parent_node.kind() {
+            NodeKinds::VARIABLE_DECLARATOR | NodeKinds::LEXICAL_DECLARATION |
+            NodeKinds::VARIABLE_DECLARATION | NodeKinds::EXPORT_STATEMENT => statement_node = parent_node,
+            _ => break
+        }
+    }
+
+    let comment_node = statement_node.prev_named_sibling()?;
+    if comment_node.kind() != NodeKinds::COMMENT {
+        return None;
+    }
+
+    let comment = &source_code[comment_node.start_byte()..comment_node.end_byte()];
+    if !comment.starts_with(KeyWords::JS_DOC_START) || !is_doc_comment(comment) {
+        return None;
+    }
+
+    Some(comment.to_string())
+}
+
+/* Deprecated by JSDoc tag */
+pub fn is_deprecated(node: &Node, source_code: &str) -> bool {
+    get_doc_comment(node, source_code).is_some_and(|x| parse_doc_comment(&x).deprecated().is_some())
+}
+
+/* Least and greatest number of arguments of call, None for rest parameter */
+pub fn get_count_of_params_range(parameters_node: &Node) -> (usize, Option<usize>) {
+
+    let mut min_count_of_params = 0;
+    let mut max_count_of_params = 0;
+
+    for child in parameters_node.named_children(&mut parameters_node.walk()) {
+        match child.kind() {
+            /* Parameter with default value may be omitted */
+            NodeKinds::ASSIGNMENT_PATTERN => max_count_of_params += 1,
+            NodeKinds::REST_PATTERN => return (min_count_of_params, None),
+            NodeKinds::COMMENT => {}
+            _ => {
+                min_count_of_params += 1;
+                max_count_of_params += 1;
+            }
+        }
+    }
+
+    (min_count_of_params, Some(max_count_of_params))
+}
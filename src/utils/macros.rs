@@ -16,4 +16,13 @@ macro_rules! unwrap_or_empty_string {
             Some(value) => value
         }
     }
-}
\ No newline at end of file
+}
+#[macro_export]
+macro_rules! unwrap_or_continue {
+    ( $e:expr ) => {
+        match $e {
+            None => continue,
+            Some(value) => value
+        }
+    }
+}
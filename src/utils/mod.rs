@@ -1 +1,10 @@
 pub mod macros;
+pub mod doc_comment;pub mod path;
+pub mod python_module;
+pub mod cpp_preprocessor;
+pub mod node;
+pub mod cpp_namespace;
+pub mod cpp_deprecation;
+pub mod java_deprecation;
+pub mod js_deprecation;
+pub mod python_deprecation;
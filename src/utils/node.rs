@@ -0,0 +1,16 @@
+/* Lookups over tree-sitter nodes shared by visitors */
+use tree_sitter::Node;
+
+/* First child of the kind, anonymous tokens like `operator` included */
+pub fn get_child_node_by_kind<'a>(node: &Node<'a>, kind: &str) -> Option<Node<'a>> {
+    node.children(&mut node.walk()).find(|child| child.kind() == kind)
+}
+
+/* The node itself or its first named descendant of the kind, depth-first */
+pub fn find_descendant_by_kind<'a>(node: Node<'a>, kind: &str) -> Option<Node<'a>> {
+
+    if node.kind() == kind {
+        return Some(node);
+    }
+    node.named_children(&mut node.walk()).find_map(|child| find_descendant_by_kind(child, kind))
+}
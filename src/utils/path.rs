@@ -0,0 +1,69 @@
+/* Lexical helpers for repository paths, `/` separated. The file system is never accessed */
+
+const SEPARATOR: char = '/';
+const CURRENT_DIR: &str = ".";
+const PARENT_DIR: &str = "..";
+
+/* Resolves `.` and `..` segments and duplicated separators */
+pub fn normalize_path(path: &str) -> String {
+
+    let path = path.replace('\\', "/");
+    let mut segments: Vec<&str> = vec![];
+
+    for segment in path.split(SEPARATOR) {
+        match segment {
+            "" | CURRENT_DIR => {}
+            PARENT_DIR => {
+                if segments.is_empty() || segments.last() == Some(&PARENT_DIR) {
+                    segments.push(PARENT_DIR);
+                } else {
+                    segments.pop();
+                }
+            }
+            _ => segments.push(segment)
+        }
+    }
+
+    let normalized = segments.join("/");
+    if path.starts_with(SEPARATOR) { format!("/{}", normalized) } else { normalized }
+}
+
+/* Directory part of the path, empty string for file in repository root */
+pub fn parent_directory(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    match path.rfind(SEPARATOR) {
+        Some(index) => path[..index].to_string(),
+        None => String::new()
+    }
+}
+
+pub fn join_path(directory: &str, path: &str) -> String {
+    if directory.is_empty() || path.starts_with(SEPARATOR) {
+        return normalize_path(path);
+    }
+    normalize_path(&format!("{}/{}", directory, path))
+}
+
+#[cfg(test)]
+mod path_tests {
+
+    use super::*;
+
+    #[test]
+    pub fn test_normalize_path() {
+        assert_eq!(normalize_path("src/./utils//path.rs"), "src/utils/path.rs");
+        assert_eq!(normalize_path("src/visitor/../utils/path.rs"), "src/utils/path.rs");
+        assert_eq!(normalize_path("../include/a.h"), "../include/a.h");
+        assert_eq!(normalize_path("/usr/include/../lib"), "/usr/lib");
+        assert_eq!(normalize_path("src\\main.cpp"), "src/main.cpp");
+    }
+
+    #[test]
+    pub fn test_join_path() {
+        assert_eq!(parent_directory("src/app/main.cpp"), "src/app");
+        assert_eq!(parent_directory("main.cpp"), "");
+        assert_eq!(join_path("src/app", "../include/a.h"), "src/include/a.h");
+        assert_eq!(join_path("", "a.h"), "a.h");
+        assert_eq!(join_path("src", "/abs/a.h"), "/abs/a.h");
+    }
+}
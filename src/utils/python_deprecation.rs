@@ -0,0 +1,203 @@
+/* Deprecation of Python declarations by `@deprecated` decorators (PEP 702), `warnings.warn(DeprecationWarning)`
+   calls and `.. deprecated::` docstring directives, shared by declaration and invocation visitors so calls are
+   marked without a second visit of the file */
+use tree_sitter::Node;
+use crate::unwrap_or_continue;
+use crate::utils::doc_comment::parse_docstring;
+use crate::utils::node::get_child_node_by_kind;
+
+struct NodeKinds;
+impl NodeKinds {
+    const COMMENT: &'static str = "comment";
+    const EXPRESSION_STATEMENT: &'static str = "expression_statement";
+    const STRING: &'static str = "string";
+    const CALL: &'static str = "call";
+    const KEYWORD_ARGUMENT: &'static str = "keyword_argument";
+    const DECORATED_DEFINITION: &'static str = "decorated_definition";
+    const DECORATOR: &'static str = "decorator";
+    const IDENTIFIER: &'static str = "identifier";
+    const TYPED_PARAMETER: &'static str = "typed_parameter";
+    const DEFAULT_PARAMETER: &'static str = "default_parameter";
+    const TYPED_DEFAULT_PARAMETER: &'static str = "typed_default_parameter";
+    const LIST_SPLAT_PATTERN: &'static str = "list_splat_pattern";
+    const DICTIONARY_SPLAT_PATTERN: &'static str = "dictionary_splat_pattern";
+}
+
+struct NodeNames;
+impl NodeNames {
+    const BODY: &'static str = "body";
+    const FUNCTION: &'static str = "function";
+    const ARGUMENTS: &'static str = "arguments";
+    const VALUE: &'static str = "value";
+}
+
+struct KeyWords;
+impl KeyWords {
+    const DEPRECATED: &'static str = "deprecated";
+    const WARN: &'static str = "warn";
+    const DEPRECATION_WARNING: &'static str = "DeprecationWarning";
+    const SELF_SPECIFIER: &'static str = "self";
+    const CLS_SPECIFIER: &'static str = "cls";
+}
+
+/* Docstring is a string literal which is the first statement of a module, class or function body */
+pub fn get_docstring(node: &Node, source_code: &str) -> Option<String> {
+
+    let statement = node
+        .named_children(&mut node.walk())
+        .find(|x| x.kind() != NodeKinds::COMMENT)?;
+    if statement.kind() != NodeKinds::EXPRESSION_STATEMENT {
+        return None;
+    }
+
+    let string_node = statement.named_child(0)?;
+    if string_node.kind() != NodeKinds::STRING {
+        return None;
+    }
+
+    Some(get_node_text(&string_node, source_code).to_string())
+}
+
+/* `@deprecated("message")` decorator or `warnings.warn("message", DeprecationWarning)` in the body of class or
+   function, None if declaration has neither */
+pub fn get_deprecation(node: &Node, source_code: &str) -> Option<Option<String>> {
+    get_deprecation_from_decorators(node, source_code)
+        .or_else(|| get_deprecation_from_warning(node, source_code))
+}
+
+/* Deprecated by decorator, warning or docstring directive */
+pub fn is_deprecated(node: &Node, source_code: &str) -> bool {
+    get_deprecation(node, source_code).is_some()
+        || node.child_by_field_name(NodeNames::BODY)
+            .and_then(|body_node| get_docstring(&body_node, source_code))
+            .is_some_and(|x| parse_docstring(&x).deprecated().is_some())
+}
+
+/* Least and greatest number of arguments of call, None for `*args` and `**kwargs`. `self` and `cls` are bound
+   by the call and are not counted, the same as on declaration side */
+pub fn get_count_of_params_range(parameters_node: &Node, source_code: &str) -> (usize, Option<usize>) {
+
+    let mut min_count_of_params = 0;
+    let mut max_count_of_params = Some(0);
+
+    for child in parameters_node.named_children(&mut parameters_node.walk()) {
+        let is_variadic = match child.kind() {
+            NodeKinds::TYPED_PARAMETER => get_child_node_by_kind(&child, NodeKinds::LIST_SPLAT_PATTERN)
+                .or_else(|| get_child_node_by_kind(&child, NodeKinds::DICTIONARY_SPLAT_PATTERN))
+                .is_some(),
+            /* Bare `*` separates keyword-only parameters */
+            NodeKinds::LIST_SPLAT_PATTERN => child.named_child_count() > 0,
+            NodeKinds::DICTIONARY_SPLAT_PATTERN => true,
+            _ => false
+        };
+
+        match child.kind() {
+            _ if is_variadic => max_count_of_params = None,
+            NodeKinds::IDENTIFIER | NodeKinds::TYPED_PARAMETER if !is_bound_parameter(&child, source_code) => {
+                min_count_of_params += 1;
+                max_count_of_params = max_count_of_params.map(|x| x + 1);
+            }
+            /* Parameter with default value may be omitted */
+            NodeKinds::DEFAULT_PARAMETER | NodeKinds::TYPED_DEFAULT_PARAMETER =>
+                max_count_of_params = max_count_of_params.map(|x| x + 1),
+            _ => {}
+        }
+    }
+
+    (min_count_of_params, max_count_of_params)
+}
+
+fn get_deprecation_from_decorators(node: &Node, source_code: &str) -> Option<Option<String>> {
+
+    for decorator in get_decorator_nodes(node) {
+        let expression = unwrap_or_continue!(decorator.named_child(0));
+        let (function_node, arguments_node) = match expression.kind() {
+            NodeKinds::CALL => (
+                unwrap_or_continue!(expression.child_by_field_name(NodeNames::FUNCTION)),
+                expression.child_by_field_name(NodeNames::ARGUMENTS)
+            ),
+            _ => (expression, None)
+        };
+
+        if get_last_name_segment(&function_node, source_code) == KeyWords::DEPRECATED {
+            return Some(arguments_node.and_then(|arguments| get_first_string_argument(&arguments, source_code)));
+        }
+    }
+
+    None
+}
+
+fn get_deprecation_from_warning(node: &Node, source_code: &str) -> Option<Option<String>> {
+
+    let body_node = node.child_by_field_name(NodeNames::BODY)?;
+
+    for statement in body_node.named_children(&mut body_node.walk()) {
+        if statement.kind() != NodeKinds::EXPRESSION_STATEMENT {
+            continue;
+        }
+
+        let call = unwrap_or_continue!(statement.named_child(0));
+        if call.kind() != NodeKinds::CALL {
+            continue;
+        }
+
+        let function_node = unwrap_or_continue!(call.child_by_field_name(NodeNames::FUNCTION));
+        let arguments_node = unwrap_or_continue!(call.child_by_field_name(NodeNames::ARGUMENTS));
+        if get_last_name_segment(&function_node, source_code) != KeyWords::WARN {
+            continue;
+        }
+
+        /* Category is the second positional argument or `category=` keyword argument */
+        let is_deprecation_warning = arguments_node
+            .named_children(&mut arguments_node.walk())
+            .filter_map(|argument| match argument.kind() {
+                NodeKinds::KEYWORD_ARGUMENT => argument.child_by_field_name(NodeNames::VALUE),
+                _ => Some(argument)
+            })
+            .any(|argument| get_last_name_segment(&argument, source_code).ends_with(KeyWords::DEPRECATION_WARNING));
+
+        if is_deprecation_warning {
+            return Some(get_first_string_argument(&arguments_node, source_code));
+        }
+    }
+
+    None
+}
+
+fn get_decorator_nodes<'tree>(node: &Node<'tree>) -> Vec<Node<'tree>> {
+
+    match node.parent() {
+        Some(parent) if parent.kind() == NodeKinds::DECORATED_DEFINITION => parent
+            .named_children(&mut parent.walk())
+            .filter(|x| x.kind() == NodeKinds::DECORATOR)
+            .collect(),
+        _ => vec![]
+    }
+}
+
+fn get_first_string_argument(node: &Node, source_code: &str) -> Option<String> {
+
+    let string_node = get_child_node_by_kind(node, NodeKinds::STRING)?;
+    Some(get_node_text(&string_node, source_code).trim_matches(['"', '\'']).to_string())
+}
+
+/* `deprecated`, `typing_extensions.deprecated`, `warnings.warn` */
+fn get_last_name_segment<'a>(node: &Node, source_code: &'a str) -> &'a str {
+    get_node_text(node, source_code).rsplit('.').next().unwrap_or_default()
+}
+
+fn is_bound_parameter(node: &Node, source_code: &str) -> bool {
+
+    let name_node = match node.kind() {
+        NodeKinds::TYPED_PARAMETER => get_child_node_by_kind(node, NodeKinds::IDENTIFIER),
+        _ => Some(*node)
+    };
+
+    name_node
+        .map(|x| get_node_text(&x, source_code))
+        .is_some_and(|name| name == KeyWords::SELF_SPECIFIER || name == KeyWords::CLS_SPECIFIER)
+}
+
+fn get_node_text<'a>(node: &Node, source_code: &'a str) -> &'a str {
+    &source_code[node.start_byte()..node.end_byte()]
+}
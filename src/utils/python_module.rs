@@ -0,0 +1,190 @@
+/* Python module names of repository files, `src/mypkg/util.py` is module `mypkg.util` of source root `src` */
+use std::collections::HashSet;
+use crate::utils::path::{normalize_path, parent_directory, join_path};
+
+const SEPARATOR: char = '/';
+const MODULE_SEPARATOR: &str = ".";
+const RELATIVE_PREFIX: char = '.';
+const PY_EXTENSION: &str = ".py";
+const STUB_EXTENSION: &str = ".pyi";
+const INIT_FILE: &str = "__init__.py";
+const INIT_STUB_FILE: &str = "__init__.pyi";
+const INIT_MODULE: &str = "__init__";
+const SRC_DIRECTORY: &str = "src";
+const PROJECT_FILES: [&str; 3] = ["setup.py", "setup.cfg", "pyproject.toml"];
+
+/* Module of the file relative to the longest matching source root, package `__init__.py` is named by its directory.
+   Stub `util.pyi` names the same module as `util.py` */
+pub fn module_name(path: &str, source_roots: &[String]) -> String {
+
+    let path = normalize_path(path);
+    let source_root = find_source_root(&path, source_roots);
+    let relative_path = if source_root.is_empty() { &path[..] } else { &path[source_root.len() + 1..] };
+    let relative_path = relative_path.strip_suffix(STUB_EXTENSION)
+        .or_else(|| relative_path.strip_suffix(PY_EXTENSION))
+        .unwrap_or(relative_path);
+
+    let mut segments: Vec<&str> = relative_path.split(SEPARATOR).collect();
+    if segments.last() == Some(&INIT_MODULE) {
+        segments.pop();
+    }
+    segments.join(MODULE_SEPARATOR)
+}
+
+/* Package of the module used by its relative imports, package `__init__.py` is the package itself */
+pub fn module_package(path: &str, source_roots: &[String]) -> String {
+
+    let module = module_name(path, source_roots);
+    if is_package_init(path) {
+        return module;
+    }
+    match module.rfind(MODULE_SEPARATOR) {
+        Some(index) => module[..index].to_string(),
+        None => String::new()
+    }
+}
+
+pub fn is_package_init(path: &str) -> bool {
+    let file_name = normalize_path(path).rsplit(SEPARATOR).next().unwrap_or_default().to_string();
+    file_name == INIT_FILE || file_name == INIT_STUB_FILE
+}
+
+pub fn is_stub(path: &str) -> bool {
+    path.ends_with(STUB_EXTENSION)
+}
+
+pub fn is_python_file(path: &str) -> bool {
+    path.ends_with(PY_EXTENSION) || is_stub(path)
+}
+
+/* `..core` imported within package `mypkg.sub` is `mypkg.core`, absolute import is kept */
+pub fn resolve_relative_import(import_name: &str, package: &str) -> String {
+
+    let level = import_name.chars().take_while(|x| *x == RELATIVE_PREFIX).count();
+    if level == 0 {
+        return import_name.to_string();
+    }
+
+    let mut segments: Vec<&str> = package.split(MODULE_SEPARATOR).filter(|x| !x.is_empty()).collect();
+    for _ in 1..level {
+        segments.pop();
+    }
+    let relative_name = &import_name[level..];
+    if !relative_name.is_empty() {
+        segments.push(relative_name);
+    }
+    segments.join(MODULE_SEPARATOR)
+}
+
+/* Without configured roots `src` layout is assumed for files under `src` directory */
+fn find_source_root(path: &str, source_roots: &[String]) -> String {
+
+    if source_roots.is_empty() {
+        let src_segment = format!("{}{}{}", SEPARATOR, SRC_DIRECTORY, SEPARATOR);
+        return if path.starts_with(&src_segment[1..]) {
+            SRC_DIRECTORY.to_string()
+        } else {
+            match path.find(&src_segment) {
+                Some(index) => path[..index + src_segment.len() - 1].to_string(),
+                None => String::new()
+            }
+        };
+    }
+
+    source_roots.iter()
+        .map(|x| normalize_path(x))
+        .filter(|x| x.is_empty() || path.starts_with(&format!("{}{}", x, SEPARATOR)))
+        .max_by_key(|x| x.len())
+        .unwrap_or_default()
+}
+
+/* Source roots of repository files: directories of `setup.py`, `setup.cfg` and `pyproject.toml` (their `src`
+   directory for src layout), otherwise parent directory of the outermost package with `__init__.py` */
+pub fn detect_source_roots(paths: &[String]) -> Vec<String> {
+
+    let paths: Vec<String> = paths.iter().map(|x| normalize_path(x)).collect();
+    let path_set: HashSet<&String> = paths.iter().collect();
+    let mut source_roots: Vec<String> = vec![];
+
+    for path in paths.iter() {
+        let file_name = path.rsplit(SEPARATOR).next().unwrap_or_default();
+        if !PROJECT_FILES.contains(&file_name) {
+            continue;
+        }
+        let project_directory = parent_directory(path);
+        let src_directory = join_path(&project_directory, SRC_DIRECTORY);
+        let is_src_layout = paths.iter()
+            .any(|x| x.starts_with(&format!("{}{}", src_directory, SEPARATOR)) && is_python_file(x));
+        source_roots.push(if is_src_layout { src_directory } else { project_directory });
+    }
+
+    let project_roots = source_roots.clone();
+    for path in paths.iter().filter(|x| is_python_file(x)) {
+        let is_in_project = project_roots.iter()
+            .any(|x| x.is_empty() || path.starts_with(&format!("{}{}", x, SEPARATOR)));
+        if is_in_project {
+            continue;
+        }
+        let mut directory = parent_directory(path);
+        while !directory.is_empty() && (path_set.contains(&join_path(&directory, INIT_FILE))
+            || path_set.contains(&join_path(&directory, INIT_STUB_FILE))) {
+            directory = parent_directory(&directory);
+        }
+        source_roots.push(directory);
+    }
+
+    source_roots.sort();
+    source_roots.dedup();
+    source_roots
+}
+
+#[cfg(test)]
+mod python_module_tests {
+
+    use super::*;
+
+    #[test]
+    pub fn test_module_name() {
+        let source_roots = vec!["src".to_string(), "services/api".to_string()];
+        assert_eq!(module_name("src/mypkg/util.py", &source_roots), "mypkg.util");
+        assert_eq!(module_name("src/mypkg/__init__.py", &source_roots), "mypkg");
+        assert_eq!(module_name("services/api/app/main.py", &source_roots), "app.main");
+        assert_eq!(module_name("tools/run.py", &source_roots), "tools.run");
+
+        assert_eq!(module_name("src/mypkg/util.py", &[]), "mypkg.util");
+        assert_eq!(module_name("lib/src/mypkg/util.py", &[]), "mypkg.util");
+        assert_eq!(module_name("mysrc/util.py", &[]), "mysrc.util");
+        assert_eq!(module_name("mypkg/sub/__init__.py", &[]), "mypkg.sub");
+        assert_eq!(module_name("src/mypkg/util.pyi", &[]), "mypkg.util");
+        assert_eq!(module_name("stubs/requests/__init__.pyi", &["stubs".to_string()]), "requests");
+        assert!(is_package_init("stubs/requests/__init__.pyi"));
+    }
+
+    #[test]
+    pub fn test_resolve_relative_import() {
+        assert_eq!(module_package("src/mypkg/sub/service.py", &[]), "mypkg.sub");
+        assert_eq!(module_package("src/mypkg/sub/__init__.py", &[]), "mypkg.sub");
+        assert_eq!(module_package("main.py", &[]), "");
+
+        assert_eq!(resolve_relative_import(".", "mypkg.sub"), "mypkg.sub");
+        assert_eq!(resolve_relative_import(".engine", "mypkg.sub"), "mypkg.sub.engine");
+        assert_eq!(resolve_relative_import("..core", "mypkg.sub"), "mypkg.core");
+        assert_eq!(resolve_relative_import("...", "mypkg.sub"), "");
+        assert_eq!(resolve_relative_import("os.path", "mypkg.sub"), "os.path");
+    }
+
+    #[test]
+    pub fn test_detect_source_roots() {
+        let paths: Vec<String> = [
+            "pyproject.toml", "src/mypkg/__init__.py", "src/mypkg/util.py",
+            "services/api/setup.py", "services/api/app/__init__.py", "services/api/app/main.py",
+            "legacy/core/__init__.py", "legacy/core/models/__init__.py", "legacy/core/models/user.py",
+            "scripts/run.py",
+        ].iter().map(|x| x.to_string()).collect();
+
+        assert_eq!(detect_source_roots(&paths), vec!["legacy", "scripts", "services/api", "src"]);
+        assert_eq!(detect_source_roots(&["mypkg/__init__.py".to_string(), "mypkg/util.py".to_string()]), vec![""]);
+        assert_eq!(detect_source_roots(&["typings/requests/__init__.pyi".to_string(),
+                                         "typings/requests/api.pyi".to_string()]), vec!["typings"]);
+    }
+}
@@ -1,18 +1,32 @@
 use crate::dto::repository_method_dto::{RepositoryMethodDto, RepositoryMethodDtoBuilder};
 use tree_sitter::{Tree, Node};
 use std::io::Read;
+use std::collections::HashMap;
 use crate::unwrap_or_return;
 use crate::unwrap_or_empty_string;
 use crate::model::cpp_object::{ClassObject, CppObject, PackageObject, MethodObject, MethodReturnType, CodeType, ObjectType};
-use crate::model::cpp_object::CodeType::{CPP_METHOD, CPP_CONSTRUCTOR, CPP_ENUM, CPP_PACKAGE, CPP_CLASS};
+use crate::model::cpp_object::CodeType::{CPP_METHOD, CPP_CONSTRUCTOR, CPP_ENUM, CPP_PACKAGE, CPP_CLASS,
+                                         CPP_DESTRUCTOR, CPP_OPERATOR, CPP_CONVERSION_OPERATOR, CPP_TYPE_ALIAS,
+                                         CPP_NAMESPACE_ALIAS, CPP_LAMBDA, CPP_ENUM_CLASS, CPP_ENUMERATOR};
 use crate::model::cpp_object::MethodReturnType::{Value, Reference, Pointer};
 use crate::model::cpp_object::ObjectType::{Definition, Declaration};
+use crate::dto::documentation::Documentation;
+use crate::dto::visibility::Visibility;
+use crate::dto::modifier::Modifier;
+use crate::dto::definition::DeclarationKind;
+use crate::utils::doc_comment::parse_doc_comment;
+use crate::utils::cpp_deprecation::{get_attribute_deprecation, get_doc_comment};
+use crate::utils::node::{find_descendant_by_kind, get_child_node_by_kind};
+use crate::utils::cpp_preprocessor::{evaluate_preproc_branch, get_branch_activity, is_preproc_branch_header,
+                                     update_defined_macros};
+use crate::utils::cpp_namespace::{expand_namespace_aliases, get_namespace_alias, resolve_namespace};
+use crate::dto::parse_options::ParseOptions;
 
-const MAX_TOKEN_LENGTH: usize = 250;
 
 struct NodeKinds;
 impl NodeKinds {
     const ERROR: &'static str = "ERROR";
+    const NAMESPACE_ALIAS_DEFINITION: &'static str = "namespace_alias_definition";
     const CLASS_SPECIFIER: &'static str = "class_specifier";
     const STRUCT_SPECIFIER: &'static str = "struct_specifier";
     const ENUM_SPECIFIER: &'static str = "enum_specifier";
@@ -20,6 +34,8 @@ impl NodeKinds {
     const FRIEND_DECLARATION: &'static str = "friend_declaration";
     const NAMESPACE_DEFINITION: &'static str = "namespace_definition";
     const TEMPLATE_DECLARATION: &'static str = "template_declaration";
+    const TEMPLATE_PARAMETER_LIST: &'static str = "template_parameter_list";
+    const TEMPLATE_FUNCTION: &'static str = "template_function";
     const DECLARATION_LIST: &'static str = "declaration_list";
     const UNION_SPECIFIER: &'static str = "union_specifier";
     const LINKAGE_SPECIFICATION: &'static str = "linkage_specification";
@@ -33,6 +49,9 @@ impl NodeKinds {
     const OPTIONAL_PARAMETER_DECLARATION: &'static str = "optional_parameter_declaration";
     const FIELD_IDENTIFIER: &'static str = "field_identifier";
     const DESTRUCTOR_NAME: &'static str = "destructor_name";
+    const OPERATOR_NAME: &'static str = "operator_name";
+    const OPERATOR_CAST: &'static str = "operator_cast";
+    const ABSTRACT_FUNCTION_DECLARATOR: &'static str = "abstract_function_declarator";
     const SCOPED_IDENTIFIER: &'static str = "scoped_identifier";
     const NAMESPACE_IDENTIFIER: &'static str = "namespace_identifier";
     const TEMPLATE_TYPE: &'static str = "template_type";
@@ -44,6 +63,22 @@ impl NodeKinds {
     const SIZED_TYPE_SPECIFIER: &'static str = "sized_type_specifier";
     const REFERENCE_DECLARATOR: &'static str = "reference_declarator";
     const POINTER_DECLARATOR: &'static str = "pointer_declarator";
+    const ACCESS_SPECIFIER: &'static str = "access_specifier";
+    const FIELD_DECLARATION_LIST: &'static str = "field_declaration_list";
+    const STORAGE_CLASS_SPECIFIER: &'static str = "storage_class_specifier";
+    const VIRTUAL_FUNCTION_SPECIFIER: &'static str = "virtual_function_specifier";
+    const EXPLICIT_FUNCTION_SPECIFIER: &'static str = "explicit_function_specifier";
+    const VIRTUAL_SPECIFIER: &'static str = "virtual_specifier";
+    const TYPE_QUALIFIER: &'static str = "type_qualifier";
+    const NOEXCEPT: &'static str = "noexcept";
+    const TYPE_DEFINITION: &'static str = "type_definition";
+    const ALIAS_DECLARATION: &'static str = "alias_declaration";
+    const PREPROC_ELIF: &'static str = "preproc_elif";
+    const PREPROC_DEF: &'static str = "preproc_def";
+    const PREPROC_CALL: &'static str = "preproc_call";
+    const INIT_DECLARATOR: &'static str = "init_declarator";
+    const LAMBDA_EXPRESSION: &'static str = "lambda_expression";
+    const ENUMERATOR: &'static str = "enumerator";
 }
 struct NodeNames;
 impl NodeNames {
@@ -51,24 +86,140 @@ impl NodeNames {
     const NAME: &'static str = "name";
     const DECLARATOR: &'static str = "declarator";
     const PARAMETERS: &'static str = "parameters";
+    const ARGUMENTS: &'static str = "arguments";
     const NAMESPACE: &'static str = "namespace";
     const TYPE: &'static str = "type";
+    const DEFAULT_VALUE: &'static str = "default_value";
+    const CONDITION: &'static str = "condition";
+    const ALTERNATIVE: &'static str = "alternative";
+    const VALUE: &'static str = "value";
+    const BASE: &'static str = "base";
 }
 
 struct KeyWords;
 impl KeyWords {
     const EMPTY_STRING: &'static str = "";
     const VOID: &'static str = "void";
+    const PUBLIC: &'static str = "public";
+    const PROTECTED: &'static str = "protected";
+    const PRIVATE: &'static str = "private";
+    const STATIC: &'static str = "static";
+    const CONSTEXPR: &'static str = "constexpr";
+    const SCOPE_SEPARATOR: &'static str = "::";
+    const OPERATOR: &'static str = "operator";
+    const PURE_SPECIFIER: &'static str = "0";
+    const PURE: &'static str = "pure";
+    const ABSTRACT: &'static str = "abstract";
+    const IFNDEF: &'static str = "#ifndef";
+    const CONDITION_SEPARATOR: &'static str = " && ";
+    const CLASS: &'static str = "class";
+    const STRUCT: &'static str = "struct";
+}
+
+/* Function enclosing named lambda, empty for lambda of namespace or class scope */
+struct LambdaOwner {
+    /* Qualifier of out-of-line function `Foo::bar`, otherwise lambda belongs to the enclosing class or namespace */
+    namespace: String,
+    qualified_name: String,
+    visibility: Visibility,
 }
 
 struct DeclarationData {
     source_code: String,
     path: String,
+    /* Access of the current class section (public:, protected:, private:) */
+    current_visibility: Visibility,
+    /* Enclosing namespaces and classes of the current node */
+    scope: Vec<String>,
+    /* Parameters of enclosing template declaration, taken by the declared class or function */
+    template_parameters: Option<Vec<String>>,
+    /* Current class declares pure virtual method */
+    has_pure_methods: bool,
+    /* Configured macros with `#define` and `#undef` of the file applied, None for unknown configuration */
+    defined_macros: Option<HashMap<String, String>>,
+    /* Conditions of enclosing preprocessor branches */
+    preprocessor_conditions: Vec<String>,
+    /* Namespace aliases `fs` of `namespace fs = std::filesystem;` declared so far */
+    namespace_aliases: HashMap<String, String>,
+    options: ParseOptions,
 }
 
 impl DeclarationData {
-    fn new(source_code: String, path: String) -> Self {
-        Self { source_code, path }
+    fn new(source_code: String, path: String, options: ParseOptions) -> Self {
+        let defined_macros = options.preprocessor().defined_macros().clone();
+        Self { source_code, path, current_visibility: Visibility::Public, scope: vec![],
+            template_parameters: None, has_pure_methods: false, defined_macros,
+            preprocessor_conditions: vec![], namespace_aliases: HashMap::new(), options }
+    }
+
+    fn defined_macros(&self) -> &Option<HashMap<String, String>> {
+        &self.defined_macros
+    }
+
+    fn update_defined_macros(&mut self, node: &Node) {
+        if let Some(defined_macros) = self.defined_macros.as_mut() {
+            update_defined_macros(node, &self.source_code, defined_macros);
+        }
+    }
+
+    fn push_preprocessor_condition(&mut self, condition: String) {
+        self.preprocessor_conditions.push(condition);
+    }
+
+    fn pop_preprocessor_condition(&mut self) {
+        self.preprocessor_conditions.pop();
+    }
+
+    fn preprocessor_condition(&self) -> String {
+        join_preproc_conditions(&self.preprocessor_conditions)
+    }
+
+    fn namespace_aliases(&self) -> &HashMap<String, String> {
+        &self.namespace_aliases
+    }
+
+    fn add_namespace_alias(&mut self, alias: String, namespace: String) {
+        self.namespace_aliases.insert(alias, namespace);
+    }
+
+    fn scope(&self) -> &Vec<String> {
+        &self.scope
+    }
+
+    fn push_scope(&mut self, name: String) {
+        self.scope.push(name);
+    }
+
+    fn pop_scope(&mut self) {
+        self.scope.pop();
+    }
+
+    fn has_pure_methods(&self) -> bool {
+        self.has_pure_methods
+    }
+
+    fn set_has_pure_methods(&mut self, has_pure_methods: bool) {
+        self.has_pure_methods = has_pure_methods;
+    }
+
+    fn set_template_parameters(&mut self, template_parameters: Option<Vec<String>>) {
+        self.template_parameters = template_parameters;
+    }
+
+    fn take_template_parameters(&mut self) -> Option<Vec<String>> {
+        std::mem::take(&mut self.template_parameters)
+    }
+
+    fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+
+    fn current_visibility(&self) -> Visibility {
+        self.current_visibility
+    }
+
+    fn set_current_visibility(&mut self, current_visibility: Visibility) {
+        self.current_visibility = current_visibility;
     }
 
     fn source_code(&self) -> &String {
@@ -82,9 +233,9 @@ impl DeclarationData {
 
 
 pub fn get_repository_method_dto(source_code: String, tree: Tree,
-                                 path: String, rep_id: i32) -> Vec<RepositoryMethodDto> {
+                                 path: String, rep_id: i32, options: &ParseOptions) -> Vec<RepositoryMethodDto> {
 
-    let mut declaration_data = DeclarationData::new(source_code, path.clone());
+    let mut declaration_data = DeclarationData::new(source_code, path.clone(), options.clone());
     let package = PackageObject::new(path.clone());
     let mut cpp_object: Box<dyn CppObject> = Box::new(package);
 
@@ -115,10 +266,19 @@ fn add_top_level_item(node: Node, declaration_data: &mut DeclarationData, parent
         NodeKinds::FUNCTION_DEFINITION => add_function_definition(node, declaration_data, parent),
         NodeKinds::DECLARATION => add_declaration(node, declaration_data, parent),
         NodeKinds::LINKAGE_SPECIFICATION => add_linkage_specification(node, declaration_data, parent),
-        NodeKinds::PREPROC_IF => add_preproc_if(node, declaration_data, parent),
-        NodeKinds::PREPROC_IFDEF => add_preproc_ifdef(node, declaration_data, parent),
+        NodeKinds::PREPROC_IF | NodeKinds::PREPROC_IFDEF
+        => add_preproc_conditional(node, declaration_data, parent, add_top_level_item),
+        /* `#define` and `#undef` of the file change configured macros */
+        NodeKinds::PREPROC_DEF | NodeKinds::PREPROC_CALL => declaration_data.update_defined_macros(&node),
         NodeKinds::PREPROC_FUNCTION_DEF => add_preproc_func_def(node, declaration_data, parent),
         NodeKinds::TEMPLATE_DECLARATION => add_template_declaration(node, declaration_data, parent),
+        NodeKinds::TYPE_DEFINITION => add_type_definition(node, declaration_data, parent),
+        NodeKinds::ALIAS_DECLARATION => add_alias_declaration(node, declaration_data, parent),
+
+        /* The grammar parses namespace alias as error node */
+        NodeKinds::ERROR | NodeKinds::NAMESPACE_ALIAS_DEFINITION
+        if get_namespace_alias(&node, declaration_data.source_code()).is_some()
+        => add_namespace_alias(node, declaration_data, parent),
 
         /* Found error node - trying to parse it's children */
         NodeKinds::ERROR => parse_top_level_node(node, declaration_data, parent),
@@ -133,14 +293,19 @@ fn add_name_space_definition(node: Node, declaration_data: &mut DeclarationData,
     let namespace_node = unwrap_or_return!(node.child_by_field_name(NodeNames::NAME));
     let namespace_name = unwrap_or_empty_string!(get_node_value(&namespace_node, declaration_data));
     let line_number = get_line_number(&namespace_node);
-    let class_object = ClassObject::new_class(namespace_name, line_number);
+    let mut class_object = ClassObject::new_class(namespace_name.clone(), line_number);
+    class_object.set_documentation(get_documentation_from_node(&node, declaration_data));
+    set_deprecation_from_node(&mut class_object, &node, declaration_data);
     let mut cpp_object: Box<dyn CppObject> = Box::new(class_object);
+    declaration_data.push_scope(namespace_name);
     add_declaration_list(decl_list_node, declaration_data, &mut cpp_object);
+    declaration_data.pop_scope();
     parent.add_child(cpp_object);
 }
 
 fn add_struct_spec(node: Node, declaration_data: &mut DeclarationData, parent: &mut Box<dyn CppObject>) {
 
+    let template_parameters = declaration_data.take_template_parameters();
     let name_opt = node.child_by_field_name(NodeNames::NAME);
     if name_opt.is_none() {
         /* Anonymous structure */
@@ -152,26 +317,57 @@ fn add_struct_spec(node: Node, declaration_data: &mut DeclarationData, parent: &
     /* Class declaration */
     let name_node = name_opt.unwrap();
     let name = unwrap_or_empty_string!(get_name_from_class_name_node(&name_node, declaration_data));
+    let template_arguments = get_template_arguments(name_node, declaration_data);
+    let specialized_name = get_specialized_name(&name, &template_arguments);
     let line_number = get_line_number(&name_node);
     let mut class_object = ClassObject::new_class(name.clone(), line_number);
+    class_object.set_template_parameters(template_parameters);
+    class_object.set_template_arguments(template_arguments);
+    class_object.set_documentation(get_documentation_from_node(&node, declaration_data));
+    class_object.set_visibility(declaration_data.current_visibility());
+    set_deprecation_from_node(&mut class_object, &node, declaration_data);
+    let deprecated = class_object.deprecated();
+    let deprecation_message = class_object.deprecation_message().clone();
     let mut cpp_class_object: Box<dyn CppObject>;
 
     if let Some(field_declaration_list) = node.child_by_field_name(NodeNames::BODY) {
         class_object.set_object_type(Definition);
         cpp_class_object = Box::new(class_object);
+
+        /* Members of class are private by default, members of struct and union are public */
+        let outer_visibility = declaration_data.current_visibility();
+        declaration_data.set_current_visibility(match node.kind() {
+            NodeKinds::CLASS_SPECIFIER => Visibility::Private,
+            _ => Visibility::Public
+        });
+        let outer_has_pure_methods = declaration_data.has_pure_methods();
+        declaration_data.set_has_pure_methods(false);
+        declaration_data.push_scope(specialized_name);
         add_field_declaration_list(field_declaration_list, declaration_data, &mut cpp_class_object);
+        declaration_data.pop_scope();
+        declaration_data.set_current_visibility(outer_visibility);
+
+        /* Class with pure virtual method can't be instantiated */
+        if declaration_data.has_pure_methods() {
+            cpp_class_object.set_modifiers(vec![KeyWords::ABSTRACT.to_string()]);
+        }
+        declaration_data.set_has_pure_methods(outer_has_pure_methods);
     } else {
         class_object.set_object_type(Declaration);
         cpp_class_object = Box::new(class_object);
     }
 
     /* Zero args constructor declaration */
-    let mut constructor_object = MethodObject::default();
-    constructor_object.set_line_code(line_number );
-    constructor_object.set_type_code(CodeType::CPP_CONSTRUCTOR);
-    constructor_object.set_name(name);
-    let cpp_constructor_object:Box<dyn CppObject> = Box::new(constructor_object);
-    cpp_class_object.add_child(cpp_constructor_object);
+    if declaration_data.options().synthetic_constructors() {
+        let mut constructor_object = MethodObject::default();
+        constructor_object.set_line_code(line_number );
+        constructor_object.set_type_code(CodeType::CPP_CONSTRUCTOR);
+        constructor_object.set_name(name);
+        constructor_object.set_deprecated(deprecated);
+        constructor_object.set_deprecation_message(deprecation_message);
+        let cpp_constructor_object:Box<dyn CppObject> = Box::new(constructor_object);
+        cpp_class_object.add_child(cpp_constructor_object);
+    }
 
     parent.add_child(cpp_class_object);
 }
@@ -180,7 +376,123 @@ fn add_enum_specifier(node: Node, declaration_data: &mut DeclarationData, parent
 
     let name_node = unwrap_or_return!(node.child_by_field_name(NodeNames::NAME));
     let name = unwrap_or_empty_string!(get_name_from_class_name_node(&name_node, declaration_data));
-    let class_object = ClassObject::new_enum(name, get_line_number(&node));
+
+    /* `enum class Color` and `enum struct Color` are scoped */
+    let is_scoped = node.children(&mut node.walk())
+        .any(|x| x.kind() == KeyWords::CLASS || x.kind() == KeyWords::STRUCT);
+    let mut class_object = if is_scoped {
+        ClassObject::new_enum_class(name.clone(), get_line_number(&name_node))
+    } else {
+        ClassObject::new_enum(name.clone(), get_line_number(&name_node))
+    };
+    class_object.set_documentation(get_documentation_from_node(&node, declaration_data));
+    class_object.set_visibility(declaration_data.current_visibility());
+    class_object.set_underlying_type(node.child_by_field_name(NodeNames::BASE)
+        .map(|x| get_compact_text(&get_node_text(&x, declaration_data))));
+    set_deprecation_from_node(&mut class_object, &node, declaration_data);
+
+    let mut cpp_object: Box<dyn CppObject> = Box::new(class_object);
+    if let Some(body) = node.child_by_field_name(NodeNames::BODY) {
+        /* Enumerators of plain enum are visible in the enclosing scope */
+        let qualifier = if is_scoped { vec![name] } else { vec![] };
+        add_enumerator_list(body, &qualifier, declaration_data, &mut cpp_object);
+    }
+    parent.add_child(cpp_object);
+}
+
+fn add_enumerator_list(node: Node, qualifier: &Vec<String>, declaration_data: &mut DeclarationData,
+                       parent: &mut Box<dyn CppObject>) {
+
+    for enumerator in node.named_children(&mut node.walk()) {
+        if enumerator.kind() != NodeKinds::ENUMERATOR {
+            continue;
+        }
+        let name_node = match enumerator.child_by_field_name(NodeNames::NAME) {
+            Some(name_node) => name_node,
+            None => continue
+        };
+        let name = unwrap_or_empty_string!(get_node_value(&name_node, declaration_data));
+        let value_expression = enumerator.child_by_field_name(NodeNames::VALUE)
+            .map(|x| get_compact_text(&get_node_text(&x, declaration_data)));
+
+        let mut method_object = MethodObject::new_enumerator(name.clone(), value_expression, get_line_number(&name_node));
+        method_object.set_documentation(get_documentation_from_node(&enumerator, declaration_data));
+        method_object.set_qualified_name(get_qualified_name(qualifier, &name, declaration_data));
+        set_deprecation_from_node(&mut method_object, &enumerator, declaration_data);
+
+        let cpp_object: Box<dyn CppObject> = Box::new(method_object);
+        parent.add_child(cpp_object);
+    }
+}
+
+fn add_namespace_alias(node: Node, declaration_data: &mut DeclarationData, parent: &mut Box<dyn CppObject>) {
+
+    let (alias, namespace) = unwrap_or_return!(get_namespace_alias(&node, declaration_data.source_code()));
+    /* `namespace fs = std::filesystem; namespace sfs = fs;` */
+    let namespace = resolve_namespace(&namespace, declaration_data.namespace_aliases());
+    let mut class_object = ClassObject::new_namespace_alias(alias.clone(), namespace.clone(), get_line_number(&node));
+    class_object.set_documentation(get_documentation_from_node(&node, declaration_data));
+    let cpp_object: Box<dyn CppObject> = Box::new(class_object);
+    parent.add_child(cpp_object);
+    declaration_data.add_namespace_alias(alias, namespace);
+}
+
+/* Type aliases */
+fn add_type_definition(node: Node, declaration_data: &mut DeclarationData, parent: &mut Box<dyn CppObject>) {
+
+    let type_node = unwrap_or_return!(node.child_by_field_name(NodeNames::TYPE));
+
+    /* `typedef struct Node {...} Node_t;` defines the structure as well */
+    let is_struct_definition = match type_node.kind() {
+        NodeKinds::CLASS_SPECIFIER | NodeKinds::STRUCT_SPECIFIER | NodeKinds::UNION_SPECIFIER
+        => type_node.child_by_field_name(NodeNames::NAME).is_some() && type_node.child_by_field_name(NodeNames::BODY).is_some(),
+        _ => false
+    };
+    if is_struct_definition {
+        add_struct_spec(type_node, declaration_data, parent);
+    }
+
+    let type_name = get_aliased_type_name(type_node, declaration_data);
+    if type_name.is_empty() {
+        return;
+    }
+
+    /* `typedef Shape *ShapePtr, ShapeRef;` declares several aliases */
+    for declarator in node.children_by_field_name(NodeNames::DECLARATOR, &mut node.walk()) {
+        let name_node = match find_descendant_by_kind(declarator, NodeKinds::TYPE_IDENTIFIER) {
+            Some(name_node) => name_node,
+            None => continue
+        };
+        let name = unwrap_or_empty_string!(get_node_value(&name_node, declaration_data));
+        let declarator_text = get_node_text(&declarator, declaration_data).replacen(&name, KeyWords::EMPTY_STRING, 1);
+        let aliased_type = format!("{}{}", type_name, get_compact_text(&declarator_text));
+        add_type_alias(&node, name, aliased_type, None, declaration_data, parent);
+    }
+}
+
+fn add_alias_declaration(node: Node, declaration_data: &mut DeclarationData, parent: &mut Box<dyn CppObject>) {
+
+    let template_parameters = declaration_data.take_template_parameters();
+    let name_node = unwrap_or_return!(node.child_by_field_name(NodeNames::NAME));
+    let type_node = unwrap_or_return!(node.child_by_field_name(NodeNames::TYPE));
+    let name = unwrap_or_empty_string!(get_node_value(&name_node, declaration_data));
+    let aliased_type = get_compact_text(&get_node_text(&type_node, declaration_data));
+    add_type_alias(&node, name, aliased_type, template_parameters, declaration_data, parent);
+}
+
+fn add_type_alias(node: &Node, name: String, aliased_type: String, template_parameters: Option<Vec<String>>,
+                  declaration_data: &mut DeclarationData, parent: &mut Box<dyn CppObject>) {
+
+    if name.is_empty() {
+        return;
+    }
+
+    let aliased_type = expand_namespace_aliases(&aliased_type, declaration_data.namespace_aliases());
+    let mut class_object = ClassObject::new_type_alias(name, aliased_type, get_line_number(node));
+    class_object.set_template_parameters(template_parameters);
+    class_object.set_documentation(get_documentation_from_node(node, declaration_data));
+    class_object.set_visibility(declaration_data.current_visibility());
+    set_deprecation_from_node(&mut class_object, node, declaration_data);
     let cpp_object: Box<dyn CppObject> = Box::new(class_object);
     parent.add_child(cpp_object);
 }
@@ -188,22 +500,47 @@ fn add_enum_specifier(node: Node, declaration_data: &mut DeclarationData, parent
 fn add_field_declaration_list(node: Node, declaration_data: &mut DeclarationData, parent: &mut Box<dyn CppObject>) {
 
     for child in node.named_children(&mut node.walk()) {
-        match child.kind() {
-            NodeKinds::FIELD_DECLARATION | NodeKinds::DECLARATION
-            => add_declaration(child, declaration_data, parent),
-            NodeKinds::FUNCTION_DEFINITION => add_function_definition(child, declaration_data, parent),
-            NodeKinds::FRIEND_DECLARATION => add_friend_declaration(child, declaration_data, parent),
-            NodeKinds::PREPROC_IFDEF => add_preproc_ifdef(child, declaration_data, parent),
-            NodeKinds::PREPROC_IF => add_preproc_if(child, declaration_data, parent),
-            NodeKinds::PREPROC_FUNCTION_DEF => add_preproc_func_def(child, declaration_data, parent),
-            NodeKinds::TEMPLATE_DECLARATION => add_template_declaration(child, declaration_data, parent),
-            &_ => {}
-        }
+        add_field_declaration_list_item(child, declaration_data, parent);
+    }
+}
+
+fn add_field_declaration_list_item(node: Node, declaration_data: &mut DeclarationData, parent: &mut Box<dyn CppObject>) {
+
+    match node.kind() {
+        NodeKinds::FIELD_DECLARATION | NodeKinds::DECLARATION
+        => add_declaration(node, declaration_data, parent),
+        NodeKinds::FUNCTION_DEFINITION => add_function_definition(node, declaration_data, parent),
+        NodeKinds::FRIEND_DECLARATION => add_friend_declaration(node, declaration_data, parent),
+        NodeKinds::PREPROC_IF | NodeKinds::PREPROC_IFDEF
+        => add_preproc_conditional(node, declaration_data, parent, add_field_declaration_list_item),
+        /* `#define` and `#undef` of the file change configured macros */
+        NodeKinds::PREPROC_DEF | NodeKinds::PREPROC_CALL => declaration_data.update_defined_macros(&node),
+        NodeKinds::PREPROC_FUNCTION_DEF => add_preproc_func_def(node, declaration_data, parent),
+        NodeKinds::TEMPLATE_DECLARATION => add_template_declaration(node, declaration_data, parent),
+        NodeKinds::ACCESS_SPECIFIER => add_access_specifier(node, declaration_data),
+        NodeKinds::TYPE_DEFINITION => add_type_definition(node, declaration_data, parent),
+        NodeKinds::ALIAS_DECLARATION => add_alias_declaration(node, declaration_data, parent),
+        &_ => {}
+    }
+}
+
+fn add_access_specifier(node: Node, declaration_data: &mut DeclarationData) {
+
+    let access = unwrap_or_empty_string!(get_node_value(&node, declaration_data));
+    match access.trim_end_matches(':').trim() {
+        KeyWords::PUBLIC => declaration_data.set_current_visibility(Visibility::Public),
+        KeyWords::PROTECTED => declaration_data.set_current_visibility(Visibility::Protected),
+        KeyWords::PRIVATE => declaration_data.set_current_visibility(Visibility::Private),
+        &_ => {}
     }
 }
 
 fn add_friend_declaration(node: Node, declaration_data: &mut DeclarationData, parent: &mut Box<dyn CppObject>) {
 
+    /* Friend functions are not members, access specifiers are not applied to them */
+    let outer_visibility = declaration_data.current_visibility();
+    declaration_data.set_current_visibility(Visibility::Public);
+
     for child in node.named_children(&mut node.walk()) {
         match child.kind() {
             NodeKinds::FUNCTION_DEFINITION => add_function_definition(child, declaration_data, parent),
@@ -211,6 +548,8 @@ fn add_friend_declaration(node: Node, declaration_data: &mut DeclarationData, pa
             &_ => {}
         }
     }
+
+    declaration_data.set_current_visibility(outer_visibility);
 }
 
 fn add_declaration_list(node: Node, declaration_data: &mut DeclarationData, parent: &mut Box<dyn CppObject>) {
@@ -222,34 +561,186 @@ fn add_declaration_list(node: Node, declaration_data: &mut DeclarationData, pare
 /* Functions */
 fn add_function(node: Node, declaration_data: &mut DeclarationData, parent: &mut Box<dyn CppObject>, method_type: ObjectType) {
 
+    let template_parameters = declaration_data.take_template_parameters();
     let decl = unwrap_or_return!(node.child_by_field_name(NodeNames::DECLARATOR));
-    let function_decl = unwrap_or_return!(get_function_declarator(decl));
-    let identifier_decl = unwrap_or_return!(function_decl.child_by_field_name(NodeNames::DECLARATOR));
+
+    /* Conversion operator `operator bool() const` has no function declarator */
+    if decl.kind() == NodeKinds::OPERATOR_CAST {
+        add_conversion_operator(node, decl, declaration_data, parent, method_type, template_parameters);
+        return;
+    }
+
+    /* Named lambda of namespace scope `auto compare = [](int a, int b) {...};` */
+    if let Some(lambda) = get_lambda_from_declarator(&decl) {
+        let owner = LambdaOwner {
+            namespace: KeyWords::EMPTY_STRING.to_string(),
+            qualified_name: KeyWords::EMPTY_STRING.to_string(),
+            visibility: declaration_data.current_visibility(),
+        };
+        add_lambda(node, decl, lambda, &owner, declaration_data, parent);
+        return;
+    }
+
+    let function_decl = unwrap_or_return!(find_descendant_by_kind(decl, NodeKinds::FUNCTION_DECLARATOR));
+    let mut identifier_decl = unwrap_or_return!(function_decl.child_by_field_name(NodeNames::DECLARATOR));
+
+    /* Explicit specialization `max<int>(...)` */
+    let template_arguments = get_template_arguments(identifier_decl, declaration_data);
+    if identifier_decl.kind() == NodeKinds::TEMPLATE_FUNCTION {
+        identifier_decl = unwrap_or_return!(identifier_decl.child_by_field_name(NodeNames::NAME));
+    }
+
     let name = get_name_from_declarator(identifier_decl, declaration_data);
-    let namespace = get_namespace_from_declarator(identifier_decl, declaration_data);
     if name == KeyWords::EMPTY_STRING { return; }
+    let qualifier = get_qualifier_segments(identifier_decl, &template_parameters, declaration_data);
+    let namespace = qualifier.last().cloned().unwrap_or_default();
+    let qualified_name = get_qualified_name(&qualifier, &get_specialized_name(&name, &template_arguments), declaration_data);
 
     let (output_parameter, type_code) = get_type_and_param_from_node(&node, declaration_data);
+    let type_code = get_code_type_from_declarator(identifier_decl).unwrap_or(type_code);
     let return_type = get_return_type_from_declarator(&decl);
     let params_node = unwrap_or_return!(function_decl.child_by_field_name(NodeNames::PARAMETERS));
     let parameters = get_parameters_from_list_node(params_node, declaration_data);
     let line_code = get_line_number(&node);
 
-    let method_object = MethodObject::new(
+    let mut method_object = MethodObject::new(
         name,
         type_code,
         output_parameter,
         parameters,
-        namespace,
+        namespace.clone(),
         return_type,
         line_code,
         method_type,
     );
+    set_function_data(&mut method_object, &node, &function_decl, declaration_data);
+    method_object.set_qualified_name(qualified_name);
+    method_object.set_template_parameters(template_parameters);
+    method_object.set_template_arguments(template_arguments);
+    if method_object.modifiers().iter().any(|x| x == KeyWords::PURE) {
+        declaration_data.set_has_pure_methods(true);
+    }
+
+    let owner = LambdaOwner {
+        namespace,
+        qualified_name: method_object.qualified_name().clone(),
+        visibility: Visibility::Private,
+    };
+    let mut cpp_object: Box<dyn CppObject> = Box::new(method_object);
+    if let Some(body) = node.child_by_field_name(NodeNames::BODY) {
+        add_local_lambdas(body, &owner, declaration_data, &mut cpp_object);
+    }
+    parent.add_child(cpp_object);
+}
+
+/* Lambdas */
+fn add_local_lambdas(node: Node, owner: &LambdaOwner, declaration_data: &mut DeclarationData,
+                     parent: &mut Box<dyn CppObject>) {
+
+    for child in node.named_children(&mut node.walk()) {
+        if child.kind() == NodeKinds::DECLARATION {
+            for declarator in child.children_by_field_name(NodeNames::DECLARATOR, &mut child.walk()) {
+                if let Some(lambda) = get_lambda_from_declarator(&declarator) {
+                    add_lambda(child, declarator, lambda, owner, declaration_data, parent);
+                }
+            }
+        }
+        /* Nested blocks and bodies of lambdas */
+        add_local_lambdas(child, owner, declaration_data, parent);
+    }
+}
+
+/* Lambda is attributed to the class of enclosing function and qualified by the function name */
+fn add_lambda(node: Node, declarator: Node, lambda: Node, owner: &LambdaOwner,
+              declaration_data: &mut DeclarationData, parent: &mut Box<dyn CppObject>) {
+
+    let name_node = unwrap_or_return!(declarator.child_by_field_name(NodeNames::DECLARATOR));
+    let name = get_name_from_declarator(name_node, declaration_data);
+    if name == KeyWords::EMPTY_STRING { return; }
+
+    /* `[] { ... }` is declared without parameter list */
+    let parameters = match lambda.child_by_field_name(NodeNames::DECLARATOR)
+        .and_then(|x| x.child_by_field_name(NodeNames::PARAMETERS)) {
+        Some(params_node) => get_parameters_from_list_node(params_node, declaration_data),
+        None => vec![]
+    };
+    let qualified_name = if owner.qualified_name.is_empty() {
+        get_qualified_name(&[], &name, declaration_data)
+    } else {
+        format!("{}{}{}", owner.qualified_name, KeyWords::SCOPE_SEPARATOR, name)
+    };
+
+    let mut method_object = MethodObject::new(
+        name,
+        CPP_LAMBDA,
+        KeyWords::EMPTY_STRING.to_string(),
+        parameters,
+        owner.namespace.clone(),
+        Value,
+        get_line_number(&node),
+        Definition,
+    );
+    method_object.set_documentation(get_documentation_from_node(&node, declaration_data));
+    method_object.set_body(get_body_from_node(&lambda, declaration_data));
+    method_object.set_visibility(owner.visibility);
+    method_object.set_qualified_name(qualified_name);
+    set_deprecation_from_node(&mut method_object, &node, declaration_data);
 
     let cpp_object: Box<dyn CppObject> = Box::new(method_object);
     parent.add_child(cpp_object);
 }
 
+fn get_lambda_from_declarator<'a>(declarator: &Node<'a>) -> Option<Node<'a>> {
+    if declarator.kind() != NodeKinds::INIT_DECLARATOR {
+        return None;
+    }
+    declarator.child_by_field_name(NodeNames::VALUE).filter(|x| x.kind() == NodeKinds::LAMBDA_EXPRESSION)
+}
+
+fn add_conversion_operator(node: Node, operator_cast: Node, declaration_data: &mut DeclarationData,
+                           parent: &mut Box<dyn CppObject>, method_type: ObjectType,
+                           template_parameters: Option<Vec<String>>) {
+
+    let type_node = unwrap_or_return!(operator_cast.child_by_field_name(NodeNames::TYPE));
+    let function_decl = unwrap_or_return!(get_abstract_function_declarator(operator_cast));
+    let params_node = unwrap_or_return!(function_decl.child_by_field_name(NodeNames::PARAMETERS));
+    let operator_node = unwrap_or_return!(get_child_node_by_kind(&operator_cast, KeyWords::OPERATOR));
+
+    /* Name is the text between `operator` keyword and parameters, e.g. `operator const char*` */
+    let name_text = &declaration_data.source_code()[operator_node.start_byte()..params_node.start_byte()];
+    let name = name_text.split_whitespace().collect::<Vec<&str>>().join(" ");
+    let qualifier = get_qualifier_segments(operator_cast, &template_parameters, declaration_data);
+    let namespace = qualifier.last().cloned().unwrap_or_default();
+    let qualified_name = get_qualified_name(&qualifier, &name, declaration_data);
+
+    let mut method_object = MethodObject::new(
+        name,
+        CPP_CONVERSION_OPERATOR,
+        get_output_param_from_type_specifier(type_node, declaration_data),
+        get_parameters_from_list_node(params_node, declaration_data),
+        namespace,
+        get_return_type_from_declarator(&function_decl),
+        get_line_number(&node),
+        method_type,
+    );
+    set_function_data(&mut method_object, &node, &function_decl, declaration_data);
+    method_object.set_qualified_name(qualified_name);
+    method_object.set_template_parameters(template_parameters);
+
+    let cpp_object: Box<dyn CppObject> = Box::new(method_object);
+    parent.add_child(cpp_object);
+}
+
+fn set_function_data(method_object: &mut MethodObject, node: &Node, function_decl: &Node,
+                     declaration_data: &mut DeclarationData) {
+
+    method_object.set_documentation(get_documentation_from_node(node, declaration_data));
+    method_object.set_body(get_body_from_node(node, declaration_data));
+    method_object.set_modifiers(get_function_modifiers(node, function_decl, declaration_data));
+    method_object.set_visibility(get_function_visibility(node, declaration_data));
+    set_deprecation_from_node(method_object, node, declaration_data);
+}
+
 fn add_function_definition(node: Node, declaration_data: &mut DeclarationData, parent: &mut Box<dyn CppObject>) {
     add_function(node, declaration_data, parent, Definition);
 }
@@ -261,7 +752,14 @@ fn add_declaration(node: Node, declaration_data: &mut DeclarationData, parent: &
 /* Templates */
 fn add_template_declaration(node: Node, declaration_data: &mut DeclarationData, parent: &mut Box<dyn CppObject>) {
 
+    /* Member template of class template `template<class T> template<class U>` has parameters of both */
+    let mut template_parameters = declaration_data.take_template_parameters().unwrap_or_default();
+    if let Some(parameter_list) = node.child_by_field_name(NodeNames::PARAMETERS) {
+        template_parameters.append(&mut get_template_parameters(parameter_list, declaration_data));
+    }
+
     for child in node.named_children(&mut node.walk()) {
+        declaration_data.set_template_parameters(Some(template_parameters.clone()));
         match child.kind() {
             /* From _empty_declaration node */
             NodeKinds::ENUM_SPECIFIER => add_enum_specifier(child, declaration_data, parent),
@@ -272,9 +770,11 @@ fn add_template_declaration(node: Node, declaration_data: &mut DeclarationData,
             NodeKinds::TEMPLATE_DECLARATION => add_template_declaration(child, declaration_data, parent),
             NodeKinds::FUNCTION_DEFINITION => add_function_definition(child, declaration_data, parent),
             NodeKinds::DECLARATION => add_declaration(child, declaration_data, parent),
+            NodeKinds::ALIAS_DECLARATION => add_alias_declaration(child, declaration_data, parent),
             &_ => {}
         }
     }
+    declaration_data.set_template_parameters(None);
 }
 
 /* Linkage (ffi functions) */
@@ -295,7 +795,7 @@ fn add_preproc_func_def(node: Node, declaration_data: &mut DeclarationData, pare
     let name_node = unwrap_or_return!(node.child_by_field_name(NodeNames::NAME));
     let preproc_parameters = unwrap_or_return!(node.child_by_field_name(NodeNames::PARAMETERS));
 
-    let method_object = MethodObject::new(
+    let mut method_object = MethodObject::new(
         unwrap_or_empty_string!(get_node_value(&name_node, declaration_data)),
         CPP_METHOD,
         KeyWords::EMPTY_STRING.to_string(),
@@ -305,21 +805,119 @@ fn add_preproc_func_def(node: Node, declaration_data: &mut DeclarationData, pare
         get_line_number(&node) ,
         Definition,
     );
+    method_object.set_documentation(get_documentation_from_node(&node, declaration_data));
+    method_object.set_body(get_body_from_node(&node, declaration_data));
+    set_deprecation_from_node(&mut method_object, &node, declaration_data);
 
     let cpp_object: Box<dyn CppObject> = Box::new(method_object);
     parent.add_child(cpp_object);
 }
 
-fn add_preproc_if(node: Node, declaration_data: &mut DeclarationData, parent: &mut Box<dyn CppObject>) {
-    for child in node.named_children(&mut node.walk()) {
-        add_top_level_item(child, declaration_data, parent);
+/* `#if`, `#ifdef` and `#ifndef` with chain of `#elif` and `#else` branches. Items of branch are added by add_item */
+fn add_preproc_conditional(node: Node, declaration_data: &mut DeclarationData, parent: &mut Box<dyn CppObject>,
+                           add_item: fn(Node, &mut DeclarationData, &mut Box<dyn CppObject>)) {
+
+    let mut previous_conditions: Vec<String> = vec![];
+    /* Some earlier branch is active, None if it is unknown */
+    let mut is_taken = Some(false);
+    let mut branch_opt = Some(node);
+
+    while let Some(branch) = branch_opt {
+
+        let condition = get_preproc_condition(&branch, declaration_data);
+        let value = evaluate_preproc_branch(&branch, declaration_data.source_code(),
+                                            declaration_data.defined_macros().as_ref());
+        let (is_active, is_branch_taken) = get_branch_activity(is_taken, value);
+        is_taken = is_branch_taken;
+
+        let is_skipped = is_active == Some(false) && declaration_data.options().preprocessor().skip_inactive_branches();
+        if !is_skipped {
+            let mut branch_conditions: Vec<String> = previous_conditions.iter()
+                .map(negate_preproc_condition)
+                .collect();
+            branch_conditions.extend(condition.clone());
+            declaration_data.push_preprocessor_condition(join_preproc_conditions(&branch_conditions));
+            add_preproc_branch_items(branch, declaration_data, parent, add_item);
+            declaration_data.pop_preprocessor_condition();
+        }
+
+        previous_conditions.extend(condition);
+        branch_opt = branch.child_by_field_name(NodeNames::ALTERNATIVE);
     }
 }
 
-fn add_preproc_ifdef(node: Node, declaration_data: &mut DeclarationData, parent: &mut Box<dyn CppObject>) {
-    for child in node.named_children(&mut node.walk()) {
-        add_top_level_item(child, declaration_data, parent);
+fn add_preproc_branch_items(branch: Node, declaration_data: &mut DeclarationData, parent: &mut Box<dyn CppObject>,
+                            add_item: fn(Node, &mut DeclarationData, &mut Box<dyn CppObject>)) {
+
+    let first_child = parent.mut_children().len();
+
+    for child in branch.named_children(&mut branch.walk()) {
+        if !is_preproc_branch_header(&branch, &child) {
+            add_item(child, declaration_data, parent);
+        }
+    }
+
+    /* Declarations of nested branches already carry their own, more specific condition */
+    let condition = declaration_data.preprocessor_condition();
+    for child in parent.mut_children()[first_child..].iter_mut() {
+        set_preprocessor_condition(child, &condition);
+    }
+}
+
+fn set_preprocessor_condition(cpp_object: &mut Box<dyn CppObject>, condition: &String) {
+
+    if cpp_object.preprocessor_condition().is_none() {
+        cpp_object.set_preprocessor_condition(Some(condition.clone()));
+    }
+    for child in cpp_object.mut_children().iter_mut() {
+        set_preprocessor_condition(child, condition);
+    }
+}
+
+
+/* Preprocessor conditions */
+fn get_preproc_condition(branch: &Node, declaration_data: &DeclarationData) -> Option<String> {
+
+    match branch.kind() {
+        NodeKinds::PREPROC_IFDEF => {
+            let name_node = branch.child_by_field_name(NodeNames::NAME)?;
+            let name = get_node_text(&name_node, declaration_data);
+            let directive = get_node_text(&branch.child(0)?, declaration_data);
+            Some(if directive == KeyWords::IFNDEF { format!("!defined({})", name) } else { format!("defined({})", name) })
+        }
+        NodeKinds::PREPROC_IF | NodeKinds::PREPROC_ELIF => {
+            let condition_node = branch.child_by_field_name(NodeNames::CONDITION)?;
+            Some(get_compact_text(&get_node_text(&condition_node, declaration_data)))
+        }
+        _ => None
+    }
+}
+
+fn negate_preproc_condition(condition: &String) -> String {
+
+    let (is_negated, operand) = match condition.strip_prefix('!') {
+        Some(operand) => (true, operand),
+        None => (false, condition.as_str())
+    };
+    let is_simple = operand.chars().all(|c| c.is_alphanumeric() || c == '_')
+        || (operand.starts_with("defined(") && operand.ends_with(')') && !operand.contains(' '));
+
+    match (is_simple, is_negated) {
+        (true, true) => operand.to_string(),
+        (true, false) => format!("!{}", operand),
+        _ => format!("!({})", condition)
+    }
+}
+
+fn join_preproc_conditions(conditions: &[String]) -> String {
+
+    if conditions.len() == 1 {
+        return conditions[0].clone();
     }
+    conditions.iter()
+        .map(|x| if x.contains("||") { format!("({})", x) } else { x.clone() })
+        .collect::<Vec<String>>()
+        .join(KeyWords::CONDITION_SEPARATOR)
 }
 
 /* Data conversation */
@@ -328,16 +926,21 @@ fn prepare_output_data(dto_vec: &mut Vec<RepositoryMethodDto>, cpp_object: Box<d
 
     match cpp_object.type_code() {
         CPP_PACKAGE => prepare_package_object(dto_vec, cpp_object, rep_id, path, class_name),
-        CPP_CLASS | CPP_ENUM => prepare_class_object(dto_vec, cpp_object, rep_id, path, package_name),
-        CPP_METHOD | CPP_CONSTRUCTOR
-        => prepare_method_object(dto_vec, cpp_object, rep_id, path, package_name, class_name),
+        CPP_CLASS | CPP_ENUM | CPP_ENUM_CLASS | CPP_TYPE_ALIAS | CPP_NAMESPACE_ALIAS
+        => prepare_class_object(dto_vec, cpp_object, rep_id, path, package_name),
+        CPP_METHOD | CPP_CONSTRUCTOR | CPP_DESTRUCTOR | CPP_OPERATOR | CPP_CONVERSION_OPERATOR | CPP_LAMBDA
+        | CPP_ENUMERATOR => prepare_method_object(dto_vec, cpp_object, rep_id, path, package_name, class_name),
         &_ => {}
     }
 }
 
-fn prepare_package_object(dto_vec: &mut Vec<RepositoryMethodDto>, cpp_object: Box<dyn CppObject>,
+fn prepare_package_object(dto_vec: &mut Vec<RepositoryMethodDto>, mut cpp_object: Box<dyn CppObject>,
                           rep_id: i32, path: &String, class_name: &String) {
 
+    let visibility = cpp_object.visibility();
+    let documentation = cpp_object.take_documentation();
+    let deprecated = cpp_object.deprecated();
+    let deprecation_message = cpp_object.take_deprecation_message();
     let boxed_any = cpp_object.to_any();
     let boxed_package = boxed_any.downcast::<PackageObject>().unwrap();
     let package_object = *boxed_package;
@@ -356,8 +959,13 @@ fn prepare_package_object(dto_vec: &mut Vec<RepositoryMethodDto>, cpp_object: Bo
         .path_file(path.clone())
         .package_name(name.clone())
         .line_code(line_code)
-        .modifiers(serde_json::to_string(&modifiers).unwrap_or(KeyWords::EMPTY_STRING.to_string()))
+        .modifiers(Modifier::from_keywords(&modifiers))
+        .legacy_modifiers(get_legacy_modifiers())
         .method_type(type_code.to_string())
+        .visibility(visibility)
+        .documentation(documentation)
+        .deprecated(deprecated)
+        .deprecation_message(deprecation_message)
         .build();
 
 
@@ -368,9 +976,18 @@ fn prepare_package_object(dto_vec: &mut Vec<RepositoryMethodDto>, cpp_object: Bo
     }
 }
 
-fn prepare_class_object(dto_vec: &mut Vec<RepositoryMethodDto>, cpp_object: Box<dyn CppObject>,
+fn prepare_class_object(dto_vec: &mut Vec<RepositoryMethodDto>, mut cpp_object: Box<dyn CppObject>,
                         rep_id: i32, path: &String, package_name: &String) {
 
+    let visibility = cpp_object.visibility();
+    let documentation = cpp_object.take_documentation();
+    let deprecated = cpp_object.deprecated();
+    let deprecation_message = cpp_object.take_deprecation_message();
+    let template_parameters = cpp_object.take_template_parameters();
+    let template_arguments = cpp_object.take_template_arguments();
+    let aliased_type = cpp_object.take_aliased_type();
+    let underlying_type = cpp_object.take_underlying_type();
+    let preprocessor_condition = cpp_object.take_preprocessor_condition();
     let boxed_any = cpp_object.to_any();
     let boxed_class = boxed_any.downcast::<ClassObject>().unwrap();
     let class_object = *boxed_class;
@@ -384,26 +1001,51 @@ fn prepare_class_object(dto_vec: &mut Vec<RepositoryMethodDto>, cpp_object: Box<
         _class_type
     ) = class_object.take();
 
+    /* Specialization `Foo<int>` is a distinct class linked to primary template `Foo` */
+    let specialized_name = get_specialized_name(&name, &template_arguments);
+    let primary_template = template_arguments.as_ref().map(|_| name);
+
     let method_dto = RepositoryMethodDtoBuilder::default()
         .repository_id(rep_id)
         .path_file(path.clone())
         .package_name(package_name.clone())
         .line_code(line_code)
-        .class_name(name.clone())
-        .modifiers(convert_modifiers_vec_to_string(&modifiers))
+        .class_name(specialized_name.clone())
+        .modifiers(Modifier::from_keywords(&modifiers))
+        .legacy_modifiers(get_legacy_modifiers())
         .method_type(type_code.to_string())
+        .visibility(visibility)
+        .documentation(documentation)
+        .deprecated(deprecated)
+        .deprecation_message(deprecation_message)
+        .template_parameters(template_parameters)
+        .template_arguments(template_arguments)
+        .primary_template(primary_template)
+        .aliased_type(aliased_type)
+        .underlying_type(underlying_type)
+        .preprocessor_condition(preprocessor_condition)
         .build();
 
     dto_vec.push(method_dto);
 
     for child in children {
-        prepare_output_data(dto_vec, child, rep_id, path, package_name, &name);
+        prepare_output_data(dto_vec, child, rep_id, path, package_name, &specialized_name);
     }
 }
 
-fn prepare_method_object(dto_vec: &mut Vec<RepositoryMethodDto>, cpp_object: Box<dyn CppObject>,
+fn prepare_method_object(dto_vec: &mut Vec<RepositoryMethodDto>, mut cpp_object: Box<dyn CppObject>,
                          rep_id: i32, path: &String, package_name: &String, class_name: &String) {
 
+    let visibility = cpp_object.visibility();
+    let documentation = cpp_object.take_documentation();
+    let deprecated = cpp_object.deprecated();
+    let deprecation_message = cpp_object.take_deprecation_message();
+    let body = cpp_object.take_body();
+    let qualified_name = cpp_object.take_qualified_name();
+    let template_parameters = cpp_object.take_template_parameters();
+    let template_arguments = cpp_object.take_template_arguments();
+    let preprocessor_condition = cpp_object.take_preprocessor_condition();
+    let value_expression = cpp_object.take_value_expression();
     let boxed_any = cpp_object.to_any();
     let boxed_method = boxed_any.downcast::<MethodObject>().unwrap();
     let method_object = *boxed_method;
@@ -418,19 +1060,41 @@ fn prepare_method_object(dto_vec: &mut Vec<RepositoryMethodDto>, cpp_object: Box
         namespace,
         _output_parameter,
         _return_type,
-        _method_type
+        method_type
     ) = method_object.take();
 
+    /* Out-of-line definition `Foo::bar() {}` belongs to class from its qualifier */
+    let owner_name = if namespace == KeyWords::EMPTY_STRING { class_name.clone() } else { namespace };
+    let specialized_name = get_specialized_name(&name, &template_arguments);
+    let primary_template = template_arguments.as_ref().map(|_| name);
+    let declaration_kind = match method_type {
+        Declaration => DeclarationKind::Declaration,
+        Definition => DeclarationKind::Definition,
+    };
+
     let method_dto = RepositoryMethodDtoBuilder::default()
         .repository_id(rep_id)
         .path_file(path.clone())
         .package_name(package_name.clone())
         .line_code(line_code)
-        .class_name(if class_name == KeyWords::EMPTY_STRING { namespace } else { class_name.clone()})
-        .method_name(name)
-        .modifiers(convert_modifiers_vec_to_string(&modifiers))
+        .class_name(owner_name)
+        .method_name(specialized_name)
+        .blob_data(body.unwrap_or_default())
+        .modifiers(Modifier::from_keywords(&modifiers))
+        .legacy_modifiers(get_legacy_modifiers())
         .method_type(type_code.to_string())
         .count_of_parameters(parameters.len())
+        .visibility(visibility)
+        .documentation(documentation)
+        .deprecated(deprecated)
+        .deprecation_message(deprecation_message)
+        .qualified_name(if qualified_name.is_empty() { None } else { Some(qualified_name) })
+        .declaration_kind(Some(declaration_kind))
+        .template_parameters(template_parameters)
+        .template_arguments(template_arguments)
+        .primary_template(primary_template)
+        .preprocessor_condition(preprocessor_condition)
+        .value_expression(value_expression)
         .build();
 
     dto_vec.push(method_dto);
@@ -442,8 +1106,9 @@ fn prepare_method_object(dto_vec: &mut Vec<RepositoryMethodDto>, cpp_object: Box
 
 
 /* Helpers */
-fn convert_modifiers_vec_to_string(modifiers: &Vec<String>) -> String {
-    serde_json::to_string(modifiers).unwrap_or(KeyWords::EMPTY_STRING.to_string())
+/* Legacy output never listed modifiers of C++ declarations */
+fn get_legacy_modifiers() -> String {
+    serde_json::to_string(&Vec::<String>::new()).unwrap_or(KeyWords::EMPTY_STRING.to_string())
 }
 
 fn get_parameters_from_preproc_node(node: &Node, declaration_data: &mut DeclarationData) -> Vec<String> {
@@ -498,6 +1163,14 @@ fn get_name_from_declarator(node: Node, declaration_data: &mut DeclarationData)
             unwrap_or_empty_string!(get_node_value(&node, declaration_data))
         }
 
+        /* `operator ==` and `operator==` are the same operator, `operator new` keeps its space */
+        NodeKinds::OPERATOR_NAME => {
+            let value = unwrap_or_empty_string!(get_node_value(&node, declaration_data));
+            let symbol = value.trim_start_matches(KeyWords::OPERATOR).split_whitespace().collect::<String>();
+            let separator = if symbol.starts_with(char::is_alphabetic) { " " } else { KeyWords::EMPTY_STRING };
+            format!("{}{}{}", KeyWords::OPERATOR, separator, symbol)
+        }
+
         NodeKinds::SCOPED_IDENTIFIER => {
             if let Some(name_node) = node.child_by_field_name(NodeNames::NAME) {
                 get_name_from_declarator(name_node, declaration_data)
@@ -510,46 +1183,134 @@ fn get_name_from_declarator(node: Node, declaration_data: &mut DeclarationData)
     }
 }
 
-fn get_namespace_from_declarator(node: Node, declaration_data: &mut DeclarationData) -> String {
+fn get_code_type_from_declarator(node: Node) -> Option<CodeType> {
 
-    let mut namespace_string = KeyWords::EMPTY_STRING.to_string();
-    let namespace_opt = node.child_by_field_name(NodeNames::NAMESPACE);
-    if namespace_opt.is_none() { return namespace_string; }
-    let namespace_node = namespace_opt.unwrap();
+    match node.kind() {
+        NodeKinds::DESTRUCTOR_NAME => Some(CPP_DESTRUCTOR),
+        NodeKinds::OPERATOR_NAME => Some(CPP_OPERATOR),
+        NodeKinds::SCOPED_IDENTIFIER => get_code_type_from_declarator(node.child_by_field_name(NodeNames::NAME)?),
+        _ => None
+    }
+}
 
-    match namespace_node.kind() {
+fn get_abstract_function_declarator(node: Node) -> Option<Node> {
 
-        NodeKinds::NAMESPACE_IDENTIFIER => {
-            namespace_string = unwrap_or_empty_string!(get_node_value(&namespace_node, declaration_data))
-        }
+    let declarator = node.child_by_field_name(NodeNames::DECLARATOR)?;
+    if declarator.kind() == NodeKinds::ABSTRACT_FUNCTION_DECLARATOR {
+        return Some(declarator);
+    }
+    get_abstract_function_declarator(declarator)
+}
 
-        NodeKinds::TEMPLATE_TYPE => {
-            if let Some(name_node) = namespace_node.child_by_field_name(NodeNames::NAME) {
-                match name_node.kind() {
+/* Qualifier chain of out-of-line declarator, e.g. `["ns", "Foo"]` for `ns::Foo::bar` */
+fn get_qualifier_segments(node: Node, template_parameters: &Option<Vec<String>>,
+                          declaration_data: &mut DeclarationData) -> Vec<String> {
 
-                    NodeKinds::TYPE_IDENTIFIER => {
-                        namespace_string = unwrap_or_empty_string!(
-                            get_node_value(&name_node, declaration_data)
-                        )
-                    }
+    let mut segments = vec![];
+    if let Some(namespace_node) = node.child_by_field_name(NodeNames::NAMESPACE) {
+        add_qualifier_segments(namespace_node, template_parameters, &mut segments, declaration_data);
+    }
 
-                    NodeKinds::SCOPED_TYPE_IDENTIFIER => {
-                        namespace_string = unwrap_or_empty_string!(
-                            get_name_from_scoped_type_identifier(&name_node, declaration_data)
-                        )
-                    }
-                    _ => {}
-                }
+    /* `void g::Shape::area()` of `namespace g = geo;` is member of `geo::Shape` */
+    let aliased_namespace = segments.first().and_then(|x| declaration_data.namespace_aliases().get(x)).cloned();
+    if let Some(namespace) = aliased_namespace {
+        segments.splice(0..1, namespace.split(KeyWords::SCOPE_SEPARATOR).map(|x| x.to_string()));
+    }
+    segments
+}
+
+/* Enclosing scope, qualifier chain of the declarator and name, e.g. `ns::Foo::bar` */
+fn get_qualified_name(qualifier: &[String], name: &String, declaration_data: &DeclarationData) -> String {
+    declaration_data.scope().iter()
+        .chain(qualifier.iter())
+        .chain(std::iter::once(name))
+        .cloned()
+        .collect::<Vec<String>>()
+        .join(KeyWords::SCOPE_SEPARATOR)
+}
+
+fn add_qualifier_segments(node: Node, template_parameters: &Option<Vec<String>>,
+                          segments: &mut Vec<String>, declaration_data: &mut DeclarationData) {
+
+    match node.kind() {
+        NodeKinds::NAMESPACE_IDENTIFIER | NodeKinds::TYPE_IDENTIFIER
+        => segments.push(unwrap_or_empty_string!(get_node_value(&node, declaration_data))),
+
+        NodeKinds::SCOPED_NAMESPACE_IDENTIFIER | NodeKinds::SCOPED_TYPE_IDENTIFIER => {
+            if let Some(namespace_node) = node.child_by_field_name(NodeNames::NAMESPACE) {
+                add_qualifier_segments(namespace_node, template_parameters, segments, declaration_data);
+            }
+            if let Some(name_node) = node.child_by_field_name(NodeNames::NAME) {
+                add_qualifier_segments(name_node, template_parameters, segments, declaration_data);
             }
         }
-        NodeKinds::SCOPED_NAMESPACE_IDENTIFIER => {
-            if let Some(name_node) = namespace_node.child_by_field_name(NodeNames::NAME) {
-                namespace_string = unwrap_or_empty_string!(get_node_value(&name_node, declaration_data))
+
+        /* Member of primary template `Box<T>::get` belongs to `Box`, member of specialization to `Box<int>` */
+        NodeKinds::TEMPLATE_TYPE => {
+            let name_node = unwrap_or_return!(node.child_by_field_name(NodeNames::NAME));
+            add_qualifier_segments(name_node, template_parameters, segments, declaration_data);
+            let template_arguments = get_template_arguments(node, declaration_data);
+            if template_arguments.is_some() && &template_arguments != template_parameters {
+                if let Some(name) = segments.pop() {
+                    segments.push(get_specialized_name(&name, &template_arguments));
+                }
             }
         }
         _ => {}
     }
-    return namespace_string;
+}
+
+/* Names of parameters from `template<typename T, int N>` list */
+fn get_template_parameters(node: Node, declaration_data: &mut DeclarationData) -> Vec<String> {
+
+    let mut template_parameters = vec![];
+    for child in node.named_children(&mut node.walk()) {
+        if let Some(name) = get_template_parameter_name(child, declaration_data) {
+            template_parameters.push(name);
+        }
+    }
+    template_parameters
+}
+
+fn get_template_parameter_name(node: Node, declaration_data: &mut DeclarationData) -> Option<String> {
+
+    if let Some(name_node) = node.child_by_field_name(NodeNames::NAME) {
+        return get_node_value(&name_node, declaration_data);
+    }
+    if let Some(declarator) = node.child_by_field_name(NodeNames::DECLARATOR) {
+        let identifier = get_identifier_from_declarator(declarator)?;
+        return get_node_value(&identifier, declaration_data);
+    }
+
+    /* Type parameter `typename T`, variadic `typename... Ts` and template template `template<typename> class C` */
+    let last_child = node.named_children(&mut node.walk()).last()?;
+    match last_child.kind() {
+        NodeKinds::TYPE_IDENTIFIER => get_node_value(&last_child, declaration_data),
+        NodeKinds::TEMPLATE_PARAMETER_LIST => None,
+        &_ => get_template_parameter_name(last_child, declaration_data)
+    }
+}
+
+/* Arguments of specialization `Foo<int, 3>` or `max<int>`, None for other nodes */
+fn get_template_arguments(node: Node, declaration_data: &mut DeclarationData) -> Option<Vec<String>> {
+
+    if node.kind() != NodeKinds::TEMPLATE_TYPE && node.kind() != NodeKinds::TEMPLATE_FUNCTION {
+        return None;
+    }
+
+    let arguments_node = node.child_by_field_name(NodeNames::ARGUMENTS)?;
+    let mut template_arguments = vec![];
+    for child in arguments_node.named_children(&mut arguments_node.walk()) {
+        template_arguments.push(unwrap_or_empty_string!(get_node_value(&child, declaration_data)));
+    }
+    Some(template_arguments)
+}
+
+fn get_specialized_name(name: &String, template_arguments: &Option<Vec<String>>) -> String {
+    match template_arguments {
+        Some(arguments) => format!("{}<{}>", name, arguments.join(", ")),
+        None => name.clone()
+    }
 }
 
 fn get_output_param_from_type_specifier(node: Node, declaration_data: &mut DeclarationData) -> String {
@@ -584,15 +1345,23 @@ fn get_output_param_from_type_specifier(node: Node, declaration_data: &mut Decla
     return if parameter == KeyWords::VOID { KeyWords::EMPTY_STRING.to_string() } else { parameter };
 }
 
-fn get_function_declarator(node: Node) -> Option<Node> {
+fn get_aliased_type_name(node: Node, declaration_data: &mut DeclarationData) -> String {
 
-    if node.kind() == NodeKinds::FUNCTION_DECLARATOR {
-        return Some(node);
-    }
-    for child in node.named_children(&mut node.walk()) {
-        return get_function_declarator(child);
+    match node.kind() {
+        /* Name of structure defined in typedef, keyword `struct` for anonymous one */
+        NodeKinds::CLASS_SPECIFIER | NodeKinds::STRUCT_SPECIFIER | NodeKinds::UNION_SPECIFIER | NodeKinds::ENUM_SPECIFIER
+        => match node.child_by_field_name(NodeNames::NAME).or(node.child(0)) {
+            Some(name_node) => unwrap_or_empty_string!(get_name_from_class_name_node(&name_node, declaration_data)
+                .or_else(|| get_node_value(&name_node, declaration_data))),
+            None => KeyWords::EMPTY_STRING.to_string()
+        },
+        _ => get_compact_text(&get_node_text(&node, declaration_data))
     }
-    return None;
+}
+
+/* Type text with whitespace collapsed, e.g. `unsigned   long` -> `unsigned long` */
+fn get_compact_text(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
 }
 
 fn get_name_from_class_name_node(node: &Node, declaration_data: &mut DeclarationData) -> Option<String> {
@@ -654,13 +1423,118 @@ fn get_node_value(node: &Node, declaration_data: &DeclarationData) -> Option<Str
             declaration_data.path_file())
         );
 
-    if node_string.len() < MAX_TOKEN_LENGTH {
+    if node_string.len() < declaration_data.options().max_token_length() {
         Some(node_string)
     } else {
         None
     }
 }
 
+fn get_node_text(node: &Node, declaration_data: &DeclarationData) -> String {
+
+    let source = declaration_data.source_code();
+    source[node.start_byte()..node.end_byte()].to_string()
+}
+
+fn get_body_from_node(node: &Node, declaration_data: &DeclarationData) -> Option<String> {
+
+    if !declaration_data.options().capture_body() {
+        return None;
+    }
+
+    Some(get_node_text(node, declaration_data))
+}
+
+fn get_documentation_from_node(node: &Node, declaration_data: &DeclarationData) -> Option<Documentation> {
+
+    /* Doc comment also carries deprecation, skip it only when both extractors are disabled */
+    let extractors = declaration_data.options().extractors();
+    if !extractors.documentation() && !extractors.deprecation() {
+        return None;
+    }
+
+    get_doc_comment(node, declaration_data.source_code()).map(|comment| parse_doc_comment(&comment))
+}
+
+fn get_function_modifiers(node: &Node, function_decl: &Node, declaration_data: &DeclarationData) -> Vec<String> {
+
+    let mut modifiers = vec![];
+
+    /* static, inline, extern, virtual, explicit, constexpr before return type */
+    for child in node.named_children(&mut node.walk()) {
+        let is_modifier = match child.kind() {
+            NodeKinds::STORAGE_CLASS_SPECIFIER | NodeKinds::VIRTUAL_FUNCTION_SPECIFIER
+            | NodeKinds::EXPLICIT_FUNCTION_SPECIFIER => true,
+            NodeKinds::TYPE_QUALIFIER => get_node_value(&child, declaration_data).as_deref() == Some(KeyWords::CONSTEXPR),
+            &_ => false
+        };
+        if is_modifier {
+            modifiers.push(unwrap_or_empty_string!(get_node_value(&child, declaration_data)));
+        }
+    }
+
+    /* const, noexcept, override, final after parameter list */
+    for child in function_decl.named_children(&mut function_decl.walk()) {
+        match child.kind() {
+            NodeKinds::TYPE_QUALIFIER | NodeKinds::VIRTUAL_SPECIFIER | NodeKinds::NOEXCEPT
+            => modifiers.push(unwrap_or_empty_string!(get_node_value(&child, declaration_data))),
+            &_ => {}
+        }
+    }
+
+    /* Pure virtual `= 0` */
+    if let Some(default_value) = node.child_by_field_name(NodeNames::DEFAULT_VALUE) {
+        if get_node_value(&default_value, declaration_data).as_deref() == Some(KeyWords::PURE_SPECIFIER) {
+            modifiers.push(KeyWords::PURE.to_string());
+        }
+    }
+
+    modifiers
+}
+
+fn get_function_visibility(node: &Node, declaration_data: &DeclarationData) -> Visibility {
+
+    let mut scope_node = node.parent();
+    while let Some(scope) = scope_node {
+        if scope.kind() != NodeKinds::TEMPLATE_DECLARATION { break; }
+        scope_node = scope.parent();
+    }
+
+    /* Class members take access of their section */
+    if scope_node.map(|scope| scope.kind()) == Some(NodeKinds::FIELD_DECLARATION_LIST) {
+        return declaration_data.current_visibility();
+    }
+
+    /* Static functions outside of classes have internal linkage */
+    let is_static = node
+        .named_children(&mut node.walk())
+        .filter(|x| x.kind() == NodeKinds::STORAGE_CLASS_SPECIFIER)
+        .any(|x| get_node_value(&x, declaration_data).as_deref() == Some(KeyWords::STATIC));
+
+    if is_static { Visibility::Internal } else { declaration_data.current_visibility() }
+}
+
+fn set_deprecation_from_node(cpp_object: &mut dyn CppObject, node: &Node, declaration_data: &DeclarationData) {
+
+    if !declaration_data.options().extractors().deprecation() {
+        return;
+    }
+
+    let attribute_deprecation = get_attribute_deprecation(node, declaration_data.source_code());
+
+    /* Doxygen \deprecated tag */
+    let doc_message = cpp_object
+        .documentation()
+        .as_ref()
+        .and_then(|documentation| documentation.deprecated().clone());
+
+    let deprecated = attribute_deprecation.is_some() || doc_message.is_some();
+    let message = attribute_deprecation.flatten().or(doc_message).filter(|message| !message.is_empty());
+
+    cpp_object.set_deprecated(deprecated);
+    cpp_object.set_deprecation_message(message);
+}
+
 fn get_line_number(node: &Node) -> usize {
     node.start_position().row + 1
 }
@@ -674,6 +1548,21 @@ mod cpp_code_declaration_tests {
     use std::fs;
     use crate::dto::invocation_structure::InvocationStructure;
 
+    fn parse_cpp(code: &str, options: &ParseOptions) -> Vec<RepositoryMethodDto> {
+        let mut parser = Parser::new();
+        parser.set_language(tree_sitter_cpp::language()).expect("ERROR: Unable to load CPP grammar");
+        let tree = parser.parse(code, None).unwrap();
+        get_repository_method_dto(code.to_string(), tree, "test".to_string(), 0, options)
+    }
+
+    fn parse_cpp_json(code: &str) -> Vec<serde_json::Value> {
+        parse_cpp(code, &ParseOptions::default()).iter().map(|x| serde_json::to_value(x).unwrap()).collect()
+    }
+
+    fn find_method<'a>(json: &'a [serde_json::Value], method_name: &str) -> &'a serde_json::Value {
+        json.iter().find(|x| x["methodName"] == method_name).unwrap()
+    }
+
     #[test]
     pub fn test_get_repository_method_dto() {
         let mut code = fs::read_to_string("resources/test_files/cpp/5.cc.txt").unwrap();
@@ -683,11 +1572,441 @@ mod cpp_code_declaration_tests {
         println!("{},  ", tree.root_node().to_sexp());
         let repository_method_dto = get_repository_method_dto(code,
                                                               tree, "test".to_string(),
-                                                              0);
+                                                              0, &ParseOptions::default());
         for dto in repository_method_dto {
             let json = serde_json::to_string_pretty(&dto).unwrap();
             print!("{}", json);
         }
 
     }
-}
\ No newline at end of file
+
+    #[test]
+    pub fn test_get_documentation() {
+        let code = String::from(
+            "/// Vector of numbers.\n\
+             class Vector {\n\
+             public:\n\
+                 /// Returns element at position.\n\
+                 /// \\param index position of the element\n\
+                 int at(int index);\n\
+             };\n\
+             /**\n\
+              * Finds maximum value.\n\
+              * @throws std::out_of_range if container is empty\n\
+              */\n\
+             template <typename T>\n\
+             T max(T a, T b) { return a > b ? a : b; }\n\
+             // Regular comment\n\
+             void helper() {}"
+        );
+        let json = parse_cpp_json(&code);
+
+        let class_dto = json.iter().find(|x| x["type"] == "CPP_CLASS").unwrap();
+        assert_eq!(class_dto["documentation"]["summary"], "Vector of numbers.");
+
+        let at_dto = find_method(&json, "at");
+        assert_eq!(at_dto["documentation"]["summary"], "Returns element at position.");
+        assert_eq!(at_dto["documentation"]["params"][0]["name"], "index");
+
+        let max_dto = find_method(&json, "max");
+        assert_eq!(max_dto["documentation"]["summary"], "Finds maximum value.");
+        assert_eq!(max_dto["documentation"]["throws"][0]["exception"], "std::out_of_range");
+
+        let helper_dto = find_method(&json, "helper");
+        assert!(helper_dto.get("documentation").is_none());
+    }
+
+    #[test]
+    pub fn test_get_deprecation() {
+        let code = String::from(
+            "[[deprecated(\"use open_file\")]] int open(const char* path);\n\
+             void close(int fd) __attribute__((deprecated));\n\
+             class File {\n\
+             public:\n\
+                 /// \\deprecated use size()\n\
+                 int length();\n\
+                 int size();\n\
+             };"
+        );
+        let json = parse_cpp_json(&code);
+        let find = |method_name: &str| find_method(&json, method_name).clone();
+
+        assert_eq!(find("open")["deprecated"], true);
+        assert_eq!(find("open")["deprecationMessage"], "use open_file");
+        assert_eq!(find("close")["deprecated"], true);
+        assert!(find("close").get("deprecationMessage").is_none());
+        assert_eq!(find("length")["deprecated"], true);
+        assert_eq!(find("length")["deprecationMessage"], "use size()");
+        assert_eq!(find("size")["deprecated"], false);
+    }
+
+    #[test]
+    pub fn test_get_visibility() {
+        let code = String::from(
+            "static int helper() { return 0; }\n\
+             int api();\n\
+             class Socket {\n\
+                 int fd();\n\
+             public:\n\
+                 void open();\n\
+                 static Socket create();\n\
+             protected:\n\
+                 void close();\n\
+             };\n\
+             struct Point {\n\
+                 int length();\n\
+             private:\n\
+                 int cache();\n\
+             };"
+        );
+        let json = parse_cpp_json(&code);
+        let find = |method_name: &str| find_method(&json, method_name)["visibility"].clone();
+
+        assert_eq!(find("helper"), "internal");
+        assert_eq!(find("api"), "public");
+        assert_eq!(find("fd"), "private");
+        assert_eq!(find("open"), "public");
+        assert_eq!(find("create"), "public");
+        assert_eq!(find("close"), "protected");
+        assert_eq!(find("length"), "public");
+        assert_eq!(find("cache"), "private");
+    }
+
+    #[test]
+    pub fn test_get_modifiers() {
+        let code = String::from(
+            "class Widget {\n\
+             public:\n\
+                 virtual int width() const override;\n\
+                 static inline Widget create();\n\
+                 explicit Widget(int id);\n\
+                 constexpr int id() const noexcept { return 0; }\n\
+             };\n\
+             extern int version();"
+        );
+        let mut dto_vec = parse_cpp(&code, &ParseOptions::default());
+        let json = serde_json::to_value(&dto_vec).unwrap();
+        let find = |json: &serde_json::Value, method_name: &str, count_of_parameters: usize| json.as_array().unwrap().iter()
+            .find(|x| x["methodName"] == method_name && x["countOfParameters"] == count_of_parameters)
+            .unwrap()["modifiers"].clone();
+
+        assert_eq!(find(&json, "width", 0), serde_json::json!(["virtual", "const", "override"]));
+        assert_eq!(find(&json, "create", 0), serde_json::json!(["static", "inline"]));
+        assert_eq!(find(&json, "Widget", 1), serde_json::json!(["explicit"]));
+        assert_eq!(find(&json, "id", 0), serde_json::json!(["constexpr", "const", "noexcept"]));
+        assert_eq!(find(&json, "version", 0), serde_json::json!(["extern"]));
+
+        for dto in dto_vec.iter_mut() {
+            dto.use_legacy_modifiers();
+        }
+        let legacy_json = serde_json::to_value(&dto_vec).unwrap();
+        assert_eq!(find(&legacy_json, "width", 0), "[]");
+    }
+
+    #[test]
+    pub fn test_get_out_of_line_definitions() {
+        let code = String::from(
+            "namespace geo {\n\
+                 class Shape {\n\
+                 public:\n\
+                     double area(int scale);\n\
+                 };\n\
+                 double Shape::area(int scale) { return 0; }\n\
+             }\n\
+             void geo::Shape::reset() {}\n\
+             template <typename T> T Box<T>::get() { return value; }"
+        );
+        let json = parse_cpp_json(&code);
+        let find = |method_name: &str, kind: &str| json.iter()
+            .find(|x| x["methodName"] == method_name && x["declarationKind"] == kind).unwrap().clone();
+
+        assert_eq!(find("area", "declaration")["className"], "Shape");
+        assert_eq!(find("area", "declaration")["qualifiedName"], "geo::Shape::area");
+        assert_eq!(find("area", "definition")["className"], "Shape");
+        assert_eq!(find("area", "definition")["qualifiedName"], "geo::Shape::area");
+        assert_eq!(find("reset", "definition")["className"], "Shape");
+        assert_eq!(find("reset", "definition")["qualifiedName"], "geo::Shape::reset");
+        assert_eq!(find("get", "definition")["className"], "Box");
+        assert_eq!(find("get", "definition")["qualifiedName"], "Box::get");
+    }
+
+    #[test]
+    pub fn test_get_templates() {
+        let code = String::from(
+            "template <typename T, int N = 3, typename... Rest> class Array { T at(int index); };\n\
+             template <> class Array<bool> { bool at(int index); };\n\
+             template <typename T> class Array<T*> {};\n\
+             template <typename T> T Array<T>::at(int index) { return T(); }\n\
+             template <> bool Array<bool>::at(int index) { return false; }\n\
+             template <typename T> T max(T a, T b);\n\
+             template <> int max<int>(int a, int b) { return a; }"
+        );
+        let json = parse_cpp_json(&code);
+        let find_class = |class_name: &str| json.iter()
+            .find(|x| x["type"] == "CPP_CLASS" && x["className"] == class_name).unwrap().clone();
+        let find_function = |method_name: &str, kind: &str| json.iter()
+            .find(|x| x["methodName"] == method_name && x["declarationKind"] == kind).unwrap().clone();
+
+        assert_eq!(find_class("Array")["templateParameters"], serde_json::json!(["T", "N", "Rest"]));
+        assert!(find_class("Array").get("primaryTemplate").is_none());
+        assert_eq!(find_class("Array<bool>")["templateParameters"], serde_json::json!([]));
+        assert_eq!(find_class("Array<bool>")["templateArguments"], serde_json::json!(["bool"]));
+        assert_eq!(find_class("Array<bool>")["primaryTemplate"], "Array");
+        assert_eq!(find_class("Array<T*>")["templateParameters"], serde_json::json!(["T"]));
+        assert_eq!(find_class("Array<T*>")["primaryTemplate"], "Array");
+
+        assert_eq!(find_function("at", "definition")["qualifiedName"], "Array::at");
+        assert_eq!(find_function("at", "definition")["className"], "Array");
+        assert_eq!(find_function("at", "definition")["templateParameters"], serde_json::json!(["T"]));
+        let specialized_at = json.iter()
+            .find(|x| x["qualifiedName"] == "Array<bool>::at" && x["declarationKind"] == "definition").unwrap();
+        assert_eq!(specialized_at["className"], "Array<bool>");
+
+        assert_eq!(find_function("max", "declaration")["templateParameters"], serde_json::json!(["T"]));
+        assert_eq!(find_function("max<int>", "definition")["primaryTemplate"], "max");
+        assert_eq!(find_function("max<int>", "definition")["templateArguments"], serde_json::json!(["int"]));
+        assert_eq!(find_function("max<int>", "definition")["qualifiedName"], "max<int>");
+    }
+
+    #[test]
+    pub fn test_get_special_members() {
+        let code = String::from(
+            "class Vector {\n\
+             public:\n\
+                 ~Vector();\n\
+                 bool operator==(const Vector& other) const;\n\
+                 Vector operator+(const Vector& other);\n\
+                 explicit operator bool() const;\n\
+                 operator const char*();\n\
+                 friend std::ostream& operator<<(std::ostream& out, const Vector& v);\n\
+             };\n\
+             Vector::~Vector() {}\n\
+             Vector::operator bool() const { return true; }"
+        );
+        let json = parse_cpp_json(&code);
+        let find = |method_name: &str, kind: &str| json.iter()
+            .find(|x| x["methodName"] == method_name && x["declarationKind"] == kind).unwrap().clone();
+
+        assert_eq!(find("~Vector", "declaration")["type"], "CPP_DESTRUCTOR");
+        assert_eq!(find("~Vector", "definition")["type"], "CPP_DESTRUCTOR");
+        assert_eq!(find("~Vector", "definition")["qualifiedName"], "Vector::~Vector");
+        assert_eq!(find("operator==", "declaration")["type"], "CPP_OPERATOR");
+        assert_eq!(find("operator==", "declaration")["modifiers"], serde_json::json!(["const"]));
+        assert_eq!(find("operator+", "declaration")["countOfParameters"], 1);
+        assert_eq!(find("operator<<", "declaration")["countOfParameters"], 2);
+        assert_eq!(find("operator bool", "declaration")["type"], "CPP_CONVERSION_OPERATOR");
+        assert_eq!(find("operator bool", "declaration")["modifiers"], serde_json::json!(["explicit", "const"]));
+        assert_eq!(find("operator bool", "definition")["className"], "Vector");
+        assert_eq!(find("operator bool", "definition")["qualifiedName"], "Vector::operator bool");
+        assert_eq!(find("operator const char*", "declaration")["type"], "CPP_CONVERSION_OPERATOR");
+    }
+
+    #[test]
+    pub fn test_get_virtual_specifiers() {
+        let code = String::from(
+            "class Shape {\n\
+             public:\n\
+                 virtual double area() const = 0;\n\
+                 virtual void draw();\n\
+             };\n\
+             class Circle : public Shape {\n\
+             public:\n\
+                 double area() const override final;\n\
+             };"
+        );
+        let json = parse_cpp_json(&code);
+        let find = |class_name: &str, method_name: &str| json.iter()
+            .find(|x| x["className"] == class_name && x["methodName"] == method_name).unwrap()["modifiers"].clone();
+
+        assert_eq!(find("Shape", ""), serde_json::json!(["abstract"]));
+        assert_eq!(find("Shape", "area"), serde_json::json!(["virtual", "const", "pure"]));
+        assert_eq!(find("Shape", "draw"), serde_json::json!(["virtual"]));
+        assert_eq!(find("Circle", ""), serde_json::json!([]));
+        assert_eq!(find("Circle", "area"), serde_json::json!(["const", "override", "final"]));
+    }
+
+    #[test]
+    pub fn test_get_type_aliases() {
+        let code = String::from(
+            "typedef geo::Circle Round;\n\
+             typedef Shape *ShapePtr, ShapeRef;\n\
+             typedef void (*Callback)(int);\n\
+             typedef struct Node { int value; } Node_t;\n\
+             namespace geo { using Id = unsigned   long; }\n\
+             class Registry {\n\
+                 using Handle = Node*;\n\
+             public:\n\
+                 typedef int Size;\n\
+             };\n\
+             template <typename T> using Vec = std::vector<T>;"
+        );
+        let json = parse_cpp_json(&code);
+        let find = |class_name: &str| json.iter()
+            .find(|x| x["type"] == "CPP_TYPE_ALIAS" && x["className"] == class_name).unwrap().clone();
+
+        assert_eq!(find("Round")["aliasedType"], "geo::Circle");
+        assert_eq!(find("ShapePtr")["aliasedType"], "Shape*");
+        assert_eq!(find("ShapeRef")["aliasedType"], "Shape");
+        assert_eq!(find("Callback")["aliasedType"], "void(*)(int)");
+        assert_eq!(find("Node_t")["aliasedType"], "Node");
+        assert!(json.iter().any(|x| x["type"] == "CPP_CLASS" && x["className"] == "Node"));
+        assert_eq!(find("Id")["aliasedType"], "unsigned long");
+        assert_eq!(find("Handle")["aliasedType"], "Node*");
+        assert_eq!(find("Handle")["visibility"], "private");
+        assert_eq!(find("Size")["visibility"], "public");
+        assert_eq!(find("Vec")["aliasedType"], "std::vector<T>");
+        assert_eq!(find("Vec")["templateParameters"], serde_json::json!(["T"]));
+    }
+
+    #[test]
+    pub fn test_get_namespace_aliases() {
+        let code = String::from(
+            "namespace fs = std::filesystem;\n\
+             namespace g = ::geo;\n\
+             namespace sfs = fs;\n\
+             using Paths = std::vector<fs::path>;\n\
+             void g::Shape::area() {}\n\
+             namespace app { void run(sfs::path p); }"
+        );
+        let json = parse_cpp_json(&code);
+        let find = |type_code: &str, name: &str| json.iter()
+            .find(|x| x["type"] == type_code && (x["className"] == name || x["methodName"] == name)).unwrap().clone();
+
+        assert_eq!(find("CPP_NAMESPACE_ALIAS", "fs")["aliasedType"], "std::filesystem");
+        assert_eq!(find("CPP_NAMESPACE_ALIAS", "g")["aliasedType"], "geo");
+        assert_eq!(find("CPP_NAMESPACE_ALIAS", "sfs")["aliasedType"], "std::filesystem");
+        assert_eq!(find("CPP_TYPE_ALIAS", "Paths")["aliasedType"], "std::vector<std::filesystem::path>");
+        assert_eq!(find("CPP_METHOD", "area")["qualifiedName"], "geo::Shape::area");
+        assert_eq!(find("CPP_METHOD", "area")["className"], "Shape");
+        assert_eq!(find("CPP_METHOD", "run")["qualifiedName"], "app::run");
+    }
+
+    #[test]
+    pub fn test_get_preprocessor_conditions() {
+        let code = String::from(
+            "#define USE_GL\n\
+             #ifdef _WIN32\n\
+             void open_window();\n\
+             #elif defined(__linux__) && LEVEL > 1\n\
+             void open_window();\n\
+             #else\n\
+             void open_window();\n\
+             #endif\n\
+             #ifndef USE_GL\n\
+             void draw_software();\n\
+             #endif\n\
+             #undef USE_GL\n\
+             #if !defined(USE_GL) || 0\n\
+             class Renderer {\n\
+             #ifdef DEBUG\n\
+                 void dump();\n\
+             #endif\n\
+                 void render();\n\
+             };\n\
+             #endif\n\
+             void run();\n"
+        );
+        let parse = |options: &ParseOptions| {
+            serde_json::to_value(parse_cpp(&code, options)).unwrap().as_array().unwrap().iter()
+                .filter(|x| x["type"] != "CPP_PACKAGE" && x["type"] != "CPP_CONSTRUCTOR")
+                .map(|x| (x[if x["type"] == "CPP_CLASS" { "className" } else { "methodName" }].as_str().unwrap().to_string(),
+                          x["preprocessorCondition"].as_str().map(|x| x.to_string())))
+                .collect::<Vec<(String, Option<String>)>>()
+        };
+        let condition = |name: &str, condition: Option<&str>| (name.to_string(), condition.map(|x| x.to_string()));
+
+        /* Unknown configuration keeps every branch */
+        let all = parse(&ParseOptions::default());
+        assert_eq!(all, vec![
+            condition("open_window", Some("defined(_WIN32)")),
+            condition("open_window", Some("!defined(_WIN32) && defined(__linux__) && LEVEL > 1")),
+            condition("open_window", Some("!defined(_WIN32) && !(defined(__linux__) && LEVEL > 1)")),
+            condition("draw_software", Some("!defined(USE_GL)")),
+            condition("Renderer", Some("!defined(USE_GL) || 0")),
+            condition("dump", Some("(!defined(USE_GL) || 0) && defined(DEBUG)")),
+            condition("render", Some("!defined(USE_GL) || 0")),
+            condition("run", None),
+        ]);
+
+        let options = ParseOptions::from_json(r#"{
+            "preprocessor": { "definedMacros": { "__linux__": "", "LEVEL": "2" } }
+        }"#).unwrap();
+        let active = parse(&options);
+        assert_eq!(active, vec![
+            condition("open_window", Some("!defined(_WIN32) && defined(__linux__) && LEVEL > 1")),
+            condition("Renderer", Some("!defined(USE_GL) || 0")),
+            condition("render", Some("!defined(USE_GL) || 0")),
+            condition("run", None),
+        ]);
+
+        let options = ParseOptions::from_json(r#"{
+            "preprocessor": { "definedMacros": { "__linux__": "", "LEVEL": "2" }, "skipInactiveBranches": false }
+        }"#).unwrap();
+        assert_eq!(parse(&options), all);
+    }
+
+    #[test]
+    pub fn test_get_named_lambdas() {
+        let code = String::from(
+            "auto square = [](int x) { return x * x; };\n\
+             namespace geo {\n\
+             class Widget { public: void run(); };\n\
+             void Widget::run() {\n\
+                 int total = 0;\n\
+                 auto cb = [&](int x, int y) { total += x; };\n\
+                 if (total) { auto reset = [this]() {}; }\n\
+             }\n\
+             void plot() { auto step = []() {}; }\n\
+             }"
+        );
+        let json = parse_cpp_json(&code);
+        let find = |method_name: &str| json.iter()
+            .find(|x| x["type"] == "CPP_LAMBDA" && x["methodName"] == method_name).unwrap().clone();
+
+        assert_eq!(find("square")["className"], "");
+        assert_eq!(find("square")["countOfParameters"], 1);
+        assert_eq!(find("square")["visibility"], "public");
+        assert_eq!(find("cb")["className"], "Widget");
+        assert_eq!(find("cb")["countOfParameters"], 2);
+        assert_eq!(find("cb")["qualifiedName"], "geo::Widget::run::cb");
+        assert_eq!(find("cb")["visibility"], "private");
+        assert_eq!(find("reset")["lineCode"], 7);
+        /* Lambda of function in namespace belongs to the namespace, as on invocation side */
+        assert_eq!(find("step")["className"], "geo");
+        assert_eq!(find("step")["qualifiedName"], "geo::plot::step");
+        /* Lambda is not a variable of the enclosing function */
+        assert!(!json.iter().any(|x| x["methodName"] == "total"));
+    }
+
+    #[test]
+    pub fn test_get_enumerators() {
+        let code = String::from(
+            "namespace gfx {\n\
+             enum class Color : unsigned   char {\n\
+                 Red = 1,\n\
+                 Green,\n\
+                 Blue = Red + 2\n\
+             };\n\
+             enum Plain { A, B };\n\
+             }"
+        );
+        let json = parse_cpp_json(&code);
+        let find = |method_type: &str, class_name: &str, method_name: &str| json.iter()
+            .find(|x| x["type"] == method_type && x["className"] == class_name && x["methodName"] == method_name)
+            .unwrap().clone();
+
+        let color = find("CPP_ENUM_CLASS", "Color", "");
+        assert_eq!(color["underlyingType"], "unsigned char");
+        assert_eq!(color["lineCode"], 2);
+        let plain = find("CPP_ENUM", "Plain", "");
+        assert!(plain.get("underlyingType").is_none());
+
+        assert_eq!(find("CPP_ENUMERATOR", "Color", "Red")["valueExpression"], "1");
+        assert_eq!(find("CPP_ENUMERATOR", "Color", "Red")["lineCode"], 3);
+        assert!(find("CPP_ENUMERATOR", "Color", "Green").get("valueExpression").is_none());
+        assert_eq!(find("CPP_ENUMERATOR", "Color", "Blue")["valueExpression"], "Red + 2");
+        assert_eq!(find("CPP_ENUMERATOR", "Color", "Blue")["qualifiedName"], "gfx::Color::Blue");
+        /* Enumerators of plain enum belong to the enclosing namespace */
+        assert_eq!(find("CPP_ENUMERATOR", "Plain", "B")["qualifiedName"], "gfx::B");
+    }
+}
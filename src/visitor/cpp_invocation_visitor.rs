@@ -1,14 +1,26 @@
 use std::io::Read;
+use std::collections::HashMap;
+use std::path::Path;
 use tree_sitter::{Node, Tree};
-use crate::dto::invocation_structure::{InvocationStructure, RepositoryImportDeclaration};
-use crate::dto::object_description::{Description, MethodDescription, PackageDescription, VarDescription};
+use crate::dto::invocation_structure::{DeprecatedMethod, InvocationStructure, RepositoryImportDeclaration};
+use crate::dto::object_description::{Description, MethodDescription, PackageDescription, VarDescription, DispatchCandidate};
 use crate::model::cpp_object::CodeType;
 use crate::unwrap_or_return;
 use crate::unwrap_or_empty_string;
+use crate::unwrap_or_continue;
+use crate::dto::parse_options::ParseOptions;
+use crate::utils::path::{join_path, normalize_path, parent_directory};
+use crate::utils::node::{find_descendant_by_kind, get_child_node_by_kind};
+use crate::utils::cpp_preprocessor::{evaluate_preproc_branch, get_branch_activity, is_preproc_branch_header,
+                                     is_preproc_conditional, update_defined_macros};
+use crate::utils::cpp_namespace::{expand_namespace_aliases, get_namespace_alias, resolve_namespace};
+use crate::utils::cpp_deprecation::{get_count_of_params_range, is_deprecated};
 
 struct NodeKinds;
 
 impl NodeKinds {
+    const ERROR: &'static str = "ERROR";
+    const NAMESPACE_ALIAS_DEFINITION: &'static str = "namespace_alias_definition";
     const CLASS_SPECIFIER: &'static str = "class_specifier";
     const STRUCT_SPECIFIER: &'static str = "struct_specifier";
     const NAMESPACE_DEFINITION: &'static str = "namespace_definition";
@@ -33,7 +45,38 @@ impl NodeKinds {
     const INITIALIZER_LIST: &'static str = "initializer_list";
     const ARGUMENT_LIST: &'static str = "argument_list";
     const TEMPLATE_FUNCTION: &'static str = "template_function";
+    const TEMPLATE_METHOD: &'static str = "template_method";
+    const BINARY_EXPRESSION: &'static str = "binary_expression";
+    const UNARY_EXPRESSION: &'static str = "unary_expression";
+    const ASSIGNMENT_EXPRESSION: &'static str = "assignment_expression";
+    const SUBSCRIPT_EXPRESSION: &'static str = "subscript_expression";
+    const FIELD_DECLARATION_LIST: &'static str = "field_declaration_list";
+    const FUNCTION_DEFINITION: &'static str = "function_definition";
+    const FUNCTION_DECLARATOR: &'static str = "function_declarator";
+    const POINTER_DECLARATOR: &'static str = "pointer_declarator";
+    const REFERENCE_DECLARATOR: &'static str = "reference_declarator";
+    const VIRTUAL_FUNCTION_SPECIFIER: &'static str = "virtual_function_specifier";
+    const VIRTUAL_SPECIFIER: &'static str = "virtual_specifier";
     const FIELD_EXPRESSION: &'static str = "field_expression";
+    const THIS: &'static str = "this";
+    const PREPROC_INCLUDE: &'static str = "preproc_include";
+    const PREPROC_DEF: &'static str = "preproc_def";
+    const PREPROC_CALL: &'static str = "preproc_call";
+    const STRING_LITERAL: &'static str = "string_literal";
+    const TYPE_DEFINITION: &'static str = "type_definition";
+    const ALIAS_DECLARATION: &'static str = "alias_declaration";
+    const USING_DECLARATION: &'static str = "using_declaration";
+    const COMPOUND_STATEMENT: &'static str = "compound_statement";
+    const DECLARATION_LIST: &'static str = "declaration_list";
+    const ABSTRACT_POINTER_DECLARATOR: &'static str = "abstract_pointer_declarator";
+    const ABSTRACT_REFERENCE_DECLARATOR: &'static str = "abstract_reference_declarator";
+    const AUTO: &'static str = "auto";
+    const NEW_EXPRESSION: &'static str = "new_expression";
+    const COMPOUND_LITERAL_EXPRESSION: &'static str = "compound_literal_expression";
+    const LAMBDA_EXPRESSION: &'static str = "lambda_expression";
+    const NAMESPACE_IDENTIFIER: &'static str = "namespace_identifier";
+    const SCOPED_NAMESPACE_IDENTIFIER: &'static str = "scoped_namespace_identifier";
+    const ENUM_SPECIFIER: &'static str = "enum_specifier";
 }
 
 struct NodeNames;
@@ -45,8 +88,15 @@ impl NodeNames {
     const ARGUMENTS: &'static str = "arguments";
     const ARGUMENT: &'static str = "argument";
     const NAME: &'static str = "name";
+    const ALTERNATIVE: &'static str = "alternative";
     const TYPE: &'static str = "type";
     const FIELD: &'static str = "field";
+    const PATH: &'static str = "path";
+    const LEFT: &'static str = "left";
+    const RIGHT: &'static str = "right";
+    const INDEX: &'static str = "index";
+    const PARAMETERS: &'static str = "parameters";
+    const NAMESPACE: &'static str = "namespace";
 }
 
 struct KeyWords;
@@ -55,33 +105,96 @@ impl KeyWords {
     const THIS: &'static str = "this";
     const SUPER_CLASS: &'static str = "super";
     const EMPTY_STRING: &'static str = "";
+    const OPERATOR: &'static str = "operator";
+    const ASSIGNMENT: &'static str = "=";
+    const SUBSCRIPT: &'static str = "[]";
+    const SCOPE_SEPARATOR: &'static str = "::";
+    const MAX_ALIAS_DEPTH: usize = 16;
+    /* Wrappers `std::unique_ptr<Foo>` which forward calls to wrapped object */
+    const POINTER_WRAPPERS: [&'static str; 4] = ["unique_ptr", "shared_ptr", "auto_ptr", "reference_wrapper"];
+    const VALUE_WRAPPERS: [&'static str; 1] = ["optional"];
+    /* Factories `std::make_unique<Foo>()` creating wrapped object */
+    const POINTER_FACTORIES: [&'static str; 3] = ["make_unique", "make_shared", "allocate_shared"];
+    const VALUE_FACTORIES: [&'static str; 1] = ["make_optional"];
+}
+
+
+/* Type of variable, also named by typedef, alias declaration or using-declaration */
+struct VarType {
+    type_name: String,
+    /* Pointer, reference or smart pointer */
+    indirect: bool,
+    primitive: bool,
+}
+
+/* Method declared in class body */
+struct MethodSignature {
+    class_name: String,
+    method_name: String,
+    count_of_params: usize,
+    /* Declared with virtual, override or final */
+    is_virtual: bool,
 }
 
-const MAX_TOKEN_LENGTH: usize = 250;
+/* Lambda assigned to variable, attributed to class of enclosing function */
+struct LambdaDescription {
+    var_description: VarDescription,
+    /* Id of enclosing function definition, None for lambda of namespace or class scope visible in the whole file */
+    function_id: Option<usize>,
+}
 
 struct InvocationData {
     import_declarations: Vec<RepositoryImportDeclaration>,
     package_descriptions: Vec<PackageDescription>,
     var_descriptions: Vec<VarDescription>,
     links: Vec<MethodDescription>,
+    method_signatures: Vec<MethodSignature>,
+    /* Indexes of links called through pointer or reference */
+    indirect_links: Vec<usize>,
+    /* Type aliases of enclosing scopes, innermost last */
+    alias_scopes: Vec<HashMap<String, VarType>>,
+    /* Lambdas assigned to variables */
+    lambda_descriptions: Vec<LambdaDescription>,
+    /* Enums declared in this file, their enumerators are linked from qualified uses `Color::Red` */
+    enum_names: Vec<String>,
+    /* Configured macros with `#define` and `#undef` of the file applied, None for unknown configuration */
+    defined_macros: Option<HashMap<String, String>>,
+    /* Namespace aliases `fs` of `namespace fs = std::filesystem;` declared so far */
+    namespace_aliases: HashMap<String, String>,
+    /* Deprecated functions and constructors of deprecated classes declared in this file */
+    deprecated_methods: Vec<DeprecatedMethod>,
     current_package: String,
     source_code: String,
     path: String,
+    options: ParseOptions,
 }
 
 impl InvocationData {
-    fn new(source_code: String, path: String) -> Self {
+    fn new(source_code: String, path: String, options: ParseOptions) -> Self {
         Self {
             import_declarations: vec![],
             current_package: String::new(),
             package_descriptions: vec![],
             var_descriptions: vec![],
             links: vec![],
+            method_signatures: vec![],
+            indirect_links: vec![],
+            alias_scopes: vec![HashMap::new()],
+            lambda_descriptions: vec![],
+            enum_names: vec![],
+            defined_macros: options.preprocessor().defined_macros().clone(),
+            namespace_aliases: HashMap::new(),
+            deprecated_methods: vec![],
             source_code,
             path,
+            options,
         }
     }
 
+    fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+
     fn set_current_package(&mut self, current_package: String) {
         self.current_package = current_package;
     }
@@ -98,6 +211,10 @@ impl InvocationData {
         self.current_package.clone()
     }
 
+    fn import_list(&self) -> &Vec<RepositoryImportDeclaration> {
+        &self.import_declarations
+    }
+
     fn mut_import_list(&mut self) -> &mut Vec<RepositoryImportDeclaration> {
         &mut self.import_declarations
     }
@@ -122,37 +239,131 @@ impl InvocationData {
         &mut self.links
     }
 
-    fn take(self) -> (Vec<RepositoryImportDeclaration>, Vec<MethodDescription>) {
-        (self.import_declarations, self.links)
+    fn method_signatures(&self) -> &Vec<MethodSignature> {
+        &self.method_signatures
+    }
+
+    fn mut_method_signatures(&mut self) -> &mut Vec<MethodSignature> {
+        &mut self.method_signatures
+    }
+
+    fn mut_indirect_links(&mut self) -> &mut Vec<usize> {
+        &mut self.indirect_links
+    }
+
+    fn lambda_descriptions(&self) -> &Vec<LambdaDescription> {
+        &self.lambda_descriptions
+    }
+
+    fn mut_lambda_descriptions(&mut self) -> &mut Vec<LambdaDescription> {
+        &mut self.lambda_descriptions
+    }
+
+    fn enum_names(&self) -> &Vec<String> {
+        &self.enum_names
+    }
+
+    fn mut_enum_names(&mut self) -> &mut Vec<String> {
+        &mut self.enum_names
+    }
+
+    fn defined_macros(&self) -> &Option<HashMap<String, String>> {
+        &self.defined_macros
+    }
+
+    fn update_defined_macros(&mut self, node: &Node) {
+        if let Some(defined_macros) = self.defined_macros.as_mut() {
+            update_defined_macros(node, &self.source_code, defined_macros);
+        }
+    }
+
+    fn namespace_aliases(&self) -> &HashMap<String, String> {
+        &self.namespace_aliases
+    }
+
+    fn add_namespace_alias(&mut self, alias: String, namespace: String) {
+        self.namespace_aliases.insert(alias, namespace);
+    }
+
+    fn mut_deprecated_methods(&mut self) -> &mut Vec<DeprecatedMethod> {
+        &mut self.deprecated_methods
+    }
+
+    fn push_alias_scope(&mut self) {
+        self.alias_scopes.push(HashMap::new());
+    }
+
+    fn pop_alias_scope(&mut self) {
+        self.alias_scopes.pop();
+    }
+
+    fn add_type_alias(&mut self, name: String, type_alias: VarType) {
+        if let Some(scope) = self.alias_scopes.last_mut() {
+            scope.insert(name, type_alias);
+        }
+    }
+
+    fn find_type_alias(&self, name: &String) -> Option<&VarType> {
+        self.alias_scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
+
+    fn take(self) -> (Vec<RepositoryImportDeclaration>, Vec<MethodDescription>, Vec<DeprecatedMethod>) {
+        (self.import_declarations, self.links, self.deprecated_methods)
     }
 
 }
 
-pub fn get_file_structure(source_code: String, tree: Tree, path: String) -> InvocationStructure {
+pub fn get_file_structure(source_code: String, tree: Tree, path: String, options: &ParseOptions) -> InvocationStructure {
 
-    let mut invocation_data = InvocationData::new(source_code, path.clone());
+    let mut invocation_data = InvocationData::new(source_code, path.clone(), options.clone());
     add_package_declaration(&mut invocation_data, path.clone());
 
     visit_node(tree.root_node(), &mut invocation_data, &mut KeyWords::EMPTY_STRING.to_string());
+    add_dispatch_candidates(&mut invocation_data);
 
     let (
         import_declarations,
-        method_descriptions
+        method_descriptions,
+        deprecated_methods
     ) = invocation_data.take();
 
-    return InvocationStructure::new(
+    let mut invocation_structure = InvocationStructure::new(
         import_declarations,
         method_descriptions,
         CodeType::type_codes(),
     );
+    invocation_structure.mark_deprecated_links(&deprecated_methods);
+
+    return invocation_structure;
 }
 
 fn visit_node(node: Node, invocation_data: &mut InvocationData, class_name: &mut String) {
 
     for child in node.named_children(&mut node.walk()) {
 
+        /* Branches of `#if` chain are visited by visit_preproc_conditional */
+        if is_preproc_branch_header(&node, &child) {
+            continue;
+        }
+        if is_preproc_conditional(&child) {
+            visit_preproc_conditional(child, invocation_data, class_name);
+            continue;
+        }
+
+        if node.kind() == NodeKinds::FIELD_DECLARATION_LIST {
+            visit_method_declaration(&child, invocation_data);
+        }
+        if invocation_data.options().extractors().deprecation() {
+            add_deprecated_method(&child, invocation_data);
+        }
+
         match child.kind() {
 
+            /* Imports */
+            NodeKinds::PREPROC_INCLUDE => visit_preproc_include(&child, invocation_data),
+            /* `#define` and `#undef` of the file change configured macros */
+            NodeKinds::PREPROC_DEF | NodeKinds::PREPROC_CALL => invocation_data.update_defined_macros(&child),
+
             /* Var descriptions */
             NodeKinds::NAMESPACE_DEFINITION => {
                 *class_name = visit_namespace_definition_get_name(&child, invocation_data)
@@ -170,10 +381,79 @@ fn visit_node(node: Node, invocation_data: &mut InvocationData, class_name: &mut
             NodeKinds::DECLARATION => visit_declaration(&child, invocation_data, class_name),
             NodeKinds::INIT_DECLARATOR => visit_init_declaration(&child, invocation_data, class_name),
             NodeKinds::CALL_EXPRESSION => visit_call_expression(&child, invocation_data, class_name),
+            NodeKinds::BINARY_EXPRESSION | NodeKinds::UNARY_EXPRESSION | NodeKinds::ASSIGNMENT_EXPRESSION
+            | NodeKinds::SUBSCRIPT_EXPRESSION => visit_operator_expression(&child, invocation_data),
+            NodeKinds::SCOPED_IDENTIFIER => visit_scoped_identifier(&child, invocation_data),
+
+            /* Enums */
+            NodeKinds::ENUM_SPECIFIER => visit_enum_specifier(&child, invocation_data),
+
+            /* Type aliases */
+            NodeKinds::TYPE_DEFINITION => visit_type_definition(&child, invocation_data),
+            NodeKinds::ALIAS_DECLARATION => visit_alias_declaration(&child, invocation_data),
+            NodeKinds::USING_DECLARATION => visit_using_declaration(&child, invocation_data),
+            /* The grammar parses namespace alias as error node */
+            NodeKinds::ERROR | NodeKinds::NAMESPACE_ALIAS_DEFINITION => visit_namespace_alias(&child, invocation_data),
             _ => {}
         }
 
-        visit_node(child, invocation_data, class_name);
+        /* Aliases are visible to the end of enclosing block, namespace or class body */
+        let is_alias_scope = matches!(child.kind(),
+            NodeKinds::COMPOUND_STATEMENT | NodeKinds::DECLARATION_LIST | NodeKinds::FIELD_DECLARATION_LIST);
+        if is_alias_scope {
+            invocation_data.push_alias_scope();
+        }
+        /* Calls inside lambda body belong to the function enclosing the lambda */
+        if child.kind() == NodeKinds::LAMBDA_EXPRESSION {
+            let mut owner_name = get_enclosing_class_name(&child, invocation_data);
+            visit_node(child, invocation_data, &mut owner_name);
+        } else {
+            visit_node(child, invocation_data, class_name);
+        }
+        if is_alias_scope {
+            invocation_data.pop_alias_scope();
+        }
+    }
+}
+
+/* Calls of inactive branches are skipped for configured macros, the same branches as on declaration side */
+fn visit_preproc_conditional(node: Node, invocation_data: &mut InvocationData, class_name: &mut String) {
+
+    /* Some earlier branch is active, None if it is unknown */
+    let mut is_taken = Some(false);
+    let mut branch_opt = Some(node);
+
+    while let Some(branch) = branch_opt {
+
+        let value = evaluate_preproc_branch(&branch, invocation_data.source_code(),
+                                            invocation_data.defined_macros().as_ref());
+        let (is_active, is_branch_taken) = get_branch_activity(is_taken, value);
+        is_taken = is_branch_taken;
+
+        let is_skipped = is_active == Some(false) && invocation_data.options().preprocessor().skip_inactive_branches();
+        if !is_skipped {
+            visit_node(branch, invocation_data, class_name);
+        }
+        branch_opt = branch.child_by_field_name(NodeNames::ALTERNATIVE);
+    }
+}
+
+fn visit_preproc_include(node: &Node, invocation_data: &mut InvocationData) {
+
+    /* #include "path" (string_literal) or #include <path> (system_lib_string) */
+    let path_node = unwrap_or_return!(node.child_by_field_name(NodeNames::PATH));
+    let path_value = unwrap_or_return!(get_node_value(&path_node, invocation_data));
+    let include_path = path_value.trim_matches(|c| c == '"' || c == '<' || c == '>');
+    if include_path.is_empty() {
+        return;
+    }
+
+    let is_quoted = path_node.kind() == NodeKinds::STRING_LITERAL;
+    let package_name = resolve_include_path(include_path, is_quoted, invocation_data);
+    let import_declaration = RepositoryImportDeclaration::new(package_name);
+
+    if !invocation_data.mut_import_list().contains(&import_declaration) {
+        invocation_data.mut_import_list().push(import_declaration);
     }
 }
 
@@ -212,19 +492,232 @@ fn visit_namespace_definition_get_name(node: &Node, invocation_data: &mut Invoca
     return namespace_name;
 }
 
+fn visit_type_definition(node: &Node, invocation_data: &mut InvocationData) {
+
+    let type_node = unwrap_or_return!(node.child_by_field_name(NodeNames::TYPE));
+    let type_name = match type_node.kind() {
+        NodeKinds::CLASS_SPECIFIER | NodeKinds::STRUCT_SPECIFIER | NodeKinds::UNION_SPECIFIER => {
+            let name_node = unwrap_or_return!(type_node.child_by_field_name(NodeNames::NAME));
+            get_name_from_class_name_node(&name_node, invocation_data)
+        }
+        _ => KeyWords::EMPTY_STRING.to_string()
+    };
+    let (value_type_node, wrapper_indirect) = unwrap_type_node(type_node, invocation_data);
+    let var_type = get_var_type(&value_type_node, invocation_data);
+    let type_name = if type_name.is_empty() { var_type.type_name } else { type_name };
+
+    /* `typedef Shape *ShapePtr, ShapeRef;` declares several aliases */
+    for declarator in node.children_by_field_name(NodeNames::DECLARATOR, &mut node.walk()) {
+        let name_node = match find_descendant_by_kind(declarator, NodeKinds::TYPE_IDENTIFIER) {
+            Some(name_node) => name_node,
+            None => continue
+        };
+        let alias_name = unwrap_or_empty_string!(get_node_value(&name_node, invocation_data));
+        let indirect = wrapper_indirect || var_type.indirect || declarator.kind() == NodeKinds::POINTER_DECLARATOR
+            || declarator.kind() == NodeKinds::REFERENCE_DECLARATOR;
+        add_type_alias(alias_name, type_name.clone(), indirect, var_type.primitive, invocation_data);
+    }
+}
+
+fn visit_alias_declaration(node: &Node, invocation_data: &mut InvocationData) {
+
+    let name_node = unwrap_or_return!(node.child_by_field_name(NodeNames::NAME));
+    let type_descriptor = unwrap_or_return!(node.child_by_field_name(NodeNames::TYPE));
+    let type_node = unwrap_or_return!(type_descriptor.child_by_field_name(NodeNames::TYPE));
+    let alias_name = unwrap_or_empty_string!(get_node_value(&name_node, invocation_data));
+    let (value_type_node, wrapper_indirect) = unwrap_type_node(type_node, invocation_data);
+    let var_type = get_var_type(&value_type_node, invocation_data);
+    let indirect = match type_descriptor.child_by_field_name(NodeNames::DECLARATOR) {
+        Some(declarator) => declarator.kind() == NodeKinds::ABSTRACT_POINTER_DECLARATOR
+            || declarator.kind() == NodeKinds::ABSTRACT_REFERENCE_DECLARATOR,
+        None => false
+    };
+    add_type_alias(alias_name, var_type.type_name, wrapper_indirect || var_type.indirect || indirect,
+                   var_type.primitive, invocation_data);
+}
+
+fn visit_using_declaration(node: &Node, invocation_data: &mut InvocationData) {
+
+    /* `using geo::Shape;` brings name into scope and hides outer alias with the same name.
+       Directive `using namespace geo;` needs nothing: types are resolved by unqualified name */
+    let scoped_node = unwrap_or_return!(get_child_node_by_kind(node, NodeKinds::SCOPED_IDENTIFIER));
+    let name_node = unwrap_or_return!(scoped_node.child_by_field_name(NodeNames::NAME));
+    let name = unwrap_or_empty_string!(get_node_value(&name_node, invocation_data));
+    add_type_alias(name.clone(), name, false, false, invocation_data);
+}
+
+fn add_type_alias(alias_name: String, type_name: String, indirect: bool, primitive: bool, invocation_data: &mut InvocationData) {
+
+    if alias_name.is_empty() || type_name.is_empty() || type_name == KeyWords::THIS {
+        return;
+    }
+
+    invocation_data.add_type_alias(alias_name, VarType { type_name, indirect, primitive });
+}
+
+/* Follows chain of aliases `using A = B; typedef C B;` to the named type */
+fn resolve_type_alias(type_node: &Node, type_name: String, invocation_data: &InvocationData) -> VarType {
+
+    let mut resolved = VarType { type_name, indirect: false, primitive: is_primitive_type(type_node) };
+
+    /* Aliases are declared with unqualified names */
+    if type_node.kind() != NodeKinds::TYPE_IDENTIFIER {
+        return resolved;
+    }
+
+    for _ in 0..KeyWords::MAX_ALIAS_DEPTH {
+        let type_alias = match invocation_data.find_type_alias(&resolved.type_name) {
+            Some(type_alias) if type_alias.type_name != resolved.type_name => type_alias,
+            _ => break
+        };
+        resolved = VarType {
+            type_name: type_alias.type_name.clone(),
+            indirect: resolved.indirect || type_alias.indirect,
+            primitive: type_alias.primitive,
+        };
+    }
+
+    return resolved;
+}
+
+fn get_var_type(type_node: &Node, invocation_data: &InvocationData) -> VarType {
+    let type_name = get_var_from_type_specifier_or_this(type_node, invocation_data);
+    resolve_type_alias(type_node, type_name, invocation_data)
+}
+
+/* `std::unique_ptr<std::optional<Foo>>` -> `Foo`, pointer-like if any wrapper is */
+fn unwrap_type_node<'a>(type_node: Node<'a>, invocation_data: &InvocationData) -> (Node<'a>, bool) {
+
+    let mut value_type_node = type_node;
+    let mut indirect = false;
+
+    while value_type_node.kind() == NodeKinds::TEMPLATE_TYPE {
+        let name_node = match value_type_node.child_by_field_name(NodeNames::NAME) {
+            Some(name_node) => name_node,
+            None => break
+        };
+        let wrapper_name = get_var_from_type_specifier_or_this(&name_node, invocation_data);
+        let is_pointer_wrapper = KeyWords::POINTER_WRAPPERS.contains(&wrapper_name.as_str());
+        if !is_pointer_wrapper && !KeyWords::VALUE_WRAPPERS.contains(&wrapper_name.as_str()) {
+            break;
+        }
+        value_type_node = match get_first_template_argument(&value_type_node) {
+            Some(argument_node) => argument_node,
+            None => break
+        };
+        indirect = indirect || is_pointer_wrapper;
+    }
+
+    return (value_type_node, indirect);
+}
+
+fn visit_namespace_alias(node: &Node, invocation_data: &mut InvocationData) {
+
+    let (alias, namespace) = unwrap_or_return!(get_namespace_alias(node, invocation_data.source_code()));
+    let namespace = resolve_namespace(&namespace, invocation_data.namespace_aliases());
+    invocation_data.add_namespace_alias(alias, namespace);
+}
+
+/* Type of `auto` variable from its initializer */
+fn infer_auto_type(declarator: &Node, invocation_data: &InvocationData) -> Option<VarType> {
+
+    if declarator.kind() != NodeKinds::INIT_DECLARATOR {
+        return None;
+    }
+    let value = declarator.child_by_field_name(NodeNames::VALUE)?;
+
+    match value.kind() {
+
+        /* `auto p = new Foo()` */
+        NodeKinds::NEW_EXPRESSION => {
+            let type_node = value.child_by_field_name(NodeNames::TYPE)?;
+            let mut var_type = get_var_type(&type_node, invocation_data);
+            var_type.indirect = true;
+            Some(var_type)
+        }
+
+        /* `auto f = Foo{1}` */
+        NodeKinds::COMPOUND_LITERAL_EXPRESSION => {
+            let type_node = value.child_by_field_name(NodeNames::TYPE)?;
+            Some(get_var_type(&type_node, invocation_data))
+        }
+
+        NodeKinds::CALL_EXPRESSION => {
+            let function = value.child_by_field_name(NodeNames::FUNCTION)?;
+            match function.kind() {
+
+                /* `auto p = std::make_unique<Foo>()` */
+                NodeKinds::TEMPLATE_FUNCTION => {
+                    let factory_name = get_name_from_template_or_scoped_node(&function, invocation_data);
+                    let is_pointer_factory = KeyWords::POINTER_FACTORIES.contains(&factory_name.as_str());
+                    if !is_pointer_factory && !KeyWords::VALUE_FACTORIES.contains(&factory_name.as_str()) {
+                        return None;
+                    }
+                    let type_node = get_first_template_argument(&function)?;
+                    let mut var_type = get_var_type(&type_node, invocation_data);
+                    var_type.indirect = var_type.indirect || is_pointer_factory;
+                    Some(var_type)
+                }
+
+                /* `auto f = Foo(1)`, call of function returning unknown type otherwise */
+                NodeKinds::IDENTIFIER | NodeKinds::SCOPED_IDENTIFIER => {
+                    let class_name = match function.kind() {
+                        NodeKinds::IDENTIFIER => get_node_value(&function, invocation_data)?,
+                        _ => get_name_from_template_or_scoped_node(&function, invocation_data)
+                    };
+                    if !is_known_class(&class_name, invocation_data) {
+                        return None;
+                    }
+                    Some(VarType { type_name: class_name, indirect: false, primitive: false })
+                }
+
+                _ => None
+            }
+        }
+
+        _ => None
+    }
+}
+
+fn is_known_class(class_name: &String, invocation_data: &InvocationData) -> bool {
+    invocation_data.package_descriptions().iter().any(|x| x.class_name() == class_name)
+        || find_declaring_header(class_name, invocation_data).is_some()
+        || invocation_data.find_type_alias(class_name).is_some()
+}
+
+fn get_first_template_argument<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+    let argument_list = node.child_by_field_name(NodeNames::ARGUMENTS)?;
+    let type_descriptor = argument_list.named_child(0)?;
+    type_descriptor.child_by_field_name(NodeNames::TYPE)
+}
+
+fn is_primitive_type(node: &Node) -> bool {
+    node.kind() == NodeKinds::PRIMITIVE_TYPE || node.kind() == NodeKinds::SIZED_TYPE_SPECIFIER
+}
+
 fn visit_field_declaration(node: &Node, invocation_data: &mut InvocationData) {
 
     let type_node = unwrap_or_return!(node.child_by_field_name(NodeNames::TYPE));
+    let (value_type_node, wrapper_indirect) = unwrap_type_node(type_node, invocation_data);
+    let field_type = unwrap_or_empty_string!(get_node_value(&value_type_node, invocation_data));
+    let field_type = expand_namespace_aliases(&field_type, invocation_data.namespace_aliases());
+    let var_type = resolve_type_alias(&value_type_node, field_type, invocation_data);
+    /* Built-in types have neither methods nor user-defined operators */
+    if var_type.primitive {
+        return;
+    }
     let declarator = unwrap_or_return!(node.child_by_field_name(NodeNames::DECLARATOR));
-    let identifier = unwrap_or_return!(get_field_identifier_from_declarator(declarator));
+    /* Fields are declared with field_identifier, parameters with identifier */
+    let identifier = unwrap_or_return!(get_field_identifier_from_declarator(declarator)
+        .or_else(|| get_identifier_from_declarator(declarator)));
     let field_name = unwrap_or_empty_string!(get_node_value(&identifier, invocation_data));
-    let field_type = unwrap_or_empty_string!(get_node_value(&type_node, invocation_data));
 
-    if field_name.is_empty() || field_type.is_empty() {
+    if field_name.is_empty() || var_type.type_name.is_empty() {
         return;
     }
 
-    add_var_description(node, field_type, field_name, invocation_data);
+    add_var_description(node, var_type.type_name, field_name, invocation_data);
+    set_last_var_indirect(&declarator, wrapper_indirect || var_type.indirect, invocation_data);
 }
 
 fn visit_parameter_list(node: &Node, invocation_data: &mut InvocationData) {
@@ -240,14 +733,35 @@ fn visit_parameter_list(node: &Node, invocation_data: &mut InvocationData) {
 fn visit_declaration(node: &Node, invocation_data: &mut InvocationData, class_name: &String) {
 
     let declarator = unwrap_or_return!(node.child_by_field_name(NodeNames::DECLARATOR));
+    if is_lambda_declarator(&declarator) {
+        add_lambda_description(node, &declarator, invocation_data);
+        return;
+    }
     let type_node = unwrap_or_return!(node.child_by_field_name(NodeNames::TYPE));
     let identifier = unwrap_or_return!(get_identifier_from_declarator(declarator));
-    let method_name = get_var_from_type_specifier_or_this(&type_node, invocation_data);
+    let (value_type_node, wrapper_indirect) = unwrap_type_node(type_node, invocation_data);
+    let inferred_type = match value_type_node.kind() {
+        NodeKinds::AUTO => infer_auto_type(&declarator, invocation_data),
+        _ => None
+    };
+    let is_deduced = inferred_type.is_some() || value_type_node.id() != type_node.id();
+    let var_type = match inferred_type {
+        Some(var_type) => var_type,
+        None => get_var_type(&value_type_node, invocation_data)
+    };
+    let method_name = var_type.type_name;
     let var_name = unwrap_or_empty_string!(get_node_value(&identifier, invocation_data));
 
     add_var_description(node, method_name.clone(), var_name.clone(), invocation_data);
+    set_last_var_indirect(&declarator, wrapper_indirect || var_type.indirect, invocation_data);
+    set_last_var_package(&value_type_node, invocation_data);
+
+    if declarator.kind() == NodeKinds::INIT_DECLARATOR && var_type.primitive {
+        return;
+    }
 
-    if declarator.kind() == NodeKinds::INIT_DECLARATOR && type_node.kind() == NodeKinds::PRIMITIVE_TYPE {
+    /* Wrapped or `auto` object is created by the initializer, not by zero arg constructor */
+    if is_deduced {
         return;
     }
 
@@ -274,10 +788,406 @@ fn visit_init_declaration(node: &Node, invocation_data: &mut InvocationData, cla
 fn visit_call_expression(node: &Node, invocation_data: &mut InvocationData, class_name: &String) {
     let function_node = unwrap_or_return!(node.child_by_field_name(NodeNames::FUNCTION));
     let arguments_node = unwrap_or_return!(node.child_by_field_name(NodeNames::ARGUMENTS));
+    if add_link_to_lambda(node, &function_node, arguments_node.named_child_count(), invocation_data) {
+        return;
+    }
     let (method_name, var_name) = get_method_and_var_from_expression(&function_node, invocation_data);
     let count_of_params: usize = arguments_node.named_child_count();
+    let template_arguments = get_template_arguments_from_function(&function_node, invocation_data);
+    let links_count = invocation_data.mut_navigation_links().len();
 
+    /* `this->draw()` belongs to class of enclosing function, also from lambda body or out-of-line definition */
+    let class_name = if var_name == KeyWords::THIS {
+        get_enclosing_class_name(node, invocation_data)
+    } else {
+        class_name.clone()
+    };
     add_navigation_link(node, &var_name, &method_name, &class_name, count_of_params, invocation_data);
+
+    let is_indirect = find_var_desc_by_name(&var_name, invocation_data).is_some_and(|x| x.indirect());
+    let links_count_after = invocation_data.mut_navigation_links().len();
+    if is_indirect {
+        invocation_data.mut_indirect_links().extend(links_count..links_count_after);
+    }
+
+    /* Template arguments are kept for resolution of `make<Foo>()` */
+    for navigation_link in invocation_data.mut_navigation_links().iter_mut().skip(links_count) {
+        navigation_link.set_template_arguments(template_arguments.clone());
+    }
+}
+
+/* `auto callback = [](int x) {...};` and `std::function<void(int)> callback = ...` */
+fn is_lambda_declarator(declarator: &Node) -> bool {
+    declarator.kind() == NodeKinds::INIT_DECLARATOR && declarator
+        .child_by_field_name(NodeNames::VALUE)
+        .is_some_and(|x| x.kind() == NodeKinds::LAMBDA_EXPRESSION)
+}
+
+fn add_lambda_description(node: &Node, declarator: &Node, invocation_data: &mut InvocationData) {
+
+    let name_node = unwrap_or_return!(declarator.child_by_field_name(NodeNames::DECLARATOR));
+    let lambda_name = get_var_from_declarator(&name_node, invocation_data);
+    if lambda_name.is_empty() {
+        return;
+    }
+
+    let var_description = VarDescription::new(
+        invocation_data.get_current_package(),
+        get_enclosing_class_name(node, invocation_data),
+        get_line_number(node),
+        get_position_in_line(node),
+        lambda_name,
+    );
+    let function_id = get_enclosing_function(node).map(|x| x.id());
+    invocation_data.mut_lambda_descriptions().push(LambdaDescription { var_description, function_id });
+}
+
+/* Call `callback(3)` of named lambda declared in the same function or at namespace scope links to the lambda */
+fn add_link_to_lambda(node: &Node, function_node: &Node, count_of_params: usize, invocation_data: &mut InvocationData) -> bool {
+
+    if function_node.kind() != NodeKinds::IDENTIFIER {
+        return false;
+    }
+    let lambda_name = unwrap_or_empty_string!(get_node_value(function_node, invocation_data));
+    let function_id = get_enclosing_function(node).map(|x| x.id());
+    let lambda_description = match invocation_data.lambda_descriptions().iter().rev()
+        .filter(|x| x.function_id.is_none() || x.function_id == function_id)
+        .find(|x| x.var_description.var_name() == lambda_name) {
+        Some(lambda_description) => &lambda_description.var_description,
+        None => return false
+    };
+
+    let navigation_link = MethodDescription::new(
+        lambda_description.get_package_name(),
+        lambda_description.get_class_name(),
+        get_line_number(node),
+        get_position_in_line(node),
+        lambda_name.clone(),
+        lambda_name,
+        count_of_params,
+    );
+    invocation_data.mut_navigation_links().push(navigation_link);
+
+    true
+}
+
+fn get_enclosing_function<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+
+    let mut parent_opt = node.parent();
+    while let Some(parent) = parent_opt {
+        if parent.kind() == NodeKinds::FUNCTION_DEFINITION {
+            return Some(parent);
+        }
+        parent_opt = parent.parent();
+    }
+    None
+}
+
+/* Class (or namespace) of the function enclosing the node, out-of-line `Foo::bar() {}` belongs to `Foo` */
+fn get_enclosing_class_name(node: &Node, invocation_data: &InvocationData) -> String {
+
+    let mut parent_opt = node.parent();
+
+    while let Some(parent) = parent_opt {
+        match parent.kind() {
+            NodeKinds::FUNCTION_DEFINITION => {
+                let qualifier_opt = parent.child_by_field_name(NodeNames::DECLARATOR)
+                    .and_then(|x| find_descendant_by_kind(x, NodeKinds::FUNCTION_DECLARATOR))
+                    .and_then(|x| x.child_by_field_name(NodeNames::DECLARATOR))
+                    .filter(|x| x.kind() == NodeKinds::SCOPED_IDENTIFIER)
+                    .and_then(|x| x.child_by_field_name(NodeNames::NAMESPACE));
+                if let Some(qualifier) = qualifier_opt {
+                    return get_name_from_qualifier(&qualifier, invocation_data);
+                }
+            }
+            NodeKinds::CLASS_SPECIFIER | NodeKinds::STRUCT_SPECIFIER | NodeKinds::UNION_SPECIFIER
+            | NodeKinds::NAMESPACE_DEFINITION => {
+                if let Some(name_node) = parent.child_by_field_name(NodeNames::NAME) {
+                    return get_name_from_qualifier(&name_node, invocation_data);
+                }
+            }
+            _ => {}
+        }
+        parent_opt = parent.parent();
+    }
+
+    return KeyWords::EMPTY_STRING.to_string();
+}
+
+fn get_name_from_qualifier(node: &Node, invocation_data: &InvocationData) -> String {
+
+    match node.kind() {
+        /* Name of namespace definition is identifier */
+        NodeKinds::NAMESPACE_IDENTIFIER | NodeKinds::TYPE_IDENTIFIER | NodeKinds::IDENTIFIER
+        => unwrap_or_empty_string!(get_node_value(node, invocation_data)),
+        NodeKinds::TEMPLATE_TYPE | NodeKinds::SCOPED_NAMESPACE_IDENTIFIER | NodeKinds::SCOPED_TYPE_IDENTIFIER
+        => match node.child_by_field_name(NodeNames::NAME) {
+            Some(name_node) => get_name_from_qualifier(&name_node, invocation_data),
+            None => KeyWords::EMPTY_STRING.to_string()
+        },
+        _ => KeyWords::EMPTY_STRING.to_string()
+    }
+}
+
+fn visit_enum_specifier(node: &Node, invocation_data: &mut InvocationData) {
+
+    let name_node = unwrap_or_return!(node.child_by_field_name(NodeNames::NAME));
+    let enum_name = get_name_from_qualifier(&name_node, invocation_data);
+    if !enum_name.is_empty() {
+        invocation_data.mut_enum_names().push(enum_name);
+    }
+}
+
+/* Qualified enumerator `Color::Red` links to the enumerator of enum declared in this file or listed in options */
+fn visit_scoped_identifier(node: &Node, invocation_data: &mut InvocationData) {
+
+    /* Qualified declarator `void Widget::run()` and called function `Widget::create()` are not enumerators */
+    let parent = unwrap_or_return!(node.parent());
+    let is_declarator_or_function = parent.child_by_field_name(NodeNames::DECLARATOR) == Some(*node)
+        || (parent.kind() == NodeKinds::CALL_EXPRESSION && parent.child_by_field_name(NodeNames::FUNCTION) == Some(*node));
+    if is_declarator_or_function {
+        return;
+    }
+
+    let qualifier_node = unwrap_or_return!(node.child_by_field_name(NodeNames::NAMESPACE));
+    let name_node = unwrap_or_return!(node.child_by_field_name(NodeNames::NAME));
+    if name_node.kind() != NodeKinds::IDENTIFIER {
+        return;
+    }
+    let enum_name = get_name_from_qualifier(&qualifier_node, invocation_data);
+    let is_known_enum = invocation_data.enum_names().contains(&enum_name)
+        || invocation_data.options().enum_names().contains(&enum_name);
+    if !is_known_enum {
+        return;
+    }
+
+    let enumerator_name = unwrap_or_empty_string!(get_node_value(&name_node, invocation_data));
+    let navigation_link = MethodDescription::new(
+        get_package_of_class(&enum_name, invocation_data),
+        enum_name.clone(),
+        get_line_number(node),
+        get_position_in_line(node),
+        enum_name,
+        enumerator_name,
+        0,
+    );
+    invocation_data.mut_navigation_links().push(navigation_link);
+}
+
+/* `a + b`, `out << x`, `!a`, `a += b`, `a[i]` are linked to user-defined operators of operand types */
+fn visit_operator_expression(node: &Node, invocation_data: &mut InvocationData) {
+
+    let (operands, operator) = match node.kind() {
+        NodeKinds::SUBSCRIPT_EXPRESSION => (
+            vec![node.child_by_field_name(NodeNames::ARGUMENT), node.child_by_field_name(NodeNames::INDEX)],
+            KeyWords::SUBSCRIPT.to_string()
+        ),
+        NodeKinds::UNARY_EXPRESSION => (
+            vec![node.child_by_field_name(NodeNames::ARGUMENT)],
+            get_operator_from_expression(node, invocation_data)
+        ),
+        _ => (
+            vec![node.child_by_field_name(NodeNames::LEFT), node.child_by_field_name(NodeNames::RIGHT)],
+            get_operator_from_expression(node, invocation_data)
+        )
+    };
+
+    /* Plain assignment is mostly implicitly generated, it is not linked */
+    if operator.is_empty() || operator == KeyWords::ASSIGNMENT { return; }
+    let method_name = format!("{}{}", KeyWords::OPERATOR, operator);
+    let operand_names: Vec<String> = operands
+        .iter()
+        .map(|operand| match operand {
+            Some(operand) if operand.kind() == NodeKinds::IDENTIFIER
+            => unwrap_or_empty_string!(get_node_value(operand, invocation_data)),
+            _ => KeyWords::EMPTY_STRING.to_string()
+        })
+        .collect();
+
+    /* Member operator of the first operand type, otherwise non-member operator taking all operands */
+    if !operand_names[0].is_empty()
+        && add_link_from_var(node, &operand_names[0], &method_name, operands.len() - 1, invocation_data) {
+        return;
+    }
+    if let Some(operand_name) = operand_names.iter().skip(1).find(|x| !x.is_empty()) {
+        add_link_from_var(node, operand_name, &method_name, operands.len(), invocation_data);
+    }
+}
+
+fn get_operator_from_expression(node: &Node, invocation_data: &InvocationData) -> String {
+    node
+        .children(&mut node.walk())
+        .find(|x| !x.is_named())
+        .and_then(|x| get_node_value(&x, invocation_data))
+        .unwrap_or_default()
+}
+
+fn visit_method_declaration(node: &Node, invocation_data: &mut InvocationData) {
+
+    let kind = node.kind();
+    if kind != NodeKinds::FIELD_DECLARATION && kind != NodeKinds::DECLARATION && kind != NodeKinds::FUNCTION_DEFINITION {
+        return;
+    }
+
+    let class_node = unwrap_or_return!(node.parent().and_then(|x| x.parent()));
+    let class_name_node = unwrap_or_return!(class_node.child_by_field_name(NodeNames::NAME));
+    let declarator = unwrap_or_return!(node.child_by_field_name(NodeNames::DECLARATOR));
+    let function_decl = unwrap_or_return!(find_descendant_by_kind(declarator, NodeKinds::FUNCTION_DECLARATOR));
+    let name_node = unwrap_or_return!(function_decl.child_by_field_name(NodeNames::DECLARATOR));
+    let parameters_node = unwrap_or_return!(function_decl.child_by_field_name(NodeNames::PARAMETERS));
+
+    let is_virtual = node.named_children(&mut node.walk())
+        .any(|x| x.kind() == NodeKinds::VIRTUAL_FUNCTION_SPECIFIER)
+        || function_decl.named_children(&mut function_decl.walk())
+        .any(|x| x.kind() == NodeKinds::VIRTUAL_SPECIFIER);
+
+    let method_signature = MethodSignature {
+        class_name: get_name_from_class_name_node(&class_name_node, invocation_data),
+        method_name: unwrap_or_empty_string!(get_node_value(&name_node, invocation_data)),
+        count_of_params: parameters_node.named_child_count(),
+        is_virtual,
+    };
+    invocation_data.mut_method_signatures().push(method_signature);
+}
+
+/* Calls of deprecated functions are marked, deprecated class deprecates its zero args constructor the same way
+   as on declaration side */
+fn add_deprecated_method(node: &Node, invocation_data: &mut InvocationData) {
+
+    let deprecated_method = match node.kind() {
+        NodeKinds::FUNCTION_DEFINITION | NodeKinds::DECLARATION | NodeKinds::FIELD_DECLARATION => {
+            let declarator = unwrap_or_return!(node.child_by_field_name(NodeNames::DECLARATOR));
+            let function_decl = unwrap_or_return!(find_descendant_by_kind(declarator, NodeKinds::FUNCTION_DECLARATOR));
+            let name_node = unwrap_or_return!(function_decl.child_by_field_name(NodeNames::DECLARATOR));
+            let parameters_node = unwrap_or_return!(function_decl.child_by_field_name(NodeNames::PARAMETERS));
+            if !is_deprecated(node, invocation_data.source_code()) {
+                return;
+            }
+            let (min_count_of_params, max_count_of_params) =
+                get_count_of_params_range(&parameters_node, invocation_data.source_code());
+            DeprecatedMethod::new(
+                get_enclosing_class_name(&function_decl, invocation_data),
+                get_var_from_declarator(&name_node, invocation_data),
+                min_count_of_params,
+                max_count_of_params,
+            )
+        }
+        NodeKinds::CLASS_SPECIFIER | NodeKinds::STRUCT_SPECIFIER | NodeKinds::UNION_SPECIFIER => {
+            let name_node = unwrap_or_return!(node.child_by_field_name(NodeNames::NAME));
+            if !invocation_data.options().synthetic_constructors() || !is_deprecated(node, invocation_data.source_code()) {
+                return;
+            }
+            let class_name = get_name_from_class_name_node(&name_node, invocation_data);
+            DeprecatedMethod::new(class_name.clone(), class_name, 0, Some(0))
+        }
+        _ => return
+    };
+
+    invocation_data.mut_deprecated_methods().push(deprecated_method);
+}
+
+/* Calls through pointer or reference of base class may reach overrides of subclasses. Only classes of the
+   current file are known here, overrides declared in other files are not listed */
+fn add_dispatch_candidates(invocation_data: &mut InvocationData) {
+
+    let mut dispatch_candidates = vec![];
+    for &index in invocation_data.indirect_links.iter() {
+        let link = &invocation_data.links[index];
+        let candidates = get_dispatch_candidates(
+            link.class_name(), link.method_name(), link.count_param_input(), invocation_data
+        );
+        dispatch_candidates.push((index, candidates));
+    }
+
+    for (index, candidates) in dispatch_candidates {
+        invocation_data.mut_navigation_links()[index].set_dispatch_candidates(candidates);
+    }
+}
+
+fn get_dispatch_candidates(class_name: &String, method_name: &String, count_of_params: usize,
+                           invocation_data: &InvocationData) -> Vec<DispatchCandidate> {
+
+    let find_signature = |class_name: &String| invocation_data
+        .method_signatures()
+        .iter()
+        .find(|x| &x.class_name == class_name && &x.method_name == method_name && x.count_of_params == count_of_params);
+
+    let mut candidates: Vec<DispatchCandidate> = vec![];
+    for package_description in invocation_data.package_descriptions() {
+        let subclass_name = package_description.class_name();
+        if candidates.iter().any(|x| x.class_name() == subclass_name) || find_signature(subclass_name).is_none() {
+            continue;
+        }
+        let inheritance_path = unwrap_or_continue!(get_inheritance_path(subclass_name, class_name, invocation_data, &mut vec![]));
+
+        /* Topmost known signature from the base down decides, own `virtual` of override counts only when
+           base and intermediate classes are declared in other files */
+        let is_override = find_signature(class_name)
+            .or_else(|| inheritance_path.iter().rev().find_map(&find_signature))
+            .is_some_and(|x| x.is_virtual);
+        if is_override {
+            candidates.push(DispatchCandidate::new(package_description.get_package_name(), subclass_name.clone()));
+        }
+    }
+
+    candidates
+}
+
+/* Classes from subclass up to direct subclass of base, `None` if class does not derive from base */
+fn get_inheritance_path(class_name: &String, base_class_name: &String, invocation_data: &InvocationData,
+                        visited: &mut Vec<String>) -> Option<Vec<String>> {
+
+    if visited.contains(class_name) { return None; }
+    visited.push(class_name.clone());
+
+    let parents: Vec<&String> = invocation_data
+        .package_descriptions()
+        .iter()
+        .filter(|x| x.class_name() == class_name)
+        .flat_map(|x| x.parents().iter())
+        .collect();
+
+    let mut path = if parents.contains(&base_class_name) {
+        vec![]
+    } else {
+        parents.iter().find_map(|parent| get_inheritance_path(parent, base_class_name, invocation_data, visited))?
+    };
+    path.insert(0, class_name.clone());
+    Some(path)
+}
+
+/* Type alias may itself name pointer or reference type */
+fn set_last_var_indirect(declarator: &Node, indirect_type: bool, invocation_data: &mut InvocationData) {
+
+    /* `Base* base = new Derived()` is init_declarator around pointer_declarator */
+    let declarator = match declarator.kind() {
+        NodeKinds::INIT_DECLARATOR => unwrap_or_return!(declarator.child_by_field_name(NodeNames::DECLARATOR)),
+        _ => *declarator
+    };
+
+    let is_indirect = indirect_type || declarator.kind() == NodeKinds::POINTER_DECLARATOR
+        || declarator.kind() == NodeKinds::REFERENCE_DECLARATOR;
+    if let Some(var_description) = invocation_data.mut_var_descriptions().last_mut() {
+        var_description.set_indirect(is_indirect);
+    }
+}
+
+/* Variable of qualified type `fs::path` belongs to the header of `symbolTable` entry keyed by qualified name,
+   namespace aliases expanded */
+fn set_last_var_package(type_node: &Node, invocation_data: &mut InvocationData) {
+
+    if type_node.kind() != NodeKinds::SCOPED_TYPE_IDENTIFIER {
+        return;
+    }
+    let type_name = unwrap_or_return!(get_node_value(type_node, invocation_data));
+    let qualified_name = expand_namespace_aliases(&type_name, invocation_data.namespace_aliases());
+    if find_declaring_header(&qualified_name, invocation_data).is_none() {
+        return;
+    }
+    let package_name = get_package_of_class(&qualified_name, invocation_data);
+    if let Some(var_description) = invocation_data.mut_var_descriptions().last_mut() {
+        var_description.set_package_name(package_name);
+    }
 }
 
 fn visit_struct_spec_get_name(node: &Node, invocation_data: &mut InvocationData) -> String {
@@ -325,8 +1235,10 @@ fn visit_super_class(node: &Node, invocation_data: &mut InvocationData, child_cl
         let class_line = get_line_number(&child);
         let position = get_position_in_line(&child);
 
+        let package_name = get_package_of_class(&class_name, invocation_data);
+
         let package_description = PackageDescription::new(
-            invocation_data.get_current_package(),
+            package_name.clone(),
             class_name.clone(),
             class_line,
             position,
@@ -336,7 +1248,7 @@ fn visit_super_class(node: &Node, invocation_data: &mut InvocationData, child_cl
         invocation_data.mut_package_descriptions().push(package_description);
 
         let var_description = VarDescription::new(
-            invocation_data.get_current_package(),
+            package_name,
             class_name.clone(),
             class_line,
             position,
@@ -385,7 +1297,7 @@ fn add_var_description(node: &Node, class_name: String, param_name: String, invo
         var_description.set_package_name(package_desc.get_package_name());
         var_description.set_class_name(package_desc.get_class_name());
     } else {
-        var_description.set_package_name(invocation_data.get_current_package());
+        var_description.set_package_name(get_package_of_class(&class_name, invocation_data));
         var_description.set_class_name(class_name);
     }
     var_description.set_line(get_line_number(&node));
@@ -429,7 +1341,7 @@ fn add_link(node: &Node, var_name: &String, method_name: &String, count_of_param
     } else if var_name.contains(':') || var_name.contains('(') {
         navigation_link.set_method_name(method_name.clone()); /* Only method name */
     } else {
-        navigation_link.set_package_name(invocation_data.get_current_package());
+        navigation_link.set_package_name(get_package_of_class(var_name, invocation_data));
         navigation_link.set_class_name(var_name.clone());
         navigation_link.set_var_name(var_name.clone());
         navigation_link.set_method_name(method_name.clone());
@@ -500,6 +1412,77 @@ fn add_links_from_package(node: &Node, var_name: &String, method_name: &String,
 }
 
 /* Helpers */
+fn resolve_include_path(include_path: &str, is_quoted: bool, invocation_data: &InvocationData) -> String {
+
+    /* Quoted includes are searched next to the including file first, then in include directories */
+    let mut candidates = vec![];
+    if is_quoted {
+        candidates.push(join_path(&parent_directory(invocation_data.path()), include_path));
+    }
+    for include_directory in invocation_data.options().include_directories() {
+        candidates.push(join_path(include_directory, include_path));
+    }
+
+    /* Files of symbol table are known to exist in repository */
+    let symbol_table = invocation_data.options().symbol_table();
+    let known_candidate = candidates
+        .iter()
+        .find(|&candidate| symbol_table.values().any(|path| path == candidate));
+
+    if let Some(candidate) = known_candidate {
+        return candidate.clone();
+    }
+
+    /* Unknown include is taken next to the including file if quoted, otherwise from the first include directory.
+       Standard library header `<vector>` has no extension and stays as written */
+    let is_standard_header = !is_quoted && Path::new(include_path).extension().is_none();
+    if candidates.is_empty() || is_standard_header {
+        return normalize_path(include_path);
+    }
+    candidates.swap_remove(0)
+}
+
+/* Header of `symbolTable` entry of class among headers included by the file. Entries are keyed by qualified name,
+   unqualified `Widget` matches `ui::Widget` */
+fn find_declaring_header(class_name: &str, invocation_data: &InvocationData) -> Option<String> {
+
+    let symbol_table = invocation_data.options().symbol_table();
+    invocation_data
+        .import_list()
+        .iter()
+        .map(|x| x.package_name())
+        .filter(|&header_path| header_path != invocation_data.path())
+        .find(|&header_path| symbol_table
+            .iter()
+            .any(|(qualified_name, path)| path == header_path && is_qualified_name_of(qualified_name, class_name)))
+        .cloned()
+}
+
+fn is_qualified_name_of(qualified_name: &str, class_name: &str) -> bool {
+    qualified_name == class_name
+        || qualified_name.strip_suffix(class_name).is_some_and(|x| x.ends_with(KeyWords::SCOPE_SEPARATOR))
+}
+
+fn get_package_of_class(class_name: &str, invocation_data: &mut InvocationData) -> String {
+
+    /* Class which is not declared in this file belongs to the included header declaring it */
+    let header_path = match find_declaring_header(class_name, invocation_data) {
+        Some(header_path) => header_path,
+        None => return invocation_data.get_current_package()
+    };
+
+    let import_declaration_opt = invocation_data
+        .mut_import_list()
+        .iter_mut()
+        .find(|x| x.package_name() == &header_path);
+
+    if let Some(import_declaration) = import_declaration_opt {
+        import_declaration.add_class(class_name.to_string());
+    }
+
+    header_path
+}
+
 fn find_var_desc_by_name<'time>(name: &'time String, invocation_data: &'time InvocationData) -> Option<&'time VarDescription> {
     /* To find latest added variable rev() function is used */
     invocation_data
@@ -646,7 +1629,7 @@ fn get_node_value(node: &Node, invocation_data: &InvocationData) -> Option<Strin
             invocation_data.path())
         );
 
-    if node_string.len() < MAX_TOKEN_LENGTH {
+    if node_string.len() < invocation_data.options().max_token_length() {
         Some(node_string)
     } else {
         None
@@ -718,7 +1701,14 @@ fn get_method_and_var_from_field_expression(node: &Node, invocation_data: &mut I
     let mut var_name = KeyWords::EMPTY_STRING.to_string();
 
     if let Some(field_node) = node.child_by_field_name(NodeNames::FIELD) {
-        method_name = unwrap_or_empty_string!(get_node_value(&field_node, invocation_data));
+        method_name = match field_node.kind() {
+            /* Method template call `obj.get<int>()` */
+            NodeKinds::TEMPLATE_METHOD => match field_node.child_by_field_name(NodeNames::NAME) {
+                Some(name_node) => unwrap_or_empty_string!(get_node_value(&name_node, invocation_data)),
+                None => KeyWords::EMPTY_STRING.to_string()
+            },
+            _ => unwrap_or_empty_string!(get_node_value(&field_node, invocation_data))
+        };
     }
 
     if let Some(argument_node) = node.child_by_field_name(NodeNames::ARGUMENT) {
@@ -726,6 +1716,7 @@ fn get_method_and_var_from_field_expression(node: &Node, invocation_data: &mut I
             NodeKinds::IDENTIFIER => unwrap_or_empty_string!(get_node_value(&argument_node, invocation_data)),
             NodeKinds::TEMPLATE_FUNCTION | NodeKinds::SCOPED_IDENTIFIER
             => get_name_from_template_or_scoped_node(&argument_node, invocation_data),
+            NodeKinds::THIS => KeyWords::THIS.to_string(),
             _ => KeyWords::EMPTY_STRING.to_string()
         }
     }
@@ -733,7 +1724,28 @@ fn get_method_and_var_from_field_expression(node: &Node, invocation_data: &mut I
     return (method_name, var_name);
 }
 
-fn get_name_from_template_or_scoped_node(node: &Node, invocation_data: &mut InvocationData) -> String {
+/* Template arguments of called `make<Foo>` or `obj.get<int>` */
+fn get_template_arguments_from_function(node: &Node, invocation_data: &InvocationData) -> Vec<String> {
+
+    let template_node = match node.kind() {
+        NodeKinds::TEMPLATE_FUNCTION => Some(*node),
+        NodeKinds::FIELD_EXPRESSION => node
+            .child_by_field_name(NodeNames::FIELD)
+            .filter(|field_node| field_node.kind() == NodeKinds::TEMPLATE_METHOD),
+        _ => None
+    };
+
+    let arguments_node = match template_node.and_then(|x| x.child_by_field_name(NodeNames::ARGUMENTS)) {
+        Some(arguments_node) => arguments_node,
+        None => return vec![]
+    };
+    arguments_node
+        .named_children(&mut arguments_node.walk())
+        .map(|argument| unwrap_or_empty_string!(get_node_value(&argument, invocation_data)))
+        .collect()
+}
+
+fn get_name_from_template_or_scoped_node(node: &Node, invocation_data: &InvocationData) -> String {
 
     if let Some(name_node) = node.child_by_field_name(NodeNames::NAME) {
         match name_node.kind() {
@@ -753,6 +1765,23 @@ mod cpp_code_invocation_tests {
     use tree_sitter::Parser;
     use std::fs;
 
+    fn parse_cpp_json(code: &str, path: &str, options: &ParseOptions) -> serde_json::Value {
+        let mut parser = Parser::new();
+        parser.set_language(tree_sitter_cpp::language()).expect("ERROR: Unable to load C++ grammar");
+        let tree = parser.parse(code, None).unwrap();
+        let structure = get_file_structure(code.to_string(), tree, path.to_string(), options);
+        serde_json::to_value(&structure).unwrap()
+    }
+
+    fn find_method<'a>(json: &'a serde_json::Value, method_name: &str) -> &'a serde_json::Value {
+        json["methodDescriptions"].as_array().unwrap().iter().find(|x| x["methodName"] == method_name).unwrap()
+    }
+
+    fn find_link<'a>(json: &'a serde_json::Value, var_name: &str, method_name: &str) -> &'a serde_json::Value {
+        json["methodDescriptions"].as_array().unwrap().iter()
+            .find(|x| x["varName"] == var_name && x["methodName"] == method_name).unwrap()
+    }
+
 
     #[test]
     pub fn test_get_invocation_structure() {
@@ -761,7 +1790,345 @@ mod cpp_code_invocation_tests {
         parser.set_language(tree_sitter_cpp::language()).expect("ERROR: Unable to load C++ grammar");
         let tree = parser.parse(&mut code, None).unwrap();
         println!("{},  ", tree.root_node().to_sexp());
-        let mut structure = get_file_structure(code, tree, "test".to_string());
+        let structure = get_file_structure(code, tree, "test".to_string(), &ParseOptions::default());
         println!("{}", serde_json::to_string_pretty(&structure).unwrap());
     }
+
+    #[test]
+    pub fn test_get_includes() {
+        let code = String::from(
+            "#include \"widget.h\"\n\
+             #include \"../common/log.h\"\n\
+             #include <vector>\n\
+             #include <core/engine.h>\n\
+             void run() {\n\
+                 Widget widget;\n\
+                 widget.draw();\n\
+                 Logger::write();\n\
+             }\n"
+        );
+        let options = ParseOptions::from_json(r#"{
+            "includeDirectories": ["third_party", "include"],
+            "symbolTable": {
+                "ui::Widget": "src/app/widget.h",
+                "core::Engine": "include/core/engine.h",
+                "Logger": "src/common/logger.h"
+            }
+        }"#).unwrap();
+        let json = parse_cpp_json(&code, "src/app/main.cpp", &options);
+
+        let imports: Vec<&serde_json::Value> = json["repositoryImportDeclarations"].as_array().unwrap().iter()
+            .map(|x| &x["packageName"]).collect();
+        assert_eq!(imports, vec!["src/app/main.cpp", "src/app/widget.h", "src/common/log.h",
+                                 "vector", "include/core/engine.h"]);
+        assert_eq!(json["repositoryImportDeclarations"][1]["classImportList"], serde_json::json!(["Widget"]));
+
+        assert_eq!(find_method(&json, "draw")["descriptionData"]["packageName"], "src/app/widget.h");
+        assert_eq!(find_method(&json, "draw")["descriptionData"]["className"], "Widget");
+        /* Header of `Logger` is not included */
+        assert_eq!(find_method(&json, "write")["descriptionData"]["packageName"], "src/app/main.cpp");
+
+        /* Without symbol table `<core/engine.h>` is taken from the first include directory */
+        let options = ParseOptions::from_json(r#"{ "includeDirectories": ["third_party", "include"] }"#).unwrap();
+        let json = parse_cpp_json(&code, "src/app/main.cpp", &options);
+        assert_eq!(json["repositoryImportDeclarations"][4]["packageName"], "third_party/core/engine.h");
+    }
+
+    #[test]
+    pub fn test_get_template_arguments() {
+        let code = String::from(
+            "void run() {\n\
+                 auto widget = make<Widget>(1);\n\
+                 factory::create<Widget, int>();\n\
+                 widget.get<int>();\n\
+                 widget.draw();\n\
+             }\n"
+        );
+        let json = parse_cpp_json(&code, "main.cpp", &ParseOptions::default());
+
+        assert_eq!(find_method(&json, "make")["templateArguments"], serde_json::json!(["Widget"]));
+        assert_eq!(find_method(&json, "create")["templateArguments"], serde_json::json!(["Widget", "int"]));
+        assert_eq!(find_method(&json, "get")["templateArguments"], serde_json::json!(["int"]));
+        assert!(find_method(&json, "draw").get("templateArguments").is_none());
+    }
+
+    #[test]
+    pub fn test_get_operator_links() {
+        let code = String::from(
+            "void run(Vector a, Vector b, int i) {\n\
+                 Vector c = a + b;\n\
+                 std::cout << a;\n\
+                 a += b;\n\
+                 !a;\n\
+                 a[i];\n\
+                 i + i;\n\
+                 a = b;\n\
+             }\n"
+        );
+        let json = parse_cpp_json(&code, "main.cpp", &ParseOptions::default());
+        let links: Vec<(String, String, u64)> = json["methodDescriptions"].as_array().unwrap().iter()
+            .map(|x| (x["descriptionData"]["className"].as_str().unwrap().to_string(),
+                      x["methodName"].as_str().unwrap().to_string(),
+                      x["countParamInput"].as_u64().unwrap()))
+            .collect();
+
+        let expected = vec![("operator+", 1), ("operator<<", 2), ("operator+=", 1), ("operator!", 0), ("operator[]", 1)];
+        for (method_name, count_of_params) in expected {
+            assert!(links.contains(&("Vector".to_string(), method_name.to_string(), count_of_params)), "{}", method_name);
+        }
+        assert!(!links.iter().any(|x| x.1 == "operator="));
+        assert_eq!(links.iter().filter(|x| x.1 == "operator+").count(), 1);
+    }
+
+    #[test]
+    pub fn test_get_dispatch_candidates() {
+        let code = String::from(
+            "class Shape {\n\
+             public:\n\
+                 virtual void draw() = 0;\n\
+                 void move(int x);\n\
+             };\n\
+             class Circle : public Shape { void draw() override; };\n\
+             class Ring : public Circle { void draw(); };\n\
+             class Point : public Shape { void move(int x); };\n\
+             class Spot : public Shape { virtual void move(int x); };\n\
+             class Panel : public View { virtual void paint(); void resize(); };\n\
+             class Toolbar : public Panel { void paint(); virtual void resize(); };\n\
+             void render(Shape* shape, Shape& ref, Circle circle, View* view) {\n\
+                 shape->draw();\n\
+                 ref.draw();\n\
+                 shape->move(1);\n\
+                 circle.draw();\n\
+                 view->paint();\n\
+                 view->resize();\n\
+             }\n"
+        );
+        let json = parse_cpp_json(&code, "shape.cpp", &ParseOptions::default());
+        let candidates = |link: &serde_json::Value| link["dispatchCandidates"].as_array().unwrap().iter()
+            .map(|x| x["className"].as_str().unwrap().to_string()).collect::<Vec<String>>();
+
+        assert_eq!(find_link(&json, "shape", "draw")["descriptionData"]["className"], "Shape");
+        assert_eq!(candidates(find_link(&json, "shape", "draw")), vec!["Circle", "Ring"]);
+        assert_eq!(candidates(find_link(&json, "ref", "draw")), vec!["Circle", "Ring"]);
+        assert_eq!(find_link(&json, "shape", "draw")["dispatchCandidates"][0]["packageName"], "shape.cpp");
+        /* Non-virtual method is hidden, not overridden, even by method marked virtual in subclass */
+        assert!(find_link(&json, "shape", "move").get("dispatchCandidates").is_none());
+        assert!(find_link(&json, "circle", "draw").get("dispatchCandidates").is_none());
+        /* Base declared in other file, topmost known signature decides */
+        assert_eq!(candidates(find_link(&json, "view", "paint")), vec!["Panel", "Toolbar"]);
+        assert!(find_link(&json, "view", "resize").get("dispatchCandidates").is_none());
+    }
+
+    #[test]
+    pub fn test_get_type_aliases() {
+        let code = String::from(
+            "namespace geo {\n\
+                 class Shape { public: virtual void draw(); void scale(); };\n\
+                 class Circle : public Shape { public: void draw() override; };\n\
+             }\n\
+             typedef geo::Circle Round;\n\
+             using ShapePtr = geo::Shape*;\n\
+             using Id = int;\n\
+             using Ring = Round;\n\
+             void run(Round round, ShapePtr shape, Id id) {\n\
+                 round.scale();\n\
+                 shape->draw();\n\
+                 id + id;\n\
+                 {\n\
+                     using Round = geo::Shape;\n\
+                     Round inner;\n\
+                     inner.scale();\n\
+                 }\n\
+                 Round outer;\n\
+                 outer.scale();\n\
+                 Ring ring;\n\
+                 ring.scale();\n\
+             }\n"
+        );
+        let json = parse_cpp_json(&code, "shape.cpp", &ParseOptions::default());
+
+        assert_eq!(find_link(&json, "round", "scale")["descriptionData"]["className"], "Circle");
+        assert_eq!(find_link(&json, "shape", "draw")["descriptionData"]["className"], "Shape");
+        assert_eq!(find_link(&json, "shape", "draw")["dispatchCandidates"][0]["className"], "Circle");
+        assert!(!json["methodDescriptions"].as_array().unwrap().iter().any(|x| x["methodName"] == "operator+"));
+        /* Alias of inner block hides the outer one only inside the block */
+        assert_eq!(find_link(&json, "inner", "scale")["descriptionData"]["className"], "Shape");
+        assert_eq!(find_link(&json, "outer", "scale")["descriptionData"]["className"], "Circle");
+        assert_eq!(find_link(&json, "ring", "scale")["descriptionData"]["className"], "Circle");
+    }
+
+    #[test]
+    pub fn test_get_namespace_alias_links() {
+        let code = String::from(
+            "#include <filesystem>\n\
+             namespace fs = std::filesystem;\n\
+             namespace sfs = fs;\n\
+             void list(sfs::path dir) {\n\
+                 fs::path file;\n\
+                 file.filename();\n\
+                 dir.stem();\n\
+             }\n"
+        );
+        let options = ParseOptions::from_json(r#"{ "symbolTable": { "std::filesystem::path": "filesystem" } }"#).unwrap();
+        let json = parse_cpp_json(&code, "list.cpp", &options);
+
+        assert_eq!(find_link(&json, "file", "filename")["descriptionData"]["className"], "path");
+        assert_eq!(find_link(&json, "file", "filename")["descriptionData"]["packageName"], "filesystem");
+        assert_eq!(find_link(&json, "dir", "stem")["descriptionData"]["className"], "std::filesystem::path");
+        assert_eq!(find_link(&json, "dir", "stem")["descriptionData"]["packageName"], "filesystem");
+    }
+
+    #[test]
+    pub fn test_get_wrapped_and_auto_types() {
+        let code = String::from(
+            "class Foo { public: virtual void bar(); };\n\
+             class Baz : public Foo { public: void bar() override; };\n\
+             using FooPtr = std::shared_ptr<Foo>;\n\
+             void run(std::unique_ptr<Foo> param, const std::optional<Foo>& maybe) {\n\
+                 std::shared_ptr<Foo> shared;\n\
+                 FooPtr aliased;\n\
+                 auto made = std::make_unique<Foo>(1);\n\
+                 auto created = Foo(2);\n\
+                 auto braced = Foo{3};\n\
+                 auto* allocated = new Foo();\n\
+                 auto other = compute();\n\
+                 param->bar();\n\
+                 maybe->bar();\n\
+                 shared->bar();\n\
+                 aliased->bar();\n\
+                 made->bar();\n\
+                 created.bar();\n\
+                 braced.bar();\n\
+                 allocated->bar();\n\
+                 other.bar();\n\
+             }\n"
+        );
+        let json = parse_cpp_json(&code, "foo.cpp", &ParseOptions::default());
+        let links = json["methodDescriptions"].as_array().unwrap();
+
+        for var_name in ["param", "maybe", "shared", "aliased", "made", "created", "braced", "allocated"] {
+            assert_eq!(find_link(&json, var_name, "bar")["descriptionData"]["className"], "Foo", "{}", var_name);
+        }
+        /* Smart pointers dispatch dynamically, values do not */
+        for var_name in ["param", "maybe", "shared", "aliased", "made", "allocated"] {
+            assert_eq!(find_link(&json, var_name, "bar")["dispatchCandidates"][0]["className"], "Baz", "{}", var_name);
+        }
+        assert!(find_link(&json, "created", "bar").get("dispatchCandidates").is_none());
+        assert!(find_link(&json, "braced", "bar").get("dispatchCandidates").is_none());
+        assert_ne!(find_link(&json, "other", "bar")["descriptionData"]["className"], "Foo");
+        /* Wrapper is not constructed with zero args */
+        assert!(!links.iter().any(|x| x["methodName"] == "unique_ptr" || x["methodName"] == "shared_ptr"));
+    }
+
+    #[test]
+    pub fn test_get_lambda_links() {
+        let code = String::from(
+            "class Widget { public: void run(); void draw(); };\n\
+             class Other { public: void draw(); };\n\
+             void Widget::run() {\n\
+                 auto cb = [this](int x) { this->draw(); };\n\
+                 cb(3);\n\
+             }\n\
+             void free_run() {\n\
+                 std::function<void()> task = []() {};\n\
+                 task();\n\
+             }\n\
+             namespace geo {\n\
+                 void plot() { auto step = []() {}; step(); }\n\
+             }\n\
+             void declare() { auto handler = []() {}; }\n\
+             void invoke(std::function<void()> handler) { handler(); }\n"
+        );
+        let json = parse_cpp_json(&code, "foo.cpp", &ParseOptions::default());
+        let links = json["methodDescriptions"].as_array().unwrap();
+
+        assert_eq!(find_method(&json, "cb")["descriptionData"]["className"], "Widget");
+        assert_eq!(find_method(&json, "cb")["countParamInput"], 1);
+        assert_eq!(find_method(&json, "task")["descriptionData"]["className"], "");
+        /* Lambda of function in namespace belongs to the namespace, as on declaration side */
+        assert_eq!(find_method(&json, "step")["descriptionData"]["className"], "geo");
+        /* Lambda is visible only in the function declaring it, `handler` of `invoke` is its parameter */
+        assert_eq!(find_method(&json, "handler")["varName"], "");
+        /* Call inside lambda body belongs to the enclosing function, not to the last declared class */
+        assert_eq!(find_method(&json, "draw")["descriptionData"]["className"], "Widget");
+        /* Lambda variable is neither a var nor constructed */
+        assert!(!links.iter().any(|x| x["methodName"] == "function"));
+    }
+
+    #[test]
+    pub fn test_get_enumerator_links() {
+        let code = String::from(
+            "#include <mode.h>\n\
+             #include <config.h>\n\
+             enum class Color { Red, Blue };\n\
+             class Widget { public: static Widget create(); void paint(Color color); };\n\
+             void Widget::paint(Color color) {\n\
+                 auto current = Color::Red;\n\
+                 paint(gfx::Color::Blue);\n\
+                 Widget::create();\n\
+                 auto mode = Mode::Dark;\n\
+                 auto verbose = Config::verbose;\n\
+                 std::cout << Color::Red;\n\
+             }\n"
+        );
+        let options = ParseOptions::from_json(r#"{
+            "includeDirectories": ["include"],
+            "symbolTable": { "Mode": "include/mode.h", "Config": "include/config.h" },
+            "enumNames": ["Mode"]
+        }"#).unwrap();
+        let json = parse_cpp_json(&code, "foo.cpp", &options);
+        let links = json["methodDescriptions"].as_array().unwrap();
+        let enumerator_links = |enumerator: &str| links.iter()
+            .filter(|x| x["methodName"] == enumerator && x["countParamInput"] == 0).cloned().collect::<Vec<_>>();
+
+        assert_eq!(enumerator_links("Red").len(), 2);
+        assert_eq!(enumerator_links("Red")[0]["descriptionData"]["className"], "Color");
+        assert_eq!(enumerator_links("Blue")[0]["descriptionData"]["className"], "Color");
+        assert_eq!(enumerator_links("Dark")[0]["descriptionData"]["packageName"], "include/mode.h");
+        /* Qualified function definition and static call are not enumerators */
+        assert!(!links.iter().any(|x| x["methodName"] == "paint" && x["countParamInput"] == 0));
+        assert_eq!(links.iter().filter(|x| x["methodName"] == "create").count(), 1);
+        /* Static member of a class is not an enumerator */
+        assert!(!links.iter().any(|x| x["methodName"] == "verbose"));
+    }
+
+    #[test]
+    pub fn test_skip_inactive_branches() {
+        let code = String::from(
+            "#define USE_GL\n\
+             void run() {\n\
+             #ifdef _WIN32\n\
+                 open_win32();\n\
+             #elif defined(__linux__)\n\
+                 open_linux();\n\
+             #else\n\
+                 open_other();\n\
+             #endif\n\
+             #if LEVEL > 1 && 0\n\
+                 trace();\n\
+             #endif\n\
+             #undef USE_GL\n\
+             #ifdef USE_GL\n\
+                 draw_gl();\n\
+             #endif\n\
+                 draw();\n\
+             }\n"
+        );
+        let parse = |options: &ParseOptions| parse_cpp_json(&code, "test.cpp", options)["methodDescriptions"]
+            .as_array().unwrap().iter()
+            .map(|x| x["methodName"].as_str().unwrap().to_string())
+            .collect::<Vec<String>>();
+
+        /* Unknown configuration keeps every branch */
+        let all = parse(&ParseOptions::default());
+        assert_eq!(all, vec!["open_win32", "open_linux", "open_other", "trace", "draw_gl", "draw"]);
+
+        let options = ParseOptions::from_json(r#"{ "preprocessor": { "definedMacros": { "__linux__": "" } } }"#).unwrap();
+        assert_eq!(parse(&options), vec!["open_linux", "draw"]);
+
+        let options = ParseOptions::from_json(r#"{
+            "preprocessor": { "definedMacros": { "__linux__": "" }, "skipInactiveBranches": false }
+        }"#).unwrap();
+        assert_eq!(parse(&options), all);
+    }
 }
@@ -5,13 +5,21 @@ use tree_sitter::{Node, Tree};
 use std::io::Read;
 use crate::unwrap_or_return;
 use crate::unwrap_or_empty_string;
+use crate::dto::documentation::Documentation;
+use crate::dto::visibility::Visibility;
+use crate::dto::modifier::Modifier;
+use crate::utils::doc_comment::parse_doc_comment;
+use crate::utils::java_deprecation::{get_doc_comment, has_deprecated_annotation};
+use crate::dto::parse_options::ParseOptions;
 
-const MAX_TOKEN_LENGTH: usize = 250;
 
 struct KeyWords;
 impl KeyWords {
     const VOID: &'static str = "void";
     const EMPTY_STRING:&'static str = "";
+    const PUBLIC: &'static str = "public";
+    const PROTECTED: &'static str = "protected";
+    const PRIVATE: &'static str = "private";
 }
 
 struct NodeKinds;
@@ -28,6 +36,8 @@ impl NodeKinds {
     const FORMAL_PARAMETERS: &'static str = "formal_parameters";
     const VARIABLE_DECLARATOR: &'static str = "variable_declarator";
     const MODIFIERS: &'static str = "modifiers";
+    const INTERFACE_BODY: &'static str = "interface_body";
+    const ANNOTATION_TYPE_BODY: &'static str = "annotation_type_body";
 }
 
 struct NodeNames;
@@ -40,17 +50,23 @@ impl NodeNames {
 struct ClassData {
     source_code: String,
     path: String,
+    options: ParseOptions,
 }
 
 impl ClassData {
 
-    fn new(source_code: String, path_file: String) -> ClassData {
+    fn new(source_code: String, path_file: String, options: ParseOptions) -> ClassData {
         ClassData {
             source_code,
             path: path_file,
+            options,
         }
     }
 
+    fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+
     fn source_code(&self) -> &String {
         &self.source_code
     }
@@ -62,10 +78,10 @@ impl ClassData {
 
 
 /* Main function */
-pub fn get_repository_method_dto(source_code: String, tree: Tree, path: String, rep_id: i32) -> Vec<RepositoryMethodDto> {
+pub fn get_repository_method_dto(source_code: String, tree: Tree, path: String, rep_id: i32, options: &ParseOptions) -> Vec<RepositoryMethodDto> {
 
     let node = tree.root_node();
-    let mut class_data = ClassData::new(source_code, path.clone());
+    let mut class_data = ClassData::new(source_code, path.clone(), options.clone());
     let mut data = find_package_declaration(&mut class_data, &node);
 
     parse_node(&mut data, &node, &mut class_data);
@@ -108,6 +124,10 @@ fn add_constructor_declaration(node: &Node, class_data: &mut ClassData, parent:
     constructor_object.set_line_code(get_node_position(&name_node));
     constructor_object.set_parameters(get_parameters_from_node(&node, class_data));
     constructor_object.set_modifiers(get_modifiers_from_node(&node, class_data));
+    constructor_object.set_visibility(get_visibility_from_node(&node, class_data));
+    constructor_object.set_documentation(get_documentation_from_node(&node, class_data));
+    constructor_object.set_body(get_body_from_node(&node, class_data));
+    set_deprecation_from_node(&mut constructor_object, &node, class_data);
     let java_object: Box<dyn JavaObject> = Box::new(constructor_object);
 
     parent.add_child(java_object);
@@ -122,6 +142,10 @@ fn add_method_declaration(node: &Node, class_data: &mut ClassData, parent: &mut
     method_object.set_line_code(get_node_position(&name_node));
     method_object.set_parameters(get_parameters_from_node(&node, class_data));
     method_object.set_modifiers(get_modifiers_from_node(&node, class_data));
+    method_object.set_visibility(get_visibility_from_node(&node, class_data));
+    method_object.set_documentation(get_documentation_from_node(&node, class_data));
+    method_object.set_body(get_body_from_node(&node, class_data));
+    set_deprecation_from_node(&mut method_object, &node, class_data);
 
     if let Some(output_param) = get_output_param_from_node(&node, class_data) {
         method_object.set_output_parameter(output_param);
@@ -137,12 +161,15 @@ fn add_class_declaration(node: &Node, class_data: &mut ClassData, parent: &mut B
     let class_name = unwrap_or_empty_string!(get_node_value(&node_name, class_data));
     let line_position = get_node_position(&node_name);
     let modifiers = get_modifiers_from_node(&node, class_data);
+    let visibility = get_visibility_from_node(&node, class_data);
 
     let mut class_object = ClassObject::new(class_name.clone());
     class_object.set_line_code(line_position);
     class_object.set_type_code(JAVA_CLASS);
     class_object.set_modifiers(modifiers.clone());
-    let mut java_class_object: Box<dyn JavaObject> = Box::new(class_object);
+    class_object.set_visibility(visibility);
+    class_object.set_documentation(get_documentation_from_node(&node, class_data));
+    set_deprecation_from_node(&mut class_object, &node, class_data);
 
     /* Zero arg constructor */
     let mut constructor_object = MethodObject::default();
@@ -150,9 +177,16 @@ fn add_class_declaration(node: &Node, class_data: &mut ClassData, parent: &mut B
     constructor_object.set_type_code(JAVA_CONSTRUCTOR);
     constructor_object.set_line_code(line_position);
     constructor_object.set_modifiers(modifiers);
+    constructor_object.set_visibility(visibility);
+    constructor_object.set_deprecated(class_object.deprecated());
+    constructor_object.set_deprecation_message(class_object.deprecation_message().clone());
+
+    let mut java_class_object: Box<dyn JavaObject> = Box::new(class_object);
     let java_constr_object: Box<dyn JavaObject> = Box::new(constructor_object);
 
-    java_class_object.add_child(java_constr_object);
+    if class_data.options().synthetic_constructors() {
+        java_class_object.add_child(java_constr_object);
+    }
 
     if let Some(class_body) = node.child_by_field_name(NodeNames::BODY) {
         parse_node(& mut java_class_object, &class_body, class_data);
@@ -170,6 +204,9 @@ fn add_interface_declaration(node: &Node, class_data: &mut ClassData, parent: &m
     let mut class_object = ClassObject::new(name);
     class_object.set_line_code(get_node_position(&node_name));
     class_object.set_type_code(JAVA_INTERFACE);
+    class_object.set_visibility(get_visibility_from_node(&node, class_data));
+    class_object.set_documentation(get_documentation_from_node(&node, class_data));
+    set_deprecation_from_node(&mut class_object, &node, class_data);
 
     let mut java_object: Box<dyn JavaObject> = Box::new(class_object);
 
@@ -189,6 +226,9 @@ fn add_enum_declaration(node: &Node, class_data: &mut ClassData, parent: &mut Bo
     let mut class_object = ClassObject::new(name);
     class_object.set_line_code(get_node_position(&node_name));
     class_object.set_type_code(JAVA_ENUM);
+    class_object.set_visibility(get_visibility_from_node(&node, class_data));
+    class_object.set_documentation(get_documentation_from_node(&node, class_data));
+    set_deprecation_from_node(&mut class_object, &node, class_data);
     let mut java_object: Box<dyn JavaObject> = Box::new(class_object);
 
     if let Some(enum_body) = node.child_by_field_name(NodeNames::BODY) {
@@ -215,6 +255,7 @@ fn find_package_declaration(class_data: &mut ClassData, root_node: &Node) -> Box
                 let mut package_object = PackageObject::new();
                 package_object.set_name(scoped_identifier_value);
                 package_object.set_line_code(get_node_position(&node));
+                package_object.set_documentation(get_documentation_from_node(&node, class_data));
                 return Box::new(package_object);
             }
         }
@@ -224,6 +265,7 @@ fn find_package_declaration(class_data: &mut ClassData, root_node: &Node) -> Box
                 let mut package_object = PackageObject::new();
                 package_object.set_name(identifier_value);
                 package_object.set_line_code(get_node_position(&node));
+                package_object.set_documentation(get_documentation_from_node(&node, class_data));
                 return Box::new(package_object);
             }
         }
@@ -245,9 +287,13 @@ fn prepare_output_data(dto_vec: &mut Vec<RepositoryMethodDto>, java_object: Box<
     }
 }
 
-fn prepare_package_object(dto_vec: &mut Vec<RepositoryMethodDto>, java_object: Box<dyn JavaObject>,
+fn prepare_package_object(dto_vec: &mut Vec<RepositoryMethodDto>, mut java_object: Box<dyn JavaObject>,
                           rep_id: i32, path: &String, class_name: &String) {
 
+    let visibility = java_object.visibility();
+    let documentation = java_object.take_documentation();
+    let deprecated = java_object.deprecated();
+    let deprecation_message = java_object.take_deprecation_message();
     let boxed_any = java_object.to_any();
     let boxed_package = boxed_any.downcast::<PackageObject>().unwrap();
     let package_object = *boxed_package;
@@ -260,7 +306,7 @@ fn prepare_package_object(dto_vec: &mut Vec<RepositoryMethodDto>, java_object: B
         modifiers
     ) = package_object.take();
 
-    let repository_method_dto = RepositoryMethodDto::new(
+    let mut repository_method_dto = RepositoryMethodDto::new(
         rep_id,
         path.clone(),
         name.clone(),
@@ -272,6 +318,11 @@ fn prepare_package_object(dto_vec: &mut Vec<RepositoryMethodDto>, java_object: B
         type_code.to_string(),
         0,
     );
+    repository_method_dto.set_modifiers(Modifier::from_keywords(&modifiers));
+    repository_method_dto.set_visibility(visibility);
+    repository_method_dto.set_documentation(documentation);
+    repository_method_dto.set_deprecated(deprecated);
+    repository_method_dto.set_deprecation_message(deprecation_message);
 
     dto_vec.push(repository_method_dto);
 
@@ -280,9 +331,13 @@ fn prepare_package_object(dto_vec: &mut Vec<RepositoryMethodDto>, java_object: B
     }
 }
 
-fn prepare_class_object(dto_vec: &mut Vec<RepositoryMethodDto>, java_object: Box<dyn JavaObject>,
+fn prepare_class_object(dto_vec: &mut Vec<RepositoryMethodDto>, mut java_object: Box<dyn JavaObject>,
                         rep_id: i32, path: &String, package_name: &String) {
 
+    let visibility = java_object.visibility();
+    let documentation = java_object.take_documentation();
+    let deprecated = java_object.deprecated();
+    let deprecation_message = java_object.take_deprecation_message();
     let boxed_any = java_object.to_any();
     let boxed_class = boxed_any.downcast::<ClassObject>().unwrap();
     let class_object = *boxed_class;
@@ -295,7 +350,7 @@ fn prepare_class_object(dto_vec: &mut Vec<RepositoryMethodDto>, java_object: Box
         modifiers,
     ) = class_object.take();
 
-    let repository_method_dto = RepositoryMethodDto::new(
+    let mut repository_method_dto = RepositoryMethodDto::new(
         rep_id,
         path.clone(),
         package_name.clone(),
@@ -307,6 +362,11 @@ fn prepare_class_object(dto_vec: &mut Vec<RepositoryMethodDto>, java_object: Box
         type_code.to_string(),
         0,
     );
+    repository_method_dto.set_modifiers(Modifier::from_keywords(&modifiers));
+    repository_method_dto.set_visibility(visibility);
+    repository_method_dto.set_documentation(documentation);
+    repository_method_dto.set_deprecated(deprecated);
+    repository_method_dto.set_deprecation_message(deprecation_message);
 
     dto_vec.push(repository_method_dto);
 
@@ -316,9 +376,14 @@ fn prepare_class_object(dto_vec: &mut Vec<RepositoryMethodDto>, java_object: Box
     
 }
 
-fn prepare_method_object(dto_vec: &mut Vec<RepositoryMethodDto>, java_object: Box<dyn JavaObject>,
+fn prepare_method_object(dto_vec: &mut Vec<RepositoryMethodDto>, mut java_object: Box<dyn JavaObject>,
                          rep_id: i32, path: &String, package_name: &String, class_name: &String) {
 
+    let visibility = java_object.visibility();
+    let documentation = java_object.take_documentation();
+    let deprecated = java_object.deprecated();
+    let deprecation_message = java_object.take_deprecation_message();
+    let body = java_object.take_body();
     let boxed_any = java_object.to_any();
     let boxed_method = boxed_any.downcast::<MethodObject>().unwrap();
     let method_object = *boxed_method;
@@ -333,18 +398,23 @@ fn prepare_method_object(dto_vec: &mut Vec<RepositoryMethodDto>, java_object: Bo
         _output_param,
     ) = method_object.take();
 
-    let repository_method_dto = RepositoryMethodDto::new(
+    let mut repository_method_dto = RepositoryMethodDto::new(
         rep_id,
         path.clone(),
         package_name.clone(),
         line_code,
         class_name.clone(),
         name,
-        KeyWords::EMPTY_STRING.to_string(),
+        body.unwrap_or_default(),
         serde_json::to_string(&modifiers).unwrap_or(KeyWords::EMPTY_STRING.to_string()),
         type_code.to_string(),
         parameters.len(),
     );
+    repository_method_dto.set_modifiers(Modifier::from_keywords(&modifiers));
+    repository_method_dto.set_visibility(visibility);
+    repository_method_dto.set_documentation(documentation);
+    repository_method_dto.set_deprecated(deprecated);
+    repository_method_dto.set_deprecation_message(deprecation_message);
 
     dto_vec.push(repository_method_dto);
 
@@ -364,13 +434,28 @@ fn get_node_value(node: &Node, class_data: &mut ClassData) -> Option<String> {
         .read_to_string(&mut node_string)
         .expect(&format!("RUST UNRECOVERABLE ERROR: Unable to read source code. Path file: {}", class_data.path_file()));
 
-    if node_string.len() < MAX_TOKEN_LENGTH {
+    if node_string.len() < class_data.options().max_token_length() {
         Some(node_string)
     } else {
         None
     }
 }
 
+fn get_node_text(node: &Node, class_data: &ClassData) -> String {
+
+    let source = class_data.source_code();
+    source[node.start_byte()..node.end_byte()].to_string()
+}
+
+fn get_body_from_node(node: &Node, class_data: &ClassData) -> Option<String> {
+
+    if !class_data.options().capture_body() {
+        return None;
+    }
+
+    Some(get_node_text(node, class_data))
+}
+
 /* Helpers */
 fn get_child_node_by_kind<'time_spec>(node: &'time_spec Node, kind: &'time_spec str) -> Option<Node<'time_spec>> {
     node
@@ -425,6 +510,55 @@ fn get_output_param_from_node(node: &Node, class_data: &mut ClassData) -> Option
     return None;
 }
 
+fn get_documentation_from_node(node: &Node, class_data: &ClassData) -> Option<Documentation> {
+
+    /* Doc comment also carries deprecation, skip it only when both extractors are disabled */
+    let extractors = class_data.options().extractors();
+    if !extractors.documentation() && !extractors.deprecation() {
+        return None;
+    }
+
+    get_doc_comment(node, class_data.source_code()).map(|comment| parse_doc_comment(&comment))
+}
+
+fn set_deprecation_from_node(java_object: &mut dyn JavaObject, node: &Node, class_data: &mut ClassData) {
+
+    if !class_data.options().extractors().deprecation() {
+        return;
+    }
+
+    /* @deprecated javadoc tag carries the message, @Deprecated annotation only marks the declaration */
+    let doc_message = java_object
+        .documentation()
+        .as_ref()
+        .and_then(|documentation| documentation.deprecated().clone());
+
+    let deprecated = doc_message.is_some() || has_deprecated_annotation(node, class_data.source_code());
+
+    java_object.set_deprecated(deprecated);
+    java_object.set_deprecation_message(doc_message.filter(|message| !message.is_empty()));
+}
+
+fn get_visibility_from_node(node: &Node, class_data: &mut ClassData) -> Visibility {
+
+    for modifier in get_modifiers_from_node(node, class_data) {
+        match modifier.as_str() {
+            KeyWords::PUBLIC => return Visibility::Public,
+            KeyWords::PROTECTED => return Visibility::Protected,
+            KeyWords::PRIVATE => return Visibility::Private,
+            &_ => {}
+        }
+    }
+
+    /* Interface members are implicitly public, enum constructors are implicitly private */
+    match node.parent().map(|parent| parent.kind()) {
+        Some(NodeKinds::INTERFACE_BODY) | Some(NodeKinds::ANNOTATION_TYPE_BODY) => Visibility::Public,
+        Some(NodeKinds::ENUM_BODY_DECLARATIONS) if node.kind() == NodeKinds::CONSTRUCTOR_DECLARATION
+        => Visibility::Private,
+        _ => Visibility::Package
+    }
+}
+
 fn get_modifiers_from_node(node: &Node, class_data: &mut ClassData) -> Vec<String> {
 
     let mut modifiers = vec![];
@@ -448,6 +582,21 @@ mod java_code_declaration_tests {
     use std::fs;
     use crate::dto::invocation_structure::InvocationStructure;
 
+    fn parse_java(code: &str, options: &ParseOptions) -> Vec<RepositoryMethodDto> {
+        let mut parser = Parser::new();
+        parser.set_language(tree_sitter_java::language()).expect("ERROR: Unable to load Java grammar");
+        let tree = parser.parse(code, None).unwrap();
+        get_repository_method_dto(code.to_string(), tree, "test".to_string(), 0, options)
+    }
+
+    fn parse_java_json(code: &str) -> Vec<serde_json::Value> {
+        parse_java(code, &ParseOptions::default()).iter().map(|x| serde_json::to_value(x).unwrap()).collect()
+    }
+
+    fn find_method<'a>(json: &'a [serde_json::Value], method_name: &str) -> &'a serde_json::Value {
+        json.iter().find(|x| x["methodName"] == method_name).unwrap()
+    }
+
 
     #[test]
     pub fn test_get_repository_method_dto() {
@@ -458,7 +607,7 @@ mod java_code_declaration_tests {
         println!("{},  ", tree.root_node().to_sexp());
         let mut repository_method_dto = get_repository_method_dto(code,
                                                                   tree, "test".to_string(),
-                                                                  0);
+                                                                  0, &ParseOptions::default());
         for dto in repository_method_dto {
             println!("{}", serde_json::to_string_pretty(&dto).unwrap())
         }
@@ -470,6 +619,153 @@ mod java_code_declaration_tests {
         name: String
     }
 
+    #[test]
+    pub fn test_get_documentation() {
+        let code = String::from(
+            "package test;\n\
+             /**\n\
+              * Simple calculator.\n\
+              */\n\
+             public class Calculator {\n\
+                 /**\n\
+                  * Adds two numbers. Result may overflow.\n\
+                  * @param a first number\n\
+                  * @param b second number\n\
+                  * @return sum of numbers\n\
+                  */\n\
+                 @Override\n\
+                 public int add(int a, int b) { return a + b; }\n\
+                 /* Not a javadoc */\n\
+                 public int sub(int a, int b) { return a - b; }\n\
+             }"
+        );
+        let json = parse_java_json(&code);
+
+        let class_dto = json.iter().find(|x| x["type"] == "JAVA_CLASS").unwrap();
+        assert_eq!(class_dto["documentation"]["summary"], "Simple calculator.");
+
+        let add_dto = find_method(&json, "add");
+        assert_eq!(add_dto["documentation"]["summary"], "Adds two numbers.");
+        assert_eq!(add_dto["documentation"]["params"][1]["name"], "b");
+        assert_eq!(add_dto["documentation"]["params"][1]["description"], "second number");
+        assert_eq!(add_dto["documentation"]["returns"], "sum of numbers");
+
+        let sub_dto = find_method(&json, "sub");
+        assert!(sub_dto.get("documentation").is_none());
+    }
+
+    #[test]
+    pub fn test_get_deprecation() {
+        let code = String::from(
+            "package test;\n\
+             @Deprecated\n\
+             public class Legacy {\n\
+                 /** @deprecated use {@link #sum} */\n\
+                 public int add(int a, int b) { return a + b; }\n\
+                 @java.lang.Deprecated(since = \"9\")\n\
+                 public int sub(int a, int b) { return a - b; }\n\
+                 public int sum(int a, int b) { return a + b; }\n\
+             }"
+        );
+        let json = parse_java_json(&code);
+        let find = |method_type: &str, method_name: &str| json.iter()
+            .find(|x| x["type"] == method_type && x["methodName"] == method_name).unwrap().clone();
 
-}
+        assert_eq!(find("JAVA_CLASS", "")["deprecated"], true);
+        assert_eq!(find("JAVA_CONSTRUCTOR", "Legacy")["deprecated"], true);
+
+        let add_dto = find("JAVA_METHOD", "add");
+        assert_eq!(add_dto["deprecated"], true);
+        assert_eq!(add_dto["deprecationMessage"], "use {@link #sum}");
 
+        let sub_dto = find("JAVA_METHOD", "sub");
+        assert_eq!(sub_dto["deprecated"], true);
+        assert!(sub_dto.get("deprecationMessage").is_none());
+
+        assert_eq!(find("JAVA_METHOD", "sum")["deprecated"], false);
+    }
+
+    #[test]
+    pub fn test_get_visibility() {
+        let code = String::from(
+            "package test;\n\
+             class Account {\n\
+                 public void deposit(int amount) {}\n\
+                 protected void audit() {}\n\
+                 private void lock() {}\n\
+                 void reset() {}\n\
+             }\n\
+             interface Storage {\n\
+                 void save();\n\
+             }\n\
+             enum State {\n\
+                 OPEN;\n\
+                 State() {}\n\
+             }"
+        );
+        let json = parse_java_json(&code);
+        let find = |method_type: &str, class_name: &str, method_name: &str| json.iter()
+            .find(|x| x["type"] == method_type && x["className"] == class_name && x["methodName"] == method_name)
+            .unwrap()["visibility"].clone();
+
+        assert_eq!(find("JAVA_PACKAGE", "", ""), "public");
+        assert_eq!(find("JAVA_CLASS", "Account", ""), "package");
+        assert_eq!(find("JAVA_METHOD", "Account", "deposit"), "public");
+        assert_eq!(find("JAVA_METHOD", "Account", "audit"), "protected");
+        assert_eq!(find("JAVA_METHOD", "Account", "lock"), "private");
+        assert_eq!(find("JAVA_METHOD", "Account", "reset"), "package");
+        assert_eq!(find("JAVA_METHOD", "Storage", "save"), "public");
+        assert_eq!(find("JAVA_CONSTRUCTOR", "State", "State"), "private");
+    }
+
+    #[test]
+    pub fn test_get_modifiers() {
+        let code = String::from(
+            "package test;\n\
+             public abstract class Shape {\n\
+                 @Override\n\
+                 public final synchronized String toString() { return \"\"; }\n\
+                 protected static native int count();\n\
+             }"
+        );
+        let mut dto_vec = parse_java(&code, &ParseOptions::default());
+        let json = serde_json::to_value(&dto_vec).unwrap();
+        let find = |json: &serde_json::Value, method_name: &str| json.as_array().unwrap().iter()
+            .find(|x| x["methodName"] == method_name).unwrap()["modifiers"].clone();
+
+        assert_eq!(find(&json, "toString"), serde_json::json!(["override", "final", "synchronized"]));
+        assert_eq!(find(&json, "count"), serde_json::json!(["static", "native"]));
+
+        for dto in dto_vec.iter_mut() {
+            dto.use_legacy_modifiers();
+        }
+        let legacy_json = serde_json::to_value(&dto_vec).unwrap();
+        assert_eq!(find(&legacy_json, "count"), "[\"protected\",\"static\",\"native\"]");
+    }
+
+    #[test]
+    pub fn test_parse_options() {
+        let code = String::from(
+            "package test;\n\
+             public class Worker {\n\
+                 /** Runs job. */\n\
+                 public void run() { work(); }\n\
+                 public void aVeryLongMethodNameExceedingLimit() {}\n\
+             }"
+        );
+        let options = ParseOptions::from_json(r#"{
+            "captureBody": true,
+            "syntheticConstructors": false,
+            "maxTokenLength": 20,
+            "extractors": { "documentation": false, "deprecation": false }
+        }"#).unwrap();
+        let json = serde_json::to_value(parse_java(&code, &options)).unwrap();
+        let methods: Vec<&serde_json::Value> = json.as_array().unwrap().iter()
+            .filter(|x| x["methodName"] != "").collect();
+
+        assert_eq!(methods.len(), 1);
+        assert_eq!(methods[0]["methodName"], "run");
+        assert_eq!(methods[0]["blobData"], "public void run() { work(); }");
+        assert!(methods[0]["documentation"].is_null());
+    }
+}
@@ -1,12 +1,13 @@
 use tree_sitter::{Tree, Node};
-use crate::dto::invocation_structure::{InvocationStructure, RepositoryImportDeclaration};
+use crate::dto::invocation_structure::{DeprecatedMethod, InvocationStructure, RepositoryImportDeclaration};
 use crate::dto::object_description::{MethodDescription, PackageDescription, VarDescription, Description};
 use std::io::Read;
 use crate::model::java_object::{CodeType};
 use crate::unwrap_or_return;
 use crate::unwrap_or_empty_string;
+use crate::dto::parse_options::ParseOptions;
+use crate::utils::java_deprecation::{get_count_of_params_range, is_deprecated};
 
-const MAX_TOKEN_LENGTH: usize = 250;
 
 struct NodeKinds;
 
@@ -65,13 +66,16 @@ struct InvocationData {
     package_descriptions: Vec<PackageDescription>,
     var_descriptions: Vec<VarDescription>,
     links: Vec<MethodDescription>,
+    /* Deprecated methods and constructors of deprecated classes declared in this file */
+    deprecated_methods: Vec<DeprecatedMethod>,
     current_package: String,
     source_code: String,
     path: String,
+    options: ParseOptions,
 }
 
 impl InvocationData {
-    fn new(source_code: String, path: String) -> Self {
+    fn new(source_code: String, path: String, options: ParseOptions) -> Self {
         Self {
             current_package: "".to_string(),
             source_code,
@@ -80,9 +84,15 @@ impl InvocationData {
             package_descriptions: vec![],
             var_descriptions: vec![],
             links: vec![],
+            deprecated_methods: vec![],
+            options,
         }
     }
 
+    fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+
     fn set_current_package(&mut self, current_package: String) {
         self.current_package = current_package;
     }
@@ -119,6 +129,10 @@ impl InvocationData {
         &mut self.links
     }
 
+    fn mut_deprecated_methods(&mut self) -> &mut Vec<DeprecatedMethod> {
+        &mut self.deprecated_methods
+    }
+
     fn mut_import_list(&mut self) -> &mut Vec<RepositoryImportDeclaration> {
         &mut self.import_declarations
     }
@@ -127,30 +141,34 @@ impl InvocationData {
         self.import_declarations.iter().position(|x| x == import_decl)
     }
 
-    fn take(self) -> (Vec<RepositoryImportDeclaration>, Vec<MethodDescription>) {
-        (self.import_declarations, self.links)
+    fn take(self) -> (Vec<RepositoryImportDeclaration>, Vec<MethodDescription>, Vec<DeprecatedMethod>) {
+        (self.import_declarations, self.links, self.deprecated_methods)
     }
 }
 
 /* Main */
-pub fn get_file_structure(source_code: String, tree: Tree, path: String) -> InvocationStructure {
+pub fn get_file_structure(source_code: String, tree: Tree, path: String, options: &ParseOptions) -> InvocationStructure {
 
     let node = tree.root_node();
-    let mut invocation_data = InvocationData::new(source_code, path.clone());
+    let mut invocation_data = InvocationData::new(source_code, path.clone(), options.clone());
     add_package_declaration(&mut invocation_data, &node);
 
     parse_root_node(&node, &mut invocation_data);
 
     let (
         repository_import_declarations,
-        method_descriptions
+        method_descriptions,
+        deprecated_methods
     ) = invocation_data.take();
 
-    return InvocationStructure::new(
+    let mut invocation_structure = InvocationStructure::new(
         repository_import_declarations,
         method_descriptions,
         CodeType::type_codes(),
     );
+    invocation_structure.mark_deprecated_links(&deprecated_methods);
+
+    return invocation_structure;
 }
 
 fn parse_root_node(node: &Node, invocation_data: &mut InvocationData) {
@@ -262,11 +280,41 @@ fn add_field_declaration(node: &Node, invocation_data: &mut InvocationData, pare
 fn add_method_or_constr_declaration(node: &Node, invocation_data: &mut InvocationData, class_name: String) {
 
     let parameters = unwrap_or_return!(node.child_by_field_name(NodeNames::PARAMETERS));
+    if invocation_data.options().extractors().deprecation() {
+        add_deprecated_method(node, &parameters, &class_name, invocation_data);
+    }
     add_parameters(&parameters, invocation_data);
     let body = unwrap_or_return!(node.child_by_field_name(NodeNames::BODY));
     parse_node(&body, invocation_data, class_name);
 }
 
+/* Calls of deprecated methods and constructors are marked, overloads differ by number of parameters */
+fn add_deprecated_method(node: &Node, parameters: &Node, class_name: &str, invocation_data: &mut InvocationData) {
+
+    let name_node = unwrap_or_return!(node.child_by_field_name(NodeNames::NAME));
+    if !is_deprecated(node, invocation_data.source_code()) {
+        return;
+    }
+
+    let method_name = unwrap_or_empty_string!(get_node_value(&name_node, invocation_data));
+    let (min_count_of_params, max_count_of_params) = get_count_of_params_range(parameters);
+    invocation_data.mut_deprecated_methods().push(
+        DeprecatedMethod::new(class_name.to_string(), method_name, min_count_of_params, max_count_of_params)
+    );
+}
+
+/* Deprecated class deprecates its zero args constructor the same way as on declaration side */
+fn add_deprecated_constructor(node: &Node, class_name: &str, invocation_data: &mut InvocationData) {
+
+    if !invocation_data.options().synthetic_constructors() || !is_deprecated(node, invocation_data.source_code()) {
+        return;
+    }
+
+    invocation_data.mut_deprecated_methods().push(
+        DeprecatedMethod::new(class_name.to_string(), class_name.to_string(), 0, Some(0))
+    );
+}
+
 fn add_method_invocation(node: &Node, invocation_data: &mut InvocationData, class_name: String) {
 
     let name_node = unwrap_or_return!(node.child_by_field_name(NodeNames::NAME));
@@ -367,6 +415,10 @@ fn add_class_or_interface_declaration(node: &Node, invocation_data: &mut Invocat
     invocation_data.mut_var_descriptions().push(var_description);
     invocation_data.mut_package_descriptions().push(package_description);
 
+    if node.kind() == NodeKinds::CLASS_DECLARATION && invocation_data.options().extractors().deprecation() {
+        add_deprecated_constructor(node, &class_name, invocation_data);
+    }
+
     let class_body = unwrap_or_return!(node.child_by_field_name(NodeNames::BODY));
     parse_class_body(&class_body, invocation_data, class_name);
 }
@@ -693,7 +745,7 @@ fn get_node_value(node: &Node, invocation_data: &InvocationData) -> Option<Strin
             invocation_data.path())
         );
 
-    if node_string.len() < MAX_TOKEN_LENGTH {
+    if node_string.len() < invocation_data.options().max_token_length() {
         Some(node_string)
     } else {
         None
@@ -768,7 +820,7 @@ mod java_code_declaration_tests {
         let tree = parser.parse(&mut code, None).unwrap();
         println!("{},  ", tree.root_node().to_sexp());
         let mut repository_method_dto = get_file_structure(code,
-                                                           tree, "test".to_string());
+                                                           tree, "test".to_string(), &ParseOptions::default());
 
 
         println!("{}", serde_json::to_string_pretty(&repository_method_dto).unwrap());
@@ -5,8 +5,13 @@ use crate::unwrap_or_return;
 use std::io::Read;
 use crate::unwrap_or_empty_string;
 use crate::model::js_object::CodeType::{JS_METHOD, JS_CONSTRUCTOR, JS_CLASS, JS_PACKAGE};
+use crate::dto::documentation::Documentation;
+use crate::dto::visibility::Visibility;
+use crate::dto::modifier::Modifier;
+use crate::utils::doc_comment::parse_doc_comment;
+use crate::utils::js_deprecation::get_doc_comment;
+use crate::dto::parse_options::ParseOptions;
 
-const MAX_TOKEN_LENGTH: usize = 250;
 
 struct NodeKinds;
 
@@ -19,6 +24,8 @@ impl NodeKinds {
     const ARROW_FUNCTION: &'static str = "arrow_function";
     const IDENTIFIER: &'static str = "identifier";
     const FUNCTION: &'static str = "function";
+    const PRIVATE_PROPERTY_IDENTIFIER: &'static str = "private_property_identifier";
+    const ERROR: &'static str = "ERROR";
 }
 
 struct NodeNames;
@@ -37,21 +44,29 @@ struct KeyWords;
 impl KeyWords {
     const CONSTRUCTOR_IDENTIFIER: &'static str = "constructor";
     const EMPTY_STRING: &'static str = "";
+    const PRIVATE_NAME_PREFIX: &'static str = "#";
+    const MODIFIER_KEYWORDS: [&'static str; 5] = ["static", "async", "get", "set", "*"];
 }
 
 struct DeclarationData {
     source_code: String,
     path_file: String,
+    options: ParseOptions,
 }
 
 impl DeclarationData {
-    pub fn new(source_code: String, path_file: String) -> DeclarationData {
+    pub fn new(source_code: String, path_file: String, options: ParseOptions) -> DeclarationData {
         DeclarationData {
             source_code,
             path_file,
+            options,
         }
     }
 
+    fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+
     fn source_code(&self) -> &String {
         &self.source_code
     }
@@ -65,9 +80,9 @@ impl DeclarationData {
 
 
 /* Main function */
-pub fn get_repository_method_dto(source: String, tree: Tree, path: String, rep_id: i32) -> Vec<RepositoryMethodDto> {
+pub fn get_repository_method_dto(source: String, tree: Tree, path: String, rep_id: i32, options: &ParseOptions) -> Vec<RepositoryMethodDto> {
 
-    let mut parse_data = DeclarationData::new(source, path.clone());
+    let mut parse_data = DeclarationData::new(source, path.clone(), options.clone());
     let package_object = PackageObject::new_name(path.clone());
     let mut parent: Box<dyn JsObject> = Box::new(package_object);
 
@@ -126,9 +141,14 @@ fn prepare_output_data(dto_vec: &mut Vec<RepositoryMethodDto>, js_object: Box<dy
     }
 }
 
-fn prepare_package_object(dto_vec: &mut Vec<RepositoryMethodDto>, js_object: Box<dyn JsObject>,
+fn prepare_package_object(dto_vec: &mut Vec<RepositoryMethodDto>, mut js_object: Box<dyn JsObject>,
                           rep_id: i32, path: &String, class_name: &String) {
 
+    let modifiers = js_object.take_modifiers();
+    let visibility = js_object.visibility();
+    let documentation = js_object.take_documentation();
+    let deprecated = js_object.deprecated();
+    let deprecation_message = js_object.take_deprecation_message();
     let boxed_any = js_object.to_any();
     let boxed_package = boxed_any.downcast::<PackageObject>().unwrap();
     let package = *boxed_package;
@@ -139,7 +159,7 @@ fn prepare_package_object(dto_vec: &mut Vec<RepositoryMethodDto>, js_object: Box
         children) = package.take();
 
 
-    let repository_method_dto = RepositoryMethodDto::new(
+    let mut repository_method_dto = RepositoryMethodDto::new(
         rep_id,
         path.clone(),
         name.clone(),
@@ -151,6 +171,11 @@ fn prepare_package_object(dto_vec: &mut Vec<RepositoryMethodDto>, js_object: Box
         type_code.to_string(),
         0,
     );
+    repository_method_dto.set_modifiers(Modifier::from_keywords(&modifiers));
+    repository_method_dto.set_visibility(visibility);
+    repository_method_dto.set_documentation(documentation);
+    repository_method_dto.set_deprecated(deprecated);
+    repository_method_dto.set_deprecation_message(deprecation_message);
 
     dto_vec.push(repository_method_dto);
     for child in children {
@@ -158,9 +183,14 @@ fn prepare_package_object(dto_vec: &mut Vec<RepositoryMethodDto>, js_object: Box
     }
 }
 
-fn prepare_class_object(dto_vec: &mut Vec<RepositoryMethodDto>, js_object: Box<dyn JsObject>,
+fn prepare_class_object(dto_vec: &mut Vec<RepositoryMethodDto>, mut js_object: Box<dyn JsObject>,
                         rep_id: i32, path: &String, package_name: &String) {
 
+    let modifiers = js_object.take_modifiers();
+    let visibility = js_object.visibility();
+    let documentation = js_object.take_documentation();
+    let deprecated = js_object.deprecated();
+    let deprecation_message = js_object.take_deprecation_message();
     let boxed_any = js_object.to_any();
     let boxed_class = boxed_any.downcast::<ClassObject>().unwrap();
     let class_object = *boxed_class;
@@ -172,7 +202,7 @@ fn prepare_class_object(dto_vec: &mut Vec<RepositoryMethodDto>, js_object: Box<d
         children
     ) = class_object.take();
 
-    let repository_method_dto = RepositoryMethodDto::new(
+    let mut repository_method_dto = RepositoryMethodDto::new(
         rep_id,
         path.clone(),
         package_name.clone(),
@@ -184,6 +214,11 @@ fn prepare_class_object(dto_vec: &mut Vec<RepositoryMethodDto>, js_object: Box<d
         type_code.to_string(),
         0,
     );
+    repository_method_dto.set_modifiers(Modifier::from_keywords(&modifiers));
+    repository_method_dto.set_visibility(visibility);
+    repository_method_dto.set_documentation(documentation);
+    repository_method_dto.set_deprecated(deprecated);
+    repository_method_dto.set_deprecation_message(deprecation_message);
 
     dto_vec.push(repository_method_dto);
 
@@ -192,9 +227,15 @@ fn prepare_class_object(dto_vec: &mut Vec<RepositoryMethodDto>, js_object: Box<d
     }
 }
 
-fn prepare_method_object(dto_vec: &mut Vec<RepositoryMethodDto>, js_object: Box<dyn JsObject>,
+fn prepare_method_object(dto_vec: &mut Vec<RepositoryMethodDto>, mut js_object: Box<dyn JsObject>,
                          rep_id: i32, path: &String, class_name: &String, package_name: &String) {
 
+    let modifiers = js_object.take_modifiers();
+    let visibility = js_object.visibility();
+    let documentation = js_object.take_documentation();
+    let deprecated = js_object.deprecated();
+    let deprecation_message = js_object.take_deprecation_message();
+    let body = js_object.take_body();
     let boxed_any = js_object.to_any();
     let boxed_method = boxed_any.downcast::<MethodObject>().unwrap();
     let method_object = *boxed_method;
@@ -208,18 +249,23 @@ fn prepare_method_object(dto_vec: &mut Vec<RepositoryMethodDto>, js_object: Box<
     ) = method_object.take();
 
 
-    let repository_method_dto = RepositoryMethodDto::new(
+    let mut repository_method_dto = RepositoryMethodDto::new(
         rep_id,
         path.clone(),
         package_name.clone(),
         line_code,
         class_name.clone(),
         name,
-        KeyWords::EMPTY_STRING.to_string(),
+        body.unwrap_or_default(),
         serde_json::to_string(&Vec::<String>::new()).unwrap(),
         type_code.to_string(),
         parameters.len(),
     );
+    repository_method_dto.set_modifiers(Modifier::from_keywords(&modifiers));
+    repository_method_dto.set_visibility(visibility);
+    repository_method_dto.set_documentation(documentation);
+    repository_method_dto.set_deprecated(deprecated);
+    repository_method_dto.set_deprecation_message(deprecation_message);
 
     dto_vec.push(repository_method_dto);
 
@@ -248,14 +294,21 @@ fn add_class_declaration(parent: &mut Box<dyn JsObject>, node: Node, declaration
 
     let mut class_object = ClassObject::new_name(name.clone());
     class_object.set_line_code(line_code_class);
-    let mut class_java_object: Box<dyn JsObject> = Box::new(class_object);
+    class_object.set_documentation(get_documentation_from_node(&node, declaration_data));
+    set_deprecation_from_documentation(&mut class_object, declaration_data);
 
     let mut method_object = MethodObject::new(name.clone());
     method_object.set_line_code(line_code_class);
     method_object.set_type_code(JS_CONSTRUCTOR);
+    method_object.set_deprecated(class_object.deprecated());
+    method_object.set_deprecation_message(class_object.deprecation_message().clone());
+
+    let mut class_java_object: Box<dyn JsObject> = Box::new(class_object);
     let method_java_object: Box<dyn JsObject> = Box::new(method_object);
 
-    class_java_object.add_child(method_java_object);
+    if declaration_data.options().synthetic_constructors() {
+        class_java_object.add_child(method_java_object);
+    }
 
     if let Some(body_node) = node.child_by_field_name(NodeNames::BODY) {
         for child in body_node.named_children(&mut body_node.walk()) {
@@ -289,6 +342,10 @@ fn add_function_declaration(parent: &mut Box<dyn JsObject>, node: Node, declarat
     let mut function_object = MethodObject::new(name);
     function_object.set_line_code(line);
     function_object.set_parameters(parameters);
+    function_object.set_modifiers(get_modifiers_from_node(&node));
+    function_object.set_documentation(get_documentation_from_node(&node, declaration_data));
+    function_object.set_body(get_body_from_node(&node, declaration_data));
+    set_deprecation_from_documentation(&mut function_object, declaration_data);
 
     let java_object: Box<dyn JsObject> = Box::new(function_object);
     parent.add_child(java_object);
@@ -315,6 +372,10 @@ fn add_arrow_function(parent: &mut Box<dyn JsObject>, node: Node, class_data: &m
     let mut function_object = MethodObject::new(name);
     function_object.set_line_code(line);
     function_object.set_parameters(parameters);
+    function_object.set_modifiers(get_modifiers_from_node(&node));
+    function_object.set_documentation(get_documentation_from_node(&node, class_data));
+    function_object.set_body(get_body_from_node(&node, class_data));
+    set_deprecation_from_documentation(&mut function_object, class_data);
 
     let java_object: Box<dyn JsObject> = Box::new(function_object);
     parent.add_child(java_object);
@@ -349,6 +410,7 @@ fn add_function_from_variable(parent: &mut Box<dyn JsObject>, node: Node, declar
 
     let (function_name, function_line_code) = function_name_line;
     let (var_name, var_line_code) = var_name_line;
+    let documentation = get_documentation_from_node(&node, declaration_data);
 
     /* Parameters (common) */
     let parameters = match value_node.child_by_field_name(NodeNames::PARAMETERS) {
@@ -365,6 +427,10 @@ fn add_function_from_variable(parent: &mut Box<dyn JsObject>, node: Node, declar
         let mut var_object = MethodObject::new(var_name);
         var_object.set_line_code(var_line_code);
         var_object.set_parameters(parameters.clone());
+        var_object.set_modifiers(get_modifiers_from_node(&value_node));
+        var_object.set_documentation(documentation.clone());
+        var_object.set_body(get_body_from_node(&node, declaration_data));
+        set_deprecation_from_documentation(&mut var_object, declaration_data);
         let js_object: Box<dyn JsObject> = Box::new(var_object);
         parent.add_child(js_object);
     }
@@ -373,6 +439,10 @@ fn add_function_from_variable(parent: &mut Box<dyn JsObject>, node: Node, declar
         let mut function_object = MethodObject::new(function_name);
         function_object.set_line_code(function_line_code);
         function_object.set_parameters(parameters);
+        function_object.set_modifiers(get_modifiers_from_node(&value_node));
+        function_object.set_documentation(documentation);
+        function_object.set_body(get_body_from_node(&node, declaration_data));
+        set_deprecation_from_documentation(&mut function_object, declaration_data);
         let js_object: Box<dyn JsObject> = Box::new(function_object);
         parent.add_child(js_object);
     }
@@ -396,6 +466,11 @@ fn add_method_definition(parent: &mut Box<dyn JsObject>, node: Node, class_data:
     let mut method_object = MethodObject::new_code(name, type_code);
     method_object.set_line_code(get_node_position(&node));
     method_object.set_parameters(parameters);
+    method_object.set_modifiers(get_modifiers_from_node(&node));
+    method_object.set_visibility(get_visibility_from_name_node(&name_node, class_data));
+    method_object.set_documentation(get_documentation_from_node(&node, class_data));
+    method_object.set_body(get_body_from_node(&node, class_data));
+    set_deprecation_from_documentation(&mut method_object, class_data);
     let js_object: Box<dyn JsObject> = Box::new(method_object);
     parent.add_child(js_object);
 }
@@ -413,13 +488,79 @@ fn get_node_value(node: &Node, parse_data: &mut DeclarationData) -> Option<Strin
         .read_to_string(&mut node_string)
         .expect(&format!("RUST UNRECOVERABLE ERROR: Unable to read source code. Path file: {}", parse_data.path_file()));
 
-    if node_string.len() < MAX_TOKEN_LENGTH {
+    if node_string.len() < parse_data.options().max_token_length() {
         Some(node_string)
     } else {
         None
     }
 }
 
+fn get_body_from_node(node: &Node, parse_data: &DeclarationData) -> Option<String> {
+
+    if !parse_data.options().capture_body() {
+        return None;
+    }
+
+    let source = parse_data.source_code();
+    Some(source[node.start_byte()..node.end_byte()].to_string())
+}
+
+fn get_documentation_from_node(node: &Node, parse_data: &mut DeclarationData) -> Option<Documentation> {
+
+    /* Doc comment also carries deprecation, skip it only when both extractors are disabled */
+    let extractors = parse_data.options().extractors();
+    if !extractors.documentation() && !extractors.deprecation() {
+        return None;
+    }
+
+    get_doc_comment(node, parse_data.source_code()).map(|comment| parse_doc_comment(&comment))
+}
+
+fn get_modifiers_from_node(node: &Node) -> Vec<String> {
+
+    /* Keywords before name: static async *gen(), get value(), async function */
+    node
+        .children(&mut node.walk())
+        .filter(|x| KeyWords::MODIFIER_KEYWORDS.contains(&x.kind()))
+        .map(|x| x.kind().to_string())
+        .collect()
+}
+
+fn get_visibility_from_name_node(node: &Node, parse_data: &mut DeclarationData) -> Visibility {
+
+    if node.kind() == NodeKinds::PRIVATE_PROPERTY_IDENTIFIER {
+        return Visibility::Private;
+    }
+
+    /* ES2022 #private members: grammar without `private_property_identifier` reports `#` as an ERROR node
+       before the name (`get #size()`) or before the whole member (`#inc()`) */
+    let marker_node = node.prev_sibling().or_else(|| node.parent().and_then(|x| x.prev_sibling()));
+    let is_private_marker = marker_node.is_some_and(|x| x.kind() == NodeKinds::ERROR
+        && &parse_data.source_code()[x.start_byte()..x.end_byte()] == KeyWords::PRIVATE_NAME_PREFIX);
+
+    if is_private_marker {
+        Visibility::Private
+    } else {
+        Visibility::Public
+    }
+}
+
+fn set_deprecation_from_documentation(js_object: &mut dyn JsObject, parse_data: &DeclarationData) {
+
+    if !parse_data.options().extractors().deprecation() {
+        return;
+    }
+
+    /* @deprecated JSDoc tag */
+    let message = js_object
+        .documentation()
+        .as_ref()
+        .and_then(|documentation| documentation.deprecated().clone());
+
+    js_object.set_deprecated(message.is_some());
+    js_object.set_deprecation_message(message.filter(|message| !message.is_empty()));
+}
+
 fn get_params_from_node(node: &Node, parse_data: &mut DeclarationData) -> Vec<String> {
 
     let mut parameters = vec![];
@@ -457,6 +598,18 @@ mod js_code_declaration_tests {
     use tree_sitter::Parser;
     use std::fs;
 
+    fn parse_js_json(code: &str) -> Vec<serde_json::Value> {
+        let mut parser = Parser::new();
+        parser.set_language(tree_sitter_javascript::language()).expect("ERROR: Unable to load JavaScript grammar");
+        let tree = parser.parse(code, None).unwrap();
+        get_repository_method_dto(code.to_string(), tree, "test".to_string(), 0, &ParseOptions::default())
+            .iter().map(|x| serde_json::to_value(x).unwrap()).collect()
+    }
+
+    fn find_method<'a>(json: &'a [serde_json::Value], method_name: &str) -> &'a serde_json::Value {
+        json.iter().find(|x| x["methodName"] == method_name).unwrap()
+    }
+
     #[test]
     pub fn test_get_repository_method_dto() {
         let mut code = fs::read_to_string("../../resources/test_files/js/1.js.txt").unwrap();
@@ -470,9 +623,109 @@ mod js_code_declaration_tests {
         println!("{}", tree.root_node().to_sexp());
         let method_dto = get_repository_method_dto(code,
                                                    tree, "test".to_string(),
-                                                   0);
+                                                   0, &ParseOptions::default());
 
         println!("{}", serde_json::to_string_pretty(&method_dto).unwrap());
         assert_eq!(method_dto.len(), 21);
     }
+
+    #[test]
+    pub fn test_get_documentation() {
+        let code = String::from(
+            "/** Shopping cart. */\n\
+             export class Cart {\n\
+                 /**\n\
+                  * Adds item to the cart.\n\
+                  * @param {Item} item - item to add\n\
+                  */\n\
+                 add(item) {}\n\
+             }\n\
+             /**\n\
+              * Formats price.\n\
+              * @deprecated use Intl.NumberFormat\n\
+              */\n\
+             const format = (price) => price.toFixed(2);\n\
+             // Regular comment\n\
+             function helper() {}"
+        );
+        let json = parse_js_json(&code);
+
+        let class_dto = json.iter().find(|x| x["type"] == "JS_CLASS").unwrap();
+        assert_eq!(class_dto["documentation"]["summary"], "Shopping cart.");
+
+        let add_dto = find_method(&json, "add");
+        assert_eq!(add_dto["documentation"]["params"][0]["name"], "item");
+        assert_eq!(add_dto["documentation"]["params"][0]["description"], "item to add");
+
+        let format_dto = find_method(&json, "format");
+        assert_eq!(format_dto["documentation"]["summary"], "Formats price.");
+        assert_eq!(format_dto["documentation"]["deprecated"], "use Intl.NumberFormat");
+
+        let helper_dto = find_method(&json, "helper");
+        assert!(helper_dto.get("documentation").is_none());
+    }
+
+    #[test]
+    pub fn test_get_deprecation() {
+        let code = String::from(
+            "/** @deprecated */\n\
+             class OldWidget {}\n\
+             /**\n\
+              * Renders widget.\n\
+              * @deprecated since 2.0, use renderWidget\n\
+              */\n\
+             function render() {}\n\
+             function renderWidget() {}"
+        );
+        let json = parse_js_json(&code);
+        let find = |method_type: &str, method_name: &str| json.iter()
+            .find(|x| x["type"] == method_type && x["methodName"] == method_name).unwrap().clone();
+
+        assert_eq!(find("JS_CLASS", "")["deprecated"], true);
+        assert_eq!(find("JS_CONSTRUCTOR", "OldWidget")["deprecated"], true);
+        assert_eq!(find("JS_METHOD", "render")["deprecated"], true);
+        assert_eq!(find("JS_METHOD", "render")["deprecationMessage"], "since 2.0, use renderWidget");
+        assert_eq!(find("JS_METHOD", "renderWidget")["deprecated"], false);
+    }
+
+    #[test]
+    pub fn test_get_visibility() {
+        let code = String::from(
+            "class Counter {\n\
+                 #increment(step) { return step; }\n\
+                 value() { return 0; }\n\
+                 static #create() { return 0; }\n\
+                 reset() {}\n\
+             }\n\
+             function createCounter() {}"
+        );
+        let json = parse_js_json(&code);
+        let find = |method_name: &str| find_method(&json, method_name)["visibility"].clone();
+
+        assert_eq!(find("increment"), "private");
+        assert_eq!(find("value"), "public");
+        assert_eq!(find("create"), "private");
+        assert_eq!(find("reset"), "public");
+        assert_eq!(find("createCounter"), "public");
+    }
+
+    #[test]
+    pub fn test_get_modifiers() {
+        let code = String::from(
+            "class Stream {\n\
+                 static async *read() {}\n\
+                 get size() { return 0; }\n\
+             }\n\
+             async function load() {}\n\
+             const parse = async function parseText(text) {};"
+        );
+        let json = parse_js_json(&code);
+        let find = |method_name: &str| find_method(&json, method_name)["modifiers"].clone();
+
+        assert_eq!(find("read"), serde_json::json!(["static", "async", "generator"]));
+        assert_eq!(find("size"), serde_json::json!(["getter"]));
+        assert_eq!(find("load"), serde_json::json!(["async"]));
+        assert_eq!(find("parse"), serde_json::json!(["async"]));
+        assert_eq!(find("parseText"), serde_json::json!(["async"]));
+    }
 }
@@ -1,29 +1,39 @@
 use crate::dto::object_description::{MethodDescription, Description};
-use crate::dto::invocation_structure::{RepositoryImportDeclaration, InvocationStructure};
+use crate::dto::invocation_structure::{DeprecatedMethod, RepositoryImportDeclaration, InvocationStructure};
 use tree_sitter::{Node, Tree, TreeCursor};
 use std::io::Read;
 use crate::unwrap_or_return;
 use crate::unwrap_or_empty_string;
 use crate::model::js_object::{CodeType};
+use crate::dto::parse_options::ParseOptions;
+use crate::utils::js_deprecation::{get_count_of_params_range, is_deprecated};
 
-const MAX_TOKEN_LENGTH: usize = 250;
 
 struct ClassData {
     source_code: String,
     current_package: String,
     current_parent_class: String,
+    /* Deprecated functions and constructors of deprecated classes declared in this file */
+    deprecated_methods: Vec<DeprecatedMethod>,
+    options: ParseOptions,
 }
 
 impl ClassData {
 
-    pub fn new(source_code: String, current_package: String) -> ClassData {
+    pub fn new(source_code: String, current_package: String, options: ParseOptions) -> ClassData {
         ClassData {
             source_code,
             current_package,
             current_parent_class: String::new(),
+            deprecated_methods: vec![],
+            options,
         }
     }
 
+    fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+
     fn source_code(&self) -> &String {
         &self.source_code
     }
@@ -44,6 +54,14 @@ impl ClassData {
         &self.current_package
     }
 
+    fn deprecated_methods(&self) -> &Vec<DeprecatedMethod> {
+        &self.deprecated_methods
+    }
+
+    fn mut_deprecated_methods(&mut self) -> &mut Vec<DeprecatedMethod> {
+        &mut self.deprecated_methods
+    }
+
 }
 
 struct NodeKinds;
@@ -53,6 +71,11 @@ impl NodeKinds {
     const CALL_EXPRESSION: &'static str = "call_expression";
     const MEMBER_EXPRESSION: &'static str = "member_expression";
     const IDENTIFIER: &'static str = "identifier";
+    const FUNCTION_DECLARATION: &'static str = "function_declaration";
+    const ARROW_FUNCTION: &'static str = "arrow_function";
+    const VARIABLE_DECLARATOR: &'static str = "variable_declarator";
+    const METHOD_DEFINITION: &'static str = "method_definition";
+    const FUNCTION: &'static str = "function";
 }
 
 struct NodeNames;
@@ -62,6 +85,10 @@ impl NodeNames {
     const OBJECT: &'static str = "object";
     const SUPER: &'static str = "super";
     const ARGUMENTS: &'static str = "arguments";
+    const NAME: &'static str = "name";
+    const VALUE: &'static str = "value";
+    const PARAMETERS: &'static str = "parameters";
+    const PARAMETER: &'static str = "parameter";
 }
 
 struct KeyWords;
@@ -70,18 +97,21 @@ impl KeyWords {
     const REQUIRE: &'static str = "require";
     const DEFINE: &'static str = "define";
     const EMPTY_STRING: &'static str = "";
+    const CONSTRUCTOR_IDENTIFIER: &'static str = "constructor";
 }
 
 /* Main function */
-pub fn get_file_structure(source_code: String, tree: Tree, path: String) -> InvocationStructure {
+pub fn get_file_structure(source_code: String, tree: Tree, path: String, options: &ParseOptions) -> InvocationStructure {
 
-    let mut class_data = ClassData::new(source_code, path);
+    let mut class_data = ClassData::new(source_code, path, options.clone());
     let mut navigation_links: Vec<MethodDescription> = vec![];
     let import_list: Vec<RepositoryImportDeclaration> = vec![];
 
     traverse_tree(tree.walk(), &mut navigation_links, &mut class_data);
 
-    InvocationStructure::new(import_list, navigation_links, CodeType::type_codes())
+    let mut invocation_structure = InvocationStructure::new(import_list, navigation_links, CodeType::type_codes());
+    invocation_structure.mark_deprecated_links(class_data.deprecated_methods());
+    invocation_structure
 }
 
 fn traverse_tree(mut tree_cursor: TreeCursor, navigation_links: &mut Vec<MethodDescription>, class_data: &mut ClassData) {
@@ -110,6 +140,9 @@ fn traverse_tree(mut tree_cursor: TreeCursor, navigation_links: &mut Vec<MethodD
 }
 
 fn walk_into(node: Node, navigation_links: &mut Vec<MethodDescription>, class_data: &mut ClassData) {
+    if class_data.options().extractors().deprecation() {
+        add_deprecated_method(node, class_data);
+    }
     match node.kind() {
         NodeKinds::CLASS_DECLARATION => add_class_declaration(node, class_data),
         NodeKinds::CALL_EXPRESSION => add_call_expression(node, navigation_links, class_data),
@@ -125,6 +158,73 @@ fn add_class_declaration(node: Node, class_data: &mut ClassData) {
     class_data.set_parent_class(parent_class_name);
 }
 
+/* Calls of deprecated functions are marked, deprecated class deprecates its zero args constructor the same way
+   as on declaration side. Functions outside of classes have empty class name */
+fn add_deprecated_method(node: Node, class_data: &mut ClassData) {
+
+    let (class_name, method_names, parameters_node) = match node.kind() {
+        NodeKinds::FUNCTION_DECLARATION => {
+            let name_node = unwrap_or_return!(node.child_by_field_name(NodeNames::NAME));
+            let name = unwrap_or_return!(get_node_value(&name_node, class_data));
+            (KeyWords::EMPTY_STRING.to_string(), vec![name], node.child_by_field_name(NodeNames::PARAMETERS))
+        }
+        NodeKinds::ARROW_FUNCTION => {
+            let parent_node = unwrap_or_return!(node.parent());
+            if parent_node.kind() != NodeKinds::VARIABLE_DECLARATOR { return; }
+            let name_node = unwrap_or_return!(parent_node.child_by_field_name(NodeNames::NAME));
+            let name = unwrap_or_return!(get_node_value(&name_node, class_data));
+            (KeyWords::EMPTY_STRING.to_string(), vec![name], node.child_by_field_name(NodeNames::PARAMETERS))
+        }
+        NodeKinds::VARIABLE_DECLARATOR => {
+            /* Both variable and function expression names are declared */
+            let value_node = unwrap_or_return!(node.child_by_field_name(NodeNames::VALUE));
+            if value_node.kind() != NodeKinds::FUNCTION { return; }
+            let names = [node.child_by_field_name(NodeNames::NAME), value_node.child_by_field_name(NodeNames::NAME)]
+                .iter()
+                .flatten()
+                .filter_map(|name_node| get_node_value(name_node, class_data))
+                .collect();
+            (KeyWords::EMPTY_STRING.to_string(), names, value_node.child_by_field_name(NodeNames::PARAMETERS))
+        }
+        NodeKinds::METHOD_DEFINITION => {
+            let class_node = unwrap_or_return!(node.parent().and_then(|body_node| body_node.parent()));
+            if class_node.kind() != NodeKinds::CLASS_DECLARATION { return; }
+            let class_name_node = unwrap_or_return!(class_node.child_by_field_name(NodeNames::NAME));
+            let class_name = unwrap_or_return!(get_node_value(&class_name_node, class_data));
+            let name_node = unwrap_or_return!(node.child_by_field_name(NodeNames::NAME));
+            let mut name = unwrap_or_return!(get_node_value(&name_node, class_data));
+            if name == KeyWords::CONSTRUCTOR_IDENTIFIER {
+                name = class_name.clone();
+            }
+            (class_name, vec![name], node.child_by_field_name(NodeNames::PARAMETERS))
+        }
+        NodeKinds::CLASS_DECLARATION => {
+            if !class_data.options().synthetic_constructors() { return; }
+            let name_node = unwrap_or_return!(node.child_by_field_name(NodeNames::NAME));
+            let class_name = unwrap_or_return!(get_node_value(&name_node, class_data));
+            (class_name.clone(), vec![class_name], None)
+        }
+        _ => return
+    };
+
+    if method_names.is_empty() || !is_deprecated(&node, class_data.source_code()) {
+        return;
+    }
+
+    /* Single parameter of arrow function has no parentheses, zero args constructor of class has no parameters */
+    let (min_count_of_params, max_count_of_params) = match parameters_node {
+        Some(parameters_node) => get_count_of_params_range(&parameters_node),
+        None if node.child_by_field_name(NodeNames::PARAMETER).is_some() => (1, Some(1)),
+        None => (0, Some(0))
+    };
+
+    for method_name in method_names {
+        class_data.mut_deprecated_methods().push(
+            DeprecatedMethod::new(class_name.clone(), method_name, min_count_of_params, max_count_of_params)
+        );
+    }
+}
+
 fn add_call_expression(call_node: Node, links: &mut Vec<MethodDescription>, class_data: &ClassData) {
 
     let function_node = unwrap_or_return!(call_node.child_by_field_name(NodeNames::FUNCTION));
@@ -239,7 +339,7 @@ fn get_node_value(node: &Node, class_data: &ClassData) -> Option<String> {
             class_data.current_package())
         );
 
-    if node_string.len() < MAX_TOKEN_LENGTH {
+    if node_string.len() < class_data.options().max_token_length() {
         Some(node_string)
     } else {
         None
@@ -284,7 +384,7 @@ mod js_method_invocation_tests {
         let mut parser = Parser::new();
         parser.set_language(tree_sitter_javascript::language()).expect("ERROR: Unable to load JavaScript grammar");
         let tree = parser.parse(&mut code, None).unwrap();
-        let invoc_structure = get_file_structure(code, tree, "test".to_string());
+        let invoc_structure = get_file_structure(code, tree, "test".to_string(), &ParseOptions::default());
         println!("{}", serde_json::to_string_pretty(&invoc_structure).unwrap_or("".to_string()));
 
     }
@@ -1,12 +1,24 @@
 use std::io::Read;
 use tree_sitter::{Node, Tree};
 use crate::dto::repository_method_dto::RepositoryMethodDto;
-use crate::model::python_object::CodeType::{PYTHON_ENUM, PYTHON_METHOD, PYTHON_CLASS, PYTHON_CONSTRUCTOR, PYTHON_PACKAGE};
+use crate::model::python_object::CodeType::{PYTHON_ENUM, PYTHON_METHOD, PYTHON_CLASS, PYTHON_CONSTRUCTOR, PYTHON_PACKAGE, PYTHON_REEXPORT, PYTHON_ROUTE, PYTHON_VARIABLE, PYTHON_CONSTANT, PYTHON_TYPE_ALIAS};
 use crate::model::python_object::{ClassObject, CodeType, MethodObject, PackageObject, PythonObject};
 use crate::unwrap_or_return;
 use crate::unwrap_or_empty_string;
+use crate::unwrap_or_continue;
+use crate::dto::documentation::Documentation;
+use crate::dto::visibility::Visibility;
+use crate::dto::modifier::Modifier;
+use crate::dto::method_kind::MethodKind;
+use crate::dto::class_kind::ClassKind;
+use crate::dto::definition::DeclarationKind;
+use crate::dto::route::Route;
+use std::collections::HashMap;
+use crate::utils::doc_comment::parse_docstring;
+use crate::utils::python_deprecation::{get_docstring, get_deprecation};
+use crate::utils::python_module::{module_name, module_package, is_package_init, is_stub, resolve_relative_import};
+use crate::dto::parse_options::ParseOptions;
 
-const MAX_TOKEN_LENGTH: usize = 250;
 
 struct NodeKinds;
 impl NodeKinds {
@@ -16,6 +28,25 @@ impl NodeKinds {
     const TYPED_PARAMETER:&'static str = "typed_parameter";
     const DEFAULT_PARAMETER:&'static str = "default_parameter";
     const TYPED_DEFAULT_PARAMETER:&'static str = "typed_default_parameter";
+    const EXPRESSION_STATEMENT:&'static str = "expression_statement";
+    const STRING:&'static str = "string";
+    const COMMENT:&'static str = "comment";
+    const DECORATED_DEFINITION:&'static str = "decorated_definition";
+    const DECORATOR:&'static str = "decorator";
+    const CALL:&'static str = "call";
+    const KEYWORD_ARGUMENT:&'static str = "keyword_argument";
+    const IMPORT_FROM_STATEMENT:&'static str = "import_from_statement";
+    const RELATIVE_IMPORT:&'static str = "relative_import";
+    const DOTTED_NAME:&'static str = "dotted_name";
+    const ALIASED_IMPORT:&'static str = "aliased_import";
+    const ASSIGNMENT:&'static str = "assignment";
+    const IMPORT_STATEMENT:&'static str = "import_statement";
+    const PATTERN_LIST:&'static str = "pattern_list";
+    const TUPLE_PATTERN:&'static str = "tuple_pattern";
+    const ATTRIBUTE:&'static str = "attribute";
+    const SUBSCRIPT:&'static str = "subscript";
+    const LIST:&'static str = "list";
+    const TUPLE:&'static str = "tuple";
 
 }
 
@@ -26,6 +57,17 @@ impl NodeNames {
     const PARAMETERS:&'static str = "parameters";
     const RETURN_TYPE:&'static str = "return_type";
     const SUPERCLASSES:&'static str = "superclasses";
+    const DEFINITION:&'static str = "definition";
+    const FUNCTION:&'static str = "function";
+    const ARGUMENTS:&'static str = "arguments";
+    const VALUE:&'static str = "value";
+    const MODULE_NAME:&'static str = "module_name";
+    const ALIAS:&'static str = "alias";
+    const LEFT:&'static str = "left";
+    const RIGHT:&'static str = "right";
+    const TYPE:&'static str = "type";
+    const OBJECT:&'static str = "object";
+    const ATTRIBUTE:&'static str = "attribute";
 }
 
 struct KeyWords;
@@ -38,16 +80,92 @@ impl KeyWords {
     const INT_ENUM:&'static str = "IntEnum";
     const FLAG:&'static str = "Flag";
     const INT_FLAG:&'static str = "IntFlag";
+    const UNDERSCORE:&'static str = "_";
+    const DOUBLE_UNDERSCORE:&'static str = "__";
+    const ASYNC:&'static str = "async";
+    const ALL:&'static str = "__all__";
+    const STATIC_METHOD:&'static str = "staticmethod";
+    const CLASS_METHOD:&'static str = "classmethod";
+    const PROPERTY:&'static str = "property";
+    const CACHED_PROPERTY:&'static str = "cached_property";
+    const SETTER:&'static str = "setter";
+    const DELETER:&'static str = "deleter";
+    const ABSTRACT_METHOD:&'static str = "abstractmethod";
+    const STATIC:&'static str = "static";
+    const ABSTRACT:&'static str = "abstract";
+    const GET:&'static str = "get";
+    const SET:&'static str = "set";
+    const ROUTE:&'static str = "route";
+    const API_ROUTE:&'static str = "api_route";
+    const HTTP_METHODS:[&'static str; 7] = ["get", "post", "put", "delete", "patch", "head", "options"];
+    const HTTP_GET:&'static str = "GET";
+    const METHODS:&'static str = "methods";
+    const PATH:&'static str = "path";
+    const RULE:&'static str = "rule";
+    const RE_PATH:&'static str = "re_path";
+    const AS_VIEW:&'static str = "as_view";
+    const URL_PATH_PREFIX:&'static str = "/";
+    const URLS_FILE:&'static str = "urls.py";
+    const FINAL:&'static str = "Final";
+    const TYPE_ALIAS:&'static str = "TypeAlias";
+    const NEW_TYPE:&'static str = "NewType";
+    const TYPE_FACTORIES:[&'static str; 4] = ["TypeVar", "NewType", "ParamSpec", "TypeVarTuple"];
+    const DATACLASS:&'static str = "dataclass";
+    const FIELD:&'static str = "field";
+    const INIT:&'static str = "init";
+    const FALSE:&'static str = "False";
+    const CLASS_VAR:&'static str = "ClassVar";
+    const KW_ONLY:&'static str = "KW_ONLY";
+    const NAMED_TUPLE:&'static str = "NamedTuple";
+    const NAMED_TUPLE_FACTORY:&'static str = "namedtuple";
+    const TYPED_DICT:&'static str = "TypedDict";
+    const PROTOCOL:&'static str = "Protocol";
+    const ABC:&'static str = "ABC";
+    const ABC_META:&'static str = "ABCMeta";
+    /* Builtin exceptions, base classes of exceptions of the file */
+    const BUILTIN_EXCEPTIONS:[&'static str; 36] = [
+        "BaseException", "Exception", "ArithmeticError", "AssertionError", "AttributeError", "BufferError",
+        "ConnectionError", "EOFError", "EnvironmentError", "FileExistsError", "FileNotFoundError", "ImportError",
+        "IndexError", "IOError", "KeyError", "LookupError", "MemoryError", "ModuleNotFoundError", "NameError",
+        "NotImplementedError", "OSError", "OverflowError", "PermissionError", "RecursionError", "RuntimeError",
+        "StopIteration", "TimeoutError", "TypeError", "UnicodeError", "ValueError", "ZeroDivisionError", "Warning",
+        "DeprecationWarning", "FutureWarning", "RuntimeWarning", "UserWarning"
+    ];
+    const OVERLOAD:&'static str = "overload";
+    const RETURN_ARROW:&'static str = " -> ";
 }
 
 struct DeclarationData {
     source_code: String,
     path: String,
+    options: ParseOptions,
+    class_kinds: HashMap<String, ClassKind>,
+    class_fields: HashMap<String, Vec<String>>,
 }
 
 impl DeclarationData {
-    fn new(source_code: String, path: String) -> Self {
-        Self { source_code, path }
+    fn new(source_code: String, path: String, options: ParseOptions) -> Self {
+        Self { source_code, path, options, class_kinds: HashMap::new(), class_fields: HashMap::new() }
+    }
+
+    fn class_kinds(&self) -> &HashMap<String, ClassKind> {
+        &self.class_kinds
+    }
+
+    fn mut_class_kinds(&mut self) -> &mut HashMap<String, ClassKind> {
+        &mut self.class_kinds
+    }
+
+    fn class_fields(&self) -> &HashMap<String, Vec<String>> {
+        &self.class_fields
+    }
+
+    fn mut_class_fields(&mut self) -> &mut HashMap<String, Vec<String>> {
+        &mut self.class_fields
+    }
+
+    fn options(&self) -> &ParseOptions {
+        &self.options
     }
 
     fn source_code(&self) -> &String {
@@ -60,13 +178,21 @@ impl DeclarationData {
 }
 
 /* Main function */
-pub fn get_repository_method_dto(source: String, tree: Tree, path: String, rep_id: i32) -> Vec<RepositoryMethodDto> {
+pub fn get_repository_method_dto(source: String, tree: Tree, path: String, rep_id: i32, options: &ParseOptions) -> Vec<RepositoryMethodDto> {
 
-    let package_name = convert_path_to_package(&path);
-    let mut declaration_data = DeclarationData::new(source, path.clone());
-    let mut python_object:Box<dyn PythonObject> = Box::new(PackageObject::new(package_name));
+    let package_name = module_name(&path, options.source_roots());
+    let mut declaration_data = DeclarationData::new(source, path.clone(), options.clone());
+    let mut package_object = PackageObject::new(package_name.clone());
+    package_object.set_documentation(get_docstring_from_block(&tree.root_node(), &declaration_data));
+    let mut python_object:Box<dyn PythonObject> = Box::new(package_object);
 
     parse_root_node(tree.root_node(),  &mut declaration_data, & mut python_object);
+    if is_package_init(&path) {
+        add_reexports(tree.root_node(), &package_name, &mut declaration_data, &mut python_object);
+    }
+    if options.extractors().routes() {
+        add_routes(tree.root_node(), &package_name, &mut declaration_data, &mut python_object);
+    }
 
     let mut method_dto_vec = vec![];
 
@@ -90,14 +216,116 @@ fn parse_root_node(node: Node, declaration_data: &mut DeclarationData, parent: &
 }
 
 fn add_statement(node: Node, declaration_data: &mut DeclarationData, parent: &mut Box<dyn PythonObject>) {
+
+    /* Decorated classes and functions are declared by their definition, which reads its decorators by
+       `get_decorator_nodes` */
+    if node.kind() == NodeKinds::DECORATED_DEFINITION {
+        let definition_node = unwrap_or_return!(node.child_by_field_name(NodeNames::DEFINITION));
+        return add_statement(definition_node, declaration_data, parent);
+    }
+
     match node.kind() {
         NodeKinds::CLASS_DEFINITION => add_class_definition(node, declaration_data, parent),
         NodeKinds::FUNCTION_DEFINITION => add_function_definition(node, declaration_data, parent),
+        NodeKinds::EXPRESSION_STATEMENT => add_expression_statement(node, declaration_data, parent),
         &_ => {}
     }
 
 }
 
+fn add_expression_statement(node: Node, declaration_data: &mut DeclarationData, parent: &mut Box<dyn PythonObject>) {
+
+    /* Module and class level assignments only, locals of functions are not declarations */
+    match parent.type_code() {
+        PYTHON_PACKAGE | PYTHON_CLASS | PYTHON_ENUM => {}
+        &_ => return
+    }
+
+    let assignment = unwrap_or_return!(node.named_child(0));
+    if assignment.kind() != NodeKinds::ASSIGNMENT {
+        return;
+    }
+    let left_node = unwrap_or_return!(assignment.child_by_field_name(NodeNames::LEFT));
+    let type_node = assignment.child_by_field_name(NodeNames::TYPE);
+    let right_node = assignment.child_by_field_name(NodeNames::RIGHT);
+
+    if let Some(fields) = right_node.and_then(|x| get_named_tuple_call_fields(&x, declaration_data)) {
+        if left_node.kind() == NodeKinds::IDENTIFIER {
+            add_named_tuple_call(left_node, fields, declaration_data, parent);
+            return;
+        }
+    }
+
+    let target_nodes = match left_node.kind() {
+        NodeKinds::IDENTIFIER => vec![left_node],
+        NodeKinds::PATTERN_LIST | NodeKinds::TUPLE_PATTERN => left_node
+            .named_children(&mut left_node.walk())
+            .filter(|x| x.kind() == NodeKinds::IDENTIFIER)
+            .collect(),
+        &_ => vec![]
+    };
+
+    for target_node in target_nodes {
+        let name = get_node_text(&target_node, declaration_data);
+
+        /* Reassignment `x = 2` or `Foo = decorate(Foo)` keeps the first declaration */
+        if parent.mut_children().iter().any(|x| x.name() == &name) {
+            continue;
+        }
+
+        let annotation = type_node.map(|x| get_node_text(&x, declaration_data));
+        let (type_code, aliased_type) = get_variable_kind(&name, &annotation, &right_node, declaration_data);
+        let annotation = if type_code == PYTHON_TYPE_ALIAS { None } else { annotation };
+
+        let mut variable_object = MethodObject::new(
+            name.clone(),
+            type_code,
+            vec![],
+            get_line_number(&target_node),
+            KeyWords::EMPTY_STRING.to_string()
+        );
+        variable_object.set_visibility(get_visibility_from_name(&name, parent.as_ref()));
+        variable_object.set_aliased_type(aliased_type);
+        variable_object.set_annotation_type(annotation);
+        parent.add_child(Box::new(variable_object));
+    }
+}
+
+/* `UserId = NewType("UserId", int)`, `T = TypeVar("T")` and `Alias: TypeAlias = ...` are type aliases,
+   `Final` annotated and UPPER_CASE names are constants */
+fn get_variable_kind(name: &str, annotation: &Option<String>, right_node: &Option<Node>,
+                     declaration_data: &DeclarationData) -> (CodeType, Option<String>) {
+
+    let annotation_name = annotation.as_ref().map(|x| get_annotation_name(x));
+
+    if annotation_name.as_deref() == Some(KeyWords::TYPE_ALIAS) {
+        return (PYTHON_TYPE_ALIAS, right_node.map(|x| get_string_value(&x, declaration_data)));
+    }
+
+    if let Some(call_node) = right_node.filter(|x| x.kind() == NodeKinds::CALL) {
+        let function_node = call_node.child_by_field_name(NodeNames::FUNCTION);
+        let function_name = function_node.map(|x| get_last_name_segment(&x, declaration_data)).unwrap_or_default();
+        if KeyWords::TYPE_FACTORIES.contains(&function_name.as_str()) {
+            /* Supertype of `NewType` is its second argument */
+            let aliased_type = match function_name.as_str() {
+                KeyWords::NEW_TYPE => call_node.child_by_field_name(NodeNames::ARGUMENTS)
+                    .and_then(|x| x.named_children(&mut x.walk()).nth(1))
+                    .map(|x| get_node_text(&x, declaration_data)),
+                _ => None
+            };
+            return (PYTHON_TYPE_ALIAS, aliased_type);
+        }
+    }
+
+    let is_upper_case = name.chars().any(|x| x.is_ascii_uppercase())
+        && name.chars().all(|x| x.is_ascii_uppercase() || x.is_ascii_digit() || x == '_');
+    if annotation_name.as_deref() == Some(KeyWords::FINAL) || is_upper_case {
+        return (PYTHON_CONSTANT, None);
+    }
+
+    (PYTHON_VARIABLE, None)
+}
+
 fn add_class_definition(node: Node, declaration_data: &mut DeclarationData, parent: &mut Box<dyn PythonObject>) {
 
     let name_node = unwrap_or_return!(node.child_by_field_name(NodeNames::NAME));
@@ -105,20 +333,41 @@ fn add_class_definition(node: Node, declaration_data: &mut DeclarationData, pare
     let body_node = unwrap_or_return!(node.child_by_field_name(NodeNames::BODY));
     let line_number = get_line_number(&name_node);
     let type_code = get_type_code_from_class_node(&node, declaration_data);
+    let visibility = get_visibility_from_name(&name, parent.as_ref());
+    let class_kind = get_class_kind(&node, declaration_data);
+    let parameters = get_synthesized_parameters(&node, &name, class_kind, declaration_data);
 
     let mut class_object = ClassObject::default();
     class_object.set_name(name.clone());
     class_object.set_line_number(line_number);
     class_object.set_type_code(type_code);
+    class_object.set_visibility(visibility);
+    class_object.set_documentation(get_docstring_from_block(&body_node, declaration_data));
+    class_object.set_decorators(get_decorators(&node, declaration_data));
+    class_object.set_class_kind(class_kind);
+    if class_kind == Some(ClassKind::Abstract) {
+        class_object.set_modifiers(vec![KeyWords::ABSTRACT.to_string()]);
+    }
+    set_deprecation_from_node(&mut class_object, &node, declaration_data);
+    let deprecated = class_object.deprecated();
+    let deprecation_message = class_object.deprecation_message().clone();
     let mut python_class:Box<dyn PythonObject> = Box::new(class_object);
 
-    let mut constructor_object = MethodObject::default();
-    constructor_object.set_name(name);
-    constructor_object.set_type_code(CodeType::PYTHON_CONSTRUCTOR);
-    constructor_object.set_line_number(line_number);
-    let python_constructor:Box<dyn PythonObject> = Box::new(constructor_object);
+    if declaration_data.options().synthetic_constructors() {
+        let mut constructor_object = MethodObject::new(
+            name,
+            PYTHON_CONSTRUCTOR,
+            parameters,
+            line_number,
+            KeyWords::EMPTY_STRING.to_string()
+        );
+        constructor_object.set_visibility(visibility);
+        constructor_object.set_deprecated(deprecated);
+        constructor_object.set_deprecation_message(deprecation_message);
+        let python_constructor:Box<dyn PythonObject> = Box::new(constructor_object);
 
-    python_class.add_child(python_constructor);
+        python_class.add_child(python_constructor);
+    }
 
     for child in body_node.named_children(&mut node.walk()) {
         add_statement(child, declaration_data, & mut python_class);
@@ -127,6 +376,51 @@ fn add_class_definition(node: Node, declaration_data: &mut DeclarationData, pare
     parent.add_child(python_class);
 }
 
+/* `Point = namedtuple("Point", ["x", "y"])` declares class with fields */
+fn add_named_tuple_call(name_node: Node, fields: Vec<String>, declaration_data: &mut DeclarationData,
+                        parent: &mut Box<dyn PythonObject>) {
+
+    let name = get_node_text(&name_node, declaration_data);
+    let line_number = get_line_number(&name_node);
+    let visibility = get_visibility_from_name(&name, parent.as_ref());
+    declaration_data.mut_class_kinds().insert(name.clone(), ClassKind::NamedTuple);
+    declaration_data.mut_class_fields().insert(name.clone(), fields.clone());
+
+    let mut class_object = ClassObject::default();
+    class_object.set_name(name.clone());
+    class_object.set_line_number(line_number);
+    class_object.set_type_code(PYTHON_CLASS);
+    class_object.set_visibility(visibility);
+    class_object.set_class_kind(Some(ClassKind::NamedTuple));
+    let mut python_class:Box<dyn PythonObject> = Box::new(class_object);
+
+    if declaration_data.options().synthetic_constructors() {
+        let mut constructor_object = MethodObject::new(
+            name,
+            PYTHON_CONSTRUCTOR,
+            fields.clone(),
+            line_number,
+            KeyWords::EMPTY_STRING.to_string()
+        );
+        constructor_object.set_visibility(visibility);
+        python_class.add_child(Box::new(constructor_object));
+    }
+
+    for field in fields {
+        let mut field_object = MethodObject::new(
+            field,
+            PYTHON_VARIABLE,
+            vec![],
+            line_number,
+            KeyWords::EMPTY_STRING.to_string()
+        );
+        field_object.set_visibility(Visibility::Public);
+        python_class.add_child(Box::new(field_object));
+    }
+
+    parent.add_child(python_class);
+}
+
 fn add_function_definition(node: Node, declaration_data: &mut DeclarationData, parent: &mut Box<dyn PythonObject>) {
 
     let name_node = unwrap_or_return!(node.child_by_field_name(NodeNames::NAME));
@@ -136,6 +430,7 @@ fn add_function_definition(node: Node, declaration_data: &mut DeclarationData, p
     let mut name = unwrap_or_empty_string!(get_node_value(&name_node, declaration_data));
     let parameters = get_params_from_param_node(params_node, declaration_data);
     let line_number = get_line_number(&node);
+    let visibility = get_visibility_from_name(&name, parent.as_ref());
 
     let mut type_code = PYTHON_METHOD;
     if name == KeyWords::CONSTRUCTOR_NAME {
@@ -147,14 +442,32 @@ fn add_function_definition(node: Node, declaration_data: &mut DeclarationData, p
         Some(return_node) => unwrap_or_empty_string!(get_node_value(&return_node, declaration_data)),
         None => KeyWords::EMPTY_STRING.to_string()
     };
+    let signature = get_signature(&params_node, &output_param, declaration_data);
+    let declaration_kind = get_function_declaration_kind(&node, declaration_data);
     
-    let method_object = MethodObject::new(
+    let mut method_object = MethodObject::new(
         name,
         type_code,
         parameters,
         line_number,
         output_param
     );
+    let decorators = get_decorators(&node, declaration_data);
+    let mut modifiers = get_modifiers_from_function_node(&node);
+    let method_kind = match parent.type_code() {
+        PYTHON_CLASS | PYTHON_ENUM => Some(get_method_kind(&node, &mut modifiers, declaration_data)),
+        &_ => None
+    };
+
+    method_object.set_visibility(visibility);
+    method_object.set_modifiers(modifiers);
+    method_object.set_decorators(decorators);
+    method_object.set_method_kind(method_kind);
+    method_object.set_signature(Some(signature));
+    method_object.set_declaration_kind(declaration_kind);
+    method_object.set_documentation(get_docstring_from_block(&body_node, declaration_data));
+    method_object.set_body(get_body_from_node(&node, declaration_data));
+    set_deprecation_from_node(&mut method_object, &node, declaration_data);
     
     let mut python_method:Box<dyn PythonObject> = Box::new(method_object);
     
@@ -165,23 +478,340 @@ fn add_function_definition(node: Node, declaration_data: &mut DeclarationData, p
     parent.add_child(python_method);
 }
 
+/* Flask/FastAPI route decorators of handlers and Django `path()`/`re_path()` entries of `urls.py` */
+fn add_routes(node: Node, package_name: &String, declaration_data: &mut DeclarationData, parent: &mut Box<dyn PythonObject>) {
+
+    let is_urls_file = declaration_data.path_file().rsplit('/').next() == Some(KeyWords::URLS_FILE);
+    let imported_names = match is_urls_file {
+        true => Some(get_imported_names(&node, declaration_data)),
+        false => None
+    };
+    add_route_nodes(node, package_name, imported_names.as_ref(), declaration_data, parent);
+}
+
+/* Imported names are given for `urls.py` only, calls of other files are not url patterns */
+fn add_route_nodes(node: Node, package_name: &String, imported_names: Option<&HashMap<String, String>>,
+                   declaration_data: &DeclarationData, parent: &mut Box<dyn PythonObject>) {
+
+    match (node.kind(), imported_names) {
+        (NodeKinds::DECORATOR, _) => add_decorator_route(node, package_name, declaration_data, parent),
+        (NodeKinds::CALL, Some(imported_names))
+        => add_url_pattern_route(node, package_name, imported_names, declaration_data, parent),
+        _ => {}
+    }
+
+    for child in node.named_children(&mut node.walk()) {
+        add_route_nodes(child, package_name, imported_names, declaration_data, parent);
+    }
+}
+
+/* `@app.route("/users", methods=["GET", "POST"])`, `@router.get("/users/{id}")` */
+fn add_decorator_route(node: Node, package_name: &String, declaration_data: &DeclarationData, parent: &mut Box<dyn PythonObject>) {
+
+    let call_node = unwrap_or_return!(node.named_child(0));
+    if call_node.kind() != NodeKinds::CALL {
+        return;
+    }
+    let function_node = unwrap_or_return!(call_node.child_by_field_name(NodeNames::FUNCTION));
+    let arguments_node = unwrap_or_return!(call_node.child_by_field_name(NodeNames::ARGUMENTS));
+    if function_node.kind() != NodeKinds::ATTRIBUTE {
+        return;
+    }
+    let decorator_name = get_last_name_segment(&function_node, declaration_data).to_lowercase();
+    let is_route = decorator_name == KeyWords::ROUTE || decorator_name == KeyWords::API_ROUTE;
+    if !is_route && !KeyWords::HTTP_METHODS.contains(&decorator_name.as_str()) {
+        return;
+    }
+
+    let path = unwrap_or_return!(get_string_argument(&arguments_node, &[KeyWords::PATH, KeyWords::RULE], declaration_data));
+    if !path.starts_with(KeyWords::URL_PATH_PREFIX) {
+        return;
+    }
+    let http_methods = match is_route {
+        true => get_list_argument(&arguments_node, KeyWords::METHODS, declaration_data)
+            .unwrap_or(vec![KeyWords::HTTP_GET.to_string()])
+            .iter()
+            .map(|x| x.to_uppercase())
+            .collect(),
+        false => vec![decorator_name.to_uppercase()]
+    };
+
+    /* Handler is the decorated function, qualified by enclosing classes */
+    let definition_node = unwrap_or_return!(node.parent().and_then(|x| x.child_by_field_name(NodeNames::DEFINITION)));
+    if definition_node.kind() != NodeKinds::FUNCTION_DEFINITION {
+        return;
+    }
+    let handler = unwrap_or_return!(get_qualified_name(&definition_node, package_name, declaration_data));
+
+    add_route(path, http_methods, handler, get_line_number(&node), parent);
+}
+
+/* `path("users/<int:pk>/", views.user_detail)`, `re_path(r"^users/$", UserList.as_view())` */
+fn add_url_pattern_route(node: Node, package_name: &String, imported_names: &HashMap<String, String>,
+                         declaration_data: &DeclarationData, parent: &mut Box<dyn PythonObject>) {
+
+    let function_node = unwrap_or_return!(node.child_by_field_name(NodeNames::FUNCTION));
+    let arguments_node = unwrap_or_return!(node.child_by_field_name(NodeNames::ARGUMENTS));
+    let function_name = get_node_text(&function_node, declaration_data);
+    if function_name != KeyWords::PATH && function_name != KeyWords::RE_PATH {
+        return;
+    }
+
+    let path = unwrap_or_return!(get_string_argument(&arguments_node, &[KeyWords::ROUTE], declaration_data));
+    let mut view_node = unwrap_or_return!(arguments_node.named_children(&mut arguments_node.walk()).nth(1));
+
+    /* Class based view `UserList.as_view()`, `include(...)` has no handler */
+    if view_node.kind() == NodeKinds::CALL {
+        let view_function = unwrap_or_return!(view_node.child_by_field_name(NodeNames::FUNCTION));
+        let attribute_node = unwrap_or_return!(view_function.child_by_field_name(NodeNames::ATTRIBUTE));
+        if get_node_text(&attribute_node, declaration_data) != KeyWords::AS_VIEW {
+            return;
+        }
+        view_node = unwrap_or_return!(view_function.child_by_field_name(NodeNames::OBJECT));
+    }
+    if view_node.kind() != NodeKinds::IDENTIFIER && view_node.kind() != NodeKinds::ATTRIBUTE {
+        return;
+    }
+
+    /* `views.user_detail` of `from . import views` */
+    let view_name = get_node_text(&view_node, declaration_data);
+    let (first_segment, rest) = match view_name.split_once('.') {
+        Some((first_segment, rest)) => (first_segment.to_string(), format!(".{}", rest)),
+        None => (view_name.clone(), String::new())
+    };
+    let handler = match imported_names.get(&first_segment) {
+        Some(qualified_name) => format!("{}{}", qualified_name, rest),
+        None => format!("{}.{}", package_name, view_name)
+    };
+
+    add_route(path, vec![], handler, get_line_number(&node), parent);
+}
+
+fn add_route(path: String, http_methods: Vec<String>, handler: String, line_number: usize, parent: &mut Box<dyn PythonObject>) {
+
+    let mut route_object = MethodObject::new(
+        path.clone(),
+        PYTHON_ROUTE,
+        vec![],
+        line_number,
+        KeyWords::EMPTY_STRING.to_string()
+    );
+    route_object.set_route(Some(Route::new(path, http_methods, handler)));
+    parent.add_child(Box::new(route_object));
+}
+
+/* Local name to qualified name of imported module or definition, relative imports resolved */
+fn get_imported_names(node: &Node, declaration_data: &DeclarationData) -> HashMap<String, String> {
+
+    let mut imported_names = HashMap::new();
+    let current_package = module_package(declaration_data.path_file(), declaration_data.options().source_roots());
+
+    for statement in node.named_children(&mut node.walk()) {
+        let module = match statement.kind() {
+            NodeKinds::IMPORT_FROM_STATEMENT => {
+                let module_node = unwrap_or_continue!(statement.child_by_field_name(NodeNames::MODULE_NAME));
+                Some(resolve_relative_import(&get_node_text(&module_node, declaration_data), &current_package))
+            }
+            NodeKinds::IMPORT_STATEMENT => None,
+            &_ => continue
+        };
+
+        for name_node in statement.children_by_field_name(NodeNames::NAME, &mut statement.walk()) {
+            let (original_node, local_node) = match name_node.kind() {
+                NodeKinds::ALIASED_IMPORT => (
+                    unwrap_or_continue!(name_node.child_by_field_name(NodeNames::NAME)),
+                    unwrap_or_continue!(name_node.child_by_field_name(NodeNames::ALIAS))
+                ),
+                NodeKinds::DOTTED_NAME => (name_node, name_node),
+                _ => continue
+            };
+            let original_name = get_node_text(&original_node, declaration_data);
+            let local_name = get_node_text(&local_node, declaration_data);
+
+            /* `import shop.views` binds `shop` */
+            let (local_name, qualified_name) = match &module {
+                Some(module) => (local_name, format!("{}.{}", module, original_name)),
+                None if name_node.kind() == NodeKinds::DOTTED_NAME => {
+                    let first_segment = local_name.split('.').next().unwrap_or_default().to_string();
+                    (first_segment.clone(), first_segment)
+                }
+                None => (local_name, original_name)
+            };
+            imported_names.insert(local_name, qualified_name);
+        }
+    }
+
+    imported_names
+}
+
+fn get_qualified_name(node: &Node, package_name: &str, declaration_data: &DeclarationData) -> Option<String> {
+
+    let mut names = vec![get_node_text(&node.child_by_field_name(NodeNames::NAME)?, declaration_data)];
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        if parent.kind() == NodeKinds::CLASS_DEFINITION {
+            names.push(get_node_text(&parent.child_by_field_name(NodeNames::NAME)?, declaration_data));
+        }
+        current = parent.parent();
+    }
+    names.push(package_name.to_string());
+    names.reverse();
+    Some(names.join("."))
+}
+
+/* First positional string argument or string keyword argument */
+fn get_string_argument(node: &Node, keywords: &[&str], declaration_data: &DeclarationData) -> Option<String> {
+
+    for argument in node.named_children(&mut node.walk()) {
+        let value_node = match argument.kind() {
+            NodeKinds::STRING => argument,
+            NodeKinds::KEYWORD_ARGUMENT => {
+                let name_node = unwrap_or_continue!(argument.child_by_field_name(NodeNames::NAME));
+                if !keywords.contains(&get_node_text(&name_node, declaration_data).as_str()) {
+                    continue;
+                }
+                unwrap_or_continue!(argument.child_by_field_name(NodeNames::VALUE))
+            }
+            /* `app.route(handler, "/users")` */
+            _ => continue
+        };
+        if value_node.kind() == NodeKinds::STRING {
+            return Some(get_string_value(&value_node, declaration_data));
+        }
+    }
+
+    None
+}
+
+/* `methods=["GET", "POST"]` */
+fn get_list_argument(node: &Node, keyword: &str, declaration_data: &DeclarationData) -> Option<Vec<String>> {
+
+    for argument in node.named_children(&mut node.walk()) {
+        if argument.kind() != NodeKinds::KEYWORD_ARGUMENT {
+            continue;
+        }
+        let name_node = unwrap_or_continue!(argument.child_by_field_name(NodeNames::NAME));
+        let value_node = unwrap_or_continue!(argument.child_by_field_name(NodeNames::VALUE));
+        if get_node_text(&name_node, declaration_data) != keyword {
+            continue;
+        }
+        let values = value_node
+            .named_children(&mut value_node.walk())
+            .filter(|x| x.kind() == NodeKinds::STRING)
+            .map(|x| get_string_value(&x, declaration_data))
+            .collect();
+        return Some(values);
+    }
+
+    None
+}
+
+/* Value of string literal without `r`/`f`/`b` prefix and quotes */
+fn get_string_value(node: &Node, declaration_data: &DeclarationData) -> String {
+
+    get_node_text(node, declaration_data)
+        .trim_start_matches(|c: char| c.is_ascii_alphabetic())
+        .trim_matches(|c| c == '"' || c == '\'')
+        .to_string()
+}
+
+/* Package `__init__.py` re-exports names listed in `__all__`, without `__all__` names imported from the package */
+fn add_reexports(node: Node, package_name: &String, declaration_data: &mut DeclarationData, parent: &mut Box<dyn PythonObject>) {
+
+    let exported_names = get_all_names(&node, declaration_data);
+    let package_prefix = format!("{}.", package_name);
+
+    for statement in node.named_children(&mut node.walk()) {
+        if statement.kind() != NodeKinds::IMPORT_FROM_STATEMENT {
+            continue;
+        }
+
+        let module_node = unwrap_or_continue!(statement.child_by_field_name(NodeNames::MODULE_NAME));
+        let module = resolve_relative_import(&get_node_text(&module_node, declaration_data), package_name);
+        let is_package_import = module_node.kind() == NodeKinds::RELATIVE_IMPORT || module.starts_with(&package_prefix);
+
+        for name_node in statement.children_by_field_name(NodeNames::NAME, &mut statement.walk()) {
+            let (original_node, exported_node) = match name_node.kind() {
+                NodeKinds::ALIASED_IMPORT => (
+                    unwrap_or_continue!(name_node.child_by_field_name(NodeNames::NAME)),
+                    unwrap_or_continue!(name_node.child_by_field_name(NodeNames::ALIAS))
+                ),
+                NodeKinds::DOTTED_NAME => (name_node, name_node),
+                _ => continue
+            };
+            let original_name = get_node_text(&original_node, declaration_data);
+            let exported_name = get_node_text(&exported_node, declaration_data);
+
+            let is_exported = match &exported_names {
+                Some(names) => names.contains(&exported_name),
+                None => is_package_import
+            };
+            if !is_exported {
+                continue;
+            }
+
+            let mut class_object = ClassObject::default();
+            class_object.set_name(exported_name.clone());
+            class_object.set_line_number(get_line_number(&exported_node));
+            class_object.set_type_code(PYTHON_REEXPORT);
+            class_object.set_visibility(get_visibility_from_name(&exported_name, parent.as_ref()));
+            class_object.set_reexported_from(Some(format!("{}.{}", module, original_name)));
+            parent.add_child(Box::new(class_object));
+        }
+    }
+}
+
+/* `__all__ = ["Engine", "Wheel"]` at module level */
+fn get_all_names(node: &Node, declaration_data: &DeclarationData) -> Option<Vec<String>> {
+
+    for statement in node.named_children(&mut node.walk()) {
+        if statement.kind() != NodeKinds::EXPRESSION_STATEMENT {
+            continue;
+        }
+
+        let assignment = unwrap_or_continue!(statement.named_child(0));
+        if assignment.kind() != NodeKinds::ASSIGNMENT {
+            continue;
+        }
+        let left_node = unwrap_or_continue!(assignment.child_by_field_name(NodeNames::LEFT));
+        if get_node_text(&left_node, declaration_data) != KeyWords::ALL {
+            continue;
+        }
+
+        let right_node = unwrap_or_continue!(assignment.child_by_field_name(NodeNames::RIGHT));
+        let names = right_node
+            .named_children(&mut right_node.walk())
+            .filter(|x| x.kind() == NodeKinds::STRING)
+            .map(|x| get_node_text(&x, declaration_data).trim_matches(|c| c == '"' || c == '\'').to_string())
+            .collect();
+        return Some(names);
+    }
+
+    None
+}
+
 /* Data conversion */
 fn prepare_output_data(dto_vec: &mut Vec<RepositoryMethodDto>, python_object: Box<dyn PythonObject>,
                        rep_id: i32, path: &String, package_name: &String, class_name: &String) {
     
     match python_object.type_code() {
         PYTHON_PACKAGE => prepare_package_object(dto_vec, python_object, rep_id, path, class_name),
-        PYTHON_CLASS | PYTHON_ENUM => prepare_class_object(dto_vec, python_object, rep_id, path, package_name),
-        PYTHON_METHOD | PYTHON_CONSTRUCTOR
+        PYTHON_CLASS | PYTHON_ENUM | PYTHON_REEXPORT => prepare_class_object(dto_vec, python_object, rep_id, path, package_name),
+        PYTHON_METHOD | PYTHON_CONSTRUCTOR | PYTHON_ROUTE | PYTHON_VARIABLE | PYTHON_CONSTANT | PYTHON_TYPE_ALIAS
         => prepare_method_object(dto_vec, python_object, rep_id, path, package_name, class_name),
         &_ => {}
     }
     
 }
 
-fn prepare_package_object(dto_vec: &mut Vec<RepositoryMethodDto>, python_object: Box<dyn PythonObject>,
+fn prepare_package_object(dto_vec: &mut Vec<RepositoryMethodDto>, mut python_object: Box<dyn PythonObject>,
                           rep_id: i32, path: &String, class_name: &String) {
 
+    let modifiers = python_object.take_modifiers();
+    let visibility = python_object.visibility();
+    let documentation = python_object.take_documentation();
+    let deprecated = python_object.deprecated();
+    let deprecation_message = python_object.take_deprecation_message();
     let boxed_any = python_object.to_any();
     let boxed_package = boxed_any.downcast::<PackageObject>().unwrap();
     let package_object = *boxed_package;
@@ -193,7 +823,7 @@ fn prepare_package_object(dto_vec: &mut Vec<RepositoryMethodDto>, python_object:
         children
     ) = package_object.take();
 
-    let repository_method_dto = RepositoryMethodDto::new(
+    let mut repository_method_dto = RepositoryMethodDto::new(
         rep_id,
         path.clone(),
         name.clone(),
@@ -205,6 +835,11 @@ fn prepare_package_object(dto_vec: &mut Vec<RepositoryMethodDto>, python_object:
         type_code.to_string(),
         0,
     );
+    repository_method_dto.set_modifiers(Modifier::from_keywords(&modifiers));
+    repository_method_dto.set_visibility(visibility);
+    repository_method_dto.set_documentation(documentation);
+    repository_method_dto.set_deprecated(deprecated);
+    repository_method_dto.set_deprecation_message(deprecation_message);
 
     dto_vec.push(repository_method_dto);
 
@@ -213,9 +848,18 @@ fn prepare_package_object(dto_vec: &mut Vec<RepositoryMethodDto>, python_object:
     }
 }
 
-fn prepare_class_object(dto_vec: &mut Vec<RepositoryMethodDto>, python_object: Box<dyn PythonObject>,
+fn prepare_class_object(dto_vec: &mut Vec<RepositoryMethodDto>, mut python_object: Box<dyn PythonObject>,
                         rep_id: i32, path: &String, package_name: &String) {
 
+    let modifiers = python_object.take_modifiers();
+    let visibility = python_object.visibility();
+    let documentation = python_object.take_documentation();
+    let deprecated = python_object.deprecated();
+    let deprecation_message = python_object.take_deprecation_message();
+    let decorators = python_object.take_decorators();
+    let method_kind = python_object.take_method_kind();
+    let reexported_from = python_object.take_reexported_from();
+    let class_kind = python_object.take_class_kind();
     let boxed_any = python_object.to_any();
     let boxed_class = boxed_any.downcast::<ClassObject>().unwrap();
     let class_object = *boxed_class;
@@ -227,7 +871,7 @@ fn prepare_class_object(dto_vec: &mut Vec<RepositoryMethodDto>, python_object: B
         children
     ) = class_object.take();
 
-    let repository_method_dto = RepositoryMethodDto::new(
+    let mut repository_method_dto = RepositoryMethodDto::new(
         rep_id,
         path.clone(),
         package_name.clone(),
@@ -239,6 +883,15 @@ fn prepare_class_object(dto_vec: &mut Vec<RepositoryMethodDto>, python_object: B
         type_code.to_string(),
         0,
     );
+    repository_method_dto.set_modifiers(Modifier::from_keywords(&modifiers));
+    repository_method_dto.set_visibility(visibility);
+    repository_method_dto.set_documentation(documentation);
+    repository_method_dto.set_deprecated(deprecated);
+    repository_method_dto.set_deprecation_message(deprecation_message);
+    repository_method_dto.set_decorators(Some(decorators).filter(|x| !x.is_empty()));
+    repository_method_dto.set_method_kind(method_kind);
+    repository_method_dto.set_reexported_from(reexported_from);
+    repository_method_dto.set_class_kind(class_kind);
 
     dto_vec.push(repository_method_dto);
 
@@ -247,9 +900,22 @@ fn prepare_class_object(dto_vec: &mut Vec<RepositoryMethodDto>, python_object: B
     }
 }
 
-fn prepare_method_object(dto_vec: &mut Vec<RepositoryMethodDto>, python_object: Box<dyn PythonObject>,
+fn prepare_method_object(dto_vec: &mut Vec<RepositoryMethodDto>, mut python_object: Box<dyn PythonObject>,
                          rep_id: i32, path: &String, package_name: &String, class_name: &String) {
 
+    let modifiers = python_object.take_modifiers();
+    let visibility = python_object.visibility();
+    let documentation = python_object.take_documentation();
+    let deprecated = python_object.deprecated();
+    let deprecation_message = python_object.take_deprecation_message();
+    let decorators = python_object.take_decorators();
+    let method_kind = python_object.take_method_kind();
+    let route = python_object.take_route();
+    let aliased_type = python_object.take_aliased_type();
+    let annotation_type = python_object.take_annotation_type();
+    let signature = python_object.take_signature();
+    let declaration_kind = python_object.take_declaration_kind();
+    let body = python_object.take_body();
     let boxed_any = python_object.to_any();
     let boxed_method = boxed_any.downcast::<MethodObject>().unwrap();
     let method_object = *boxed_method;
@@ -263,18 +929,30 @@ fn prepare_method_object(dto_vec: &mut Vec<RepositoryMethodDto>, python_object:
         _output_parameter,
     ) = method_object.take();
 
-    let repository_method_dto = RepositoryMethodDto::new(
+    let mut repository_method_dto = RepositoryMethodDto::new(
         rep_id,
         path.clone(),
         package_name.clone(),
         line_code,
         class_name.clone(),
         name,
-        KeyWords::EMPTY_STRING.to_string(),
+        body.unwrap_or_default(),
         serde_json::to_string(&Vec::<String>::new()).unwrap(),
         type_code.to_string(),
         parameters.len(),
     );
+    repository_method_dto.set_modifiers(Modifier::from_keywords(&modifiers));
+    repository_method_dto.set_visibility(visibility);
+    repository_method_dto.set_documentation(documentation);
+    repository_method_dto.set_deprecated(deprecated);
+    repository_method_dto.set_deprecation_message(deprecation_message);
+    repository_method_dto.set_decorators(Some(decorators).filter(|x| !x.is_empty()));
+    repository_method_dto.set_method_kind(method_kind);
+    repository_method_dto.set_route(route);
+    repository_method_dto.set_aliased_type(aliased_type);
+    repository_method_dto.set_annotation_type(annotation_type);
+    repository_method_dto.set_signature(signature);
+    repository_method_dto.set_declaration_kind(declaration_kind);
 
     dto_vec.push(repository_method_dto);
 
@@ -308,6 +986,284 @@ fn get_type_code_from_class_node(node: &Node, declaration_data: & mut Declaratio
 
 }
 
+/* Dataclass by its decorator, otherwise by the first classified superclass. Subclasses of exceptions and
+   TypedDicts of the file are exceptions and TypedDicts too */
+fn get_class_kind(node: &Node, declaration_data: &DeclarationData) -> Option<ClassKind> {
+
+    if get_dataclass_decorator(node, declaration_data).is_some() {
+        return Some(ClassKind::Dataclass);
+    }
+
+    let superclasses = node.child_by_field_name(NodeNames::SUPERCLASSES)?;
+    for child in superclasses.named_children(&mut superclasses.walk()) {
+        let base_node = match child.kind() {
+            NodeKinds::SUBSCRIPT => unwrap_or_continue!(child.child_by_field_name(NodeNames::VALUE)),
+            NodeKinds::KEYWORD_ARGUMENT => {
+                /* `metaclass=ABCMeta` */
+                let value_node = unwrap_or_continue!(child.child_by_field_name(NodeNames::VALUE));
+                if get_last_name_segment(&value_node, declaration_data) == KeyWords::ABC_META {
+                    return Some(ClassKind::Abstract);
+                }
+                continue;
+            }
+            _ => child
+        };
+
+        let base_name = get_last_name_segment(&base_node, declaration_data);
+        let class_kind = match base_name.as_str() {
+            KeyWords::NAMED_TUPLE => Some(ClassKind::NamedTuple),
+            KeyWords::TYPED_DICT => Some(ClassKind::TypedDict),
+            KeyWords::PROTOCOL => Some(ClassKind::Protocol),
+            KeyWords::ABC => Some(ClassKind::Abstract),
+            _ if KeyWords::BUILTIN_EXCEPTIONS.contains(&base_name.as_str()) => Some(ClassKind::Exception),
+            _ => declaration_data.class_kinds()
+                .get(&base_name)
+                .copied()
+                .filter(|x| *x == ClassKind::Exception || *x == ClassKind::TypedDict)
+        };
+        if class_kind.is_some() {
+            return class_kind;
+        }
+    }
+
+    None
+}
+
+/* `@dataclass`, `@dataclasses.dataclass` or `@dataclass(frozen=True)` */
+fn get_dataclass_decorator<'tree>(node: &Node<'tree>, declaration_data: &DeclarationData) -> Option<Node<'tree>> {
+
+    get_decorator_nodes(node)
+        .iter()
+        .filter_map(|x| x.named_child(0))
+        .find(|x| {
+            let function_node = match x.kind() {
+                NodeKinds::CALL => x.child_by_field_name(NodeNames::FUNCTION).unwrap_or(*x),
+                _ => *x
+            };
+            get_last_name_segment(&function_node, declaration_data) == KeyWords::DATACLASS
+        })
+}
+
+/* Dataclasses, NamedTuples and TypedDicts get `__init__` taking their fields, fields of base classes of the file
+   first. Dataclass with `init=False` or with its own `__init__` gets no generated parameters */
+fn get_synthesized_parameters(node: &Node, name: &str, class_kind: Option<ClassKind>,
+                              declaration_data: &mut DeclarationData) -> Vec<String> {
+
+    match class_kind {
+        Some(ClassKind::Dataclass) | Some(ClassKind::NamedTuple) | Some(ClassKind::TypedDict) => {}
+        Some(class_kind) => {
+            declaration_data.mut_class_kinds().insert(name.to_string(), class_kind);
+            return vec![];
+        }
+        None => return get_inherited_parameters(node, name, declaration_data)
+    }
+
+    let mut fields: Vec<String> = vec![];
+    if let Some(superclasses) = node.child_by_field_name(NodeNames::SUPERCLASSES) {
+        for child in superclasses.named_children(&mut superclasses.walk()) {
+            let base_name = get_node_text(&child, declaration_data);
+            fields.extend(declaration_data.class_fields().get(&base_name).cloned().unwrap_or_default());
+        }
+    }
+    if let Some(body_node) = node.child_by_field_name(NodeNames::BODY) {
+        for field in get_class_fields(&body_node, declaration_data) {
+            /* Redefined field keeps its original position */
+            if !fields.contains(&field) {
+                fields.push(field);
+            }
+        }
+    }
+    declaration_data.mut_class_kinds().insert(name.to_string(), class_kind.unwrap());
+    declaration_data.mut_class_fields().insert(name.to_string(), fields.clone());
+
+    let has_init_disabled = get_dataclass_decorator(node, declaration_data)
+        .filter(|x| x.kind() == NodeKinds::CALL)
+        .and_then(|x| x.child_by_field_name(NodeNames::ARGUMENTS))
+        .is_some_and(|x| has_false_argument(&x, KeyWords::INIT, declaration_data));
+    let has_init = node.child_by_field_name(NodeNames::BODY).is_some_and(|x| has_constructor(&x, declaration_data));
+    if has_init_disabled || has_init {
+        return vec![];
+    }
+
+    fields
+}
+
+/* Undecorated subclass of dataclass or NamedTuple of the file inherits `__init__` of the first such base, its own
+   annotations are not fields */
+fn get_inherited_parameters(node: &Node, name: &str, declaration_data: &mut DeclarationData) -> Vec<String> {
+
+    let superclasses = match node.child_by_field_name(NodeNames::SUPERCLASSES) {
+        Some(superclasses) => superclasses,
+        None => return vec![]
+    };
+    let inherited_fields = superclasses
+        .named_children(&mut superclasses.walk())
+        .find_map(|x| declaration_data.class_fields().get(&get_node_text(&x, declaration_data)).cloned());
+    let fields = match inherited_fields {
+        Some(fields) => fields,
+        None => return vec![]
+    };
+    declaration_data.mut_class_fields().insert(name.to_string(), fields.clone());
+
+    let has_init = node.child_by_field_name(NodeNames::BODY).is_some_and(|x| has_constructor(&x, declaration_data));
+    if has_init {
+        return vec![];
+    }
+
+    fields
+}
+
+/* Annotated class body assignments, `ClassVar`, `KW_ONLY` and `field(init=False)` are not fields */
+fn get_class_fields(body_node: &Node, declaration_data: &DeclarationData) -> Vec<String> {
+
+    let mut fields = vec![];
+
+    for statement in body_node.named_children(&mut body_node.walk()) {
+        if statement.kind() != NodeKinds::EXPRESSION_STATEMENT {
+            continue;
+        }
+        let assignment = unwrap_or_continue!(statement.named_child(0).filter(|x| x.kind() == NodeKinds::ASSIGNMENT));
+        let left_node = unwrap_or_continue!(assignment.child_by_field_name(NodeNames::LEFT));
+        let type_node = unwrap_or_continue!(assignment.child_by_field_name(NodeNames::TYPE));
+        if left_node.kind() != NodeKinds::IDENTIFIER {
+            continue;
+        }
+
+        let annotation_name = get_annotation_name(&get_node_text(&type_node, declaration_data));
+        if annotation_name == KeyWords::CLASS_VAR || annotation_name == KeyWords::KW_ONLY {
+            continue;
+        }
+
+        let is_init_disabled = assignment.child_by_field_name(NodeNames::RIGHT)
+            .filter(|x| x.kind() == NodeKinds::CALL)
+            .filter(|x| x.child_by_field_name(NodeNames::FUNCTION)
+                .is_some_and(|y| get_last_name_segment(&y, declaration_data) == KeyWords::FIELD))
+            .and_then(|x| x.child_by_field_name(NodeNames::ARGUMENTS))
+            .is_some_and(|x| has_false_argument(&x, KeyWords::INIT, declaration_data));
+        if is_init_disabled {
+            continue;
+        }
+
+        fields.push(get_node_text(&left_node, declaration_data));
+    }
+
+    fields
+}
+
+fn has_constructor(body_node: &Node, declaration_data: &DeclarationData) -> bool {
+
+    body_node
+        .named_children(&mut body_node.walk())
+        .filter_map(|x| match x.kind() {
+            NodeKinds::DECORATED_DEFINITION => x.child_by_field_name(NodeNames::DEFINITION),
+            _ => Some(x)
+        })
+        .filter(|x| x.kind() == NodeKinds::FUNCTION_DEFINITION)
+        .filter_map(|x| x.child_by_field_name(NodeNames::NAME))
+        .any(|x| get_node_text(&x, declaration_data) == KeyWords::CONSTRUCTOR_NAME)
+}
+
+/* `init=False` */
+fn has_false_argument(arguments_node: &Node, keyword: &str, declaration_data: &DeclarationData) -> bool {
+
+    arguments_node
+        .named_children(&mut arguments_node.walk())
+        .filter(|x| x.kind() == NodeKinds::KEYWORD_ARGUMENT)
+        .any(|x| {
+            let name = x.child_by_field_name(NodeNames::NAME).map(|y| get_node_text(&y, declaration_data));
+            let value = x.child_by_field_name(NodeNames::VALUE).map(|y| get_node_text(&y, declaration_data));
+            name.as_deref() == Some(keyword) && value.as_deref() == Some(KeyWords::FALSE)
+        })
+}
+
+/* Fields of `namedtuple("Point", "x y")`, `namedtuple("Point", ["x", "y"])` and
+   `NamedTuple("Point", [("x", int), ("y", int)])`, None for other expressions */
+fn get_named_tuple_call_fields(node: &Node, declaration_data: &DeclarationData) -> Option<Vec<String>> {
+
+    if node.kind() != NodeKinds::CALL {
+        return None;
+    }
+    let function_node = node.child_by_field_name(NodeNames::FUNCTION)?;
+    let function_name = get_last_name_segment(&function_node, declaration_data);
+    if function_name != KeyWords::NAMED_TUPLE_FACTORY && function_name != KeyWords::NAMED_TUPLE {
+        return None;
+    }
+
+    let arguments_node = node.child_by_field_name(NodeNames::ARGUMENTS)?;
+    let fields_node = arguments_node.named_children(&mut arguments_node.walk()).nth(1)?;
+    let fields = match fields_node.kind() {
+        NodeKinds::STRING => get_string_value(&fields_node, declaration_data)
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|x| !x.is_empty())
+            .map(|x| x.to_string())
+            .collect(),
+        NodeKinds::LIST | NodeKinds::TUPLE => fields_node
+            .named_children(&mut fields_node.walk())
+            .filter_map(|x| match x.kind() {
+                NodeKinds::TUPLE => x.named_child(0),
+                _ => Some(x)
+            })
+            .filter(|x| x.kind() == NodeKinds::STRING)
+            .map(|x| get_string_value(&x, declaration_data))
+            .collect(),
+        _ => vec![]
+    };
+
+    Some(fields)
+}
+
+/* `Final[int]` is `Final`, `typing.ClassVar[int]` is `ClassVar` */
+fn get_annotation_name(annotation: &str) -> String {
+    annotation.split('[').next().unwrap_or_default().rsplit('.').next().unwrap_or_default().to_string()
+}
+
+/* `(key: str, default: int = 0) -> bool` with whitespace of multiline parameter lists collapsed */
+fn get_signature(params_node: &Node, output_param: &String, declaration_data: &DeclarationData) -> String {
+
+    let parameters = params_node.named_children(&mut params_node.walk())
+        .filter(|x| x.kind() != NodeKinds::COMMENT)
+        .map(|x| get_parameter_signature(&x, declaration_data))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let parameters = format!("({})", parameters);
+    match output_param.is_empty() {
+        true => parameters,
+        false => format!("{}{}{}", parameters, KeyWords::RETURN_ARROW, output_param)
+    }
+}
+
+/* `name: type = value` with single spaces between parts, texts of name, type and default value are kept */
+fn get_parameter_signature(node: &Node, declaration_data: &DeclarationData) -> String {
+
+    let text = |x: Option<Node>| x.map(|x| get_node_text(&x, declaration_data)).unwrap_or_default();
+
+    match node.kind() {
+        NodeKinds::TYPED_PARAMETER =>
+            format!("{}: {}", text(node.named_child(0)), text(node.child_by_field_name(NodeNames::TYPE))),
+        NodeKinds::DEFAULT_PARAMETER =>
+            format!("{}={}", text(node.child_by_field_name(NodeNames::NAME)),
+                    text(node.child_by_field_name(NodeNames::VALUE))),
+        NodeKinds::TYPED_DEFAULT_PARAMETER =>
+            format!("{}: {} = {}", text(node.child_by_field_name(NodeNames::NAME)),
+                    text(node.child_by_field_name(NodeNames::TYPE)), text(node.child_by_field_name(NodeNames::VALUE))),
+        _ => get_node_text(node, declaration_data)
+    }
+}
+
+/* Functions of `.pyi` stubs and `@overload` signatures declare the function implemented elsewhere */
+fn get_function_declaration_kind(node: &Node, declaration_data: &DeclarationData) -> Option<DeclarationKind> {
+
+    let is_overload = get_decorator_nodes(node)
+        .iter()
+        .filter_map(|x| x.named_child(0))
+        .any(|x| get_last_name_segment(&x, declaration_data) == KeyWords::OVERLOAD);
+    if is_stub(declaration_data.path_file()) || is_overload {
+        return Some(DeclarationKind::Declaration);
+    }
+
+    None
+}
+
 fn get_params_from_param_node(node: Node, declaration_data: &DeclarationData) -> Vec<String> {
 
     let mut parameters = vec![];
@@ -358,6 +1314,138 @@ fn get_name_from_typed_param(node: &Node, declaration_data: &DeclarationData) ->
     return unwrap_or_empty_string!(get_node_value(&identifier_opt.unwrap(), declaration_data))
 }
 
+fn get_docstring_from_block(node: &Node, declaration_data: &DeclarationData) -> Option<Documentation> {
+
+    /* Doc comment also carries deprecation, skip it only when both extractors are disabled */
+    let extractors = declaration_data.options().extractors();
+    if !extractors.documentation() && !extractors.deprecation() {
+        return None;
+    }
+
+    get_docstring(node, declaration_data.source_code()).map(|docstring| parse_docstring(&docstring))
+}
+
+fn get_modifiers_from_function_node(node: &Node) -> Vec<String> {
+
+    let mut modifiers = vec![];
+    if get_child_node_by_kind(node, KeyWords::ASYNC).is_some() {
+        modifiers.push(KeyWords::ASYNC.to_string());
+    }
+    modifiers
+}
+
+/* Decorator expressions as written, `app.route("/users")` of `@app.route("/users")` */
+fn get_decorators(node: &Node, declaration_data: &DeclarationData) -> Vec<String> {
+
+    get_decorator_nodes(node)
+        .iter()
+        .filter_map(|x| x.named_child(0))
+        .map(|x| get_node_text(&x, declaration_data))
+        .collect()
+}
+
+fn get_decorator_nodes<'tree>(node: &Node<'tree>) -> Vec<Node<'tree>> {
+
+    match node.parent() {
+        Some(parent) if parent.kind() == NodeKinds::DECORATED_DEFINITION => parent
+            .named_children(&mut parent.walk())
+            .filter(|x| x.kind() == NodeKinds::DECORATOR)
+            .collect(),
+        _ => vec![]
+    }
+}
+
+/* Property accessors and static methods also carry `get`/`set` and `static` modifiers, `@abstractmethod` the
+   `abstract` modifier whatever the binding is */
+fn get_method_kind(node: &Node, modifiers: &mut Vec<String>, declaration_data: &DeclarationData) -> MethodKind {
+
+    let mut method_kind = MethodKind::Instance;
+    let mut is_abstract = false;
+
+    for decorator in get_decorator_nodes(node) {
+        let expression = unwrap_or_continue!(decorator.named_child(0));
+        let function_node = match expression.kind() {
+            NodeKinds::CALL => unwrap_or_continue!(expression.child_by_field_name(NodeNames::FUNCTION)),
+            _ => expression
+        };
+
+        match get_last_name_segment(&function_node, declaration_data).as_str() {
+            KeyWords::PROPERTY | KeyWords::CACHED_PROPERTY => {
+                method_kind = MethodKind::Property;
+                modifiers.push(KeyWords::GET.to_string());
+            }
+            KeyWords::SETTER => {
+                method_kind = MethodKind::Property;
+                modifiers.push(KeyWords::SET.to_string());
+            }
+            KeyWords::DELETER => method_kind = MethodKind::Property,
+            KeyWords::STATIC_METHOD if method_kind != MethodKind::Property => {
+                method_kind = MethodKind::Static;
+                modifiers.push(KeyWords::STATIC.to_string());
+            }
+            KeyWords::CLASS_METHOD if method_kind != MethodKind::Property => method_kind = MethodKind::Class,
+            KeyWords::ABSTRACT_METHOD => is_abstract = true,
+            _ => {}
+        }
+    }
+
+    if is_abstract {
+        modifiers.push(KeyWords::ABSTRACT.to_string());
+        if method_kind == MethodKind::Instance {
+            method_kind = MethodKind::Abstract;
+        }
+    }
+    method_kind
+}
+
+fn get_visibility_from_name(name: &str, parent: &dyn PythonObject) -> Visibility {
+
+    /* Dunder methods (__init__, __eq__) are public */
+    if name.starts_with(KeyWords::DOUBLE_UNDERSCORE) && name.ends_with(KeyWords::DOUBLE_UNDERSCORE) {
+        return Visibility::Public;
+    }
+
+    if !name.starts_with(KeyWords::UNDERSCORE) {
+        return Visibility::Public;
+    }
+
+    /* Name mangling (__name) makes class members private, single underscore is protected by convention */
+    match parent.type_code() {
+        PYTHON_CLASS | PYTHON_ENUM if name.starts_with(KeyWords::DOUBLE_UNDERSCORE) => Visibility::Private,
+        PYTHON_CLASS | PYTHON_ENUM => Visibility::Protected,
+        &_ => Visibility::Internal
+    }
+}
+
+fn set_deprecation_from_node(python_object: &mut dyn PythonObject, node: &Node, declaration_data: &DeclarationData) {
+
+    if !declaration_data.options().extractors().deprecation() {
+        return;
+    }
+
+    /* `.. deprecated::` docstring directive */
+    let doc_message = python_object
+        .documentation()
+        .as_ref()
+        .and_then(|documentation| documentation.deprecated().clone());
+
+    /* @deprecated("message") decorator (PEP 702) or warnings.warn("message", DeprecationWarning) in the body */
+    let deprecation = get_deprecation(node, declaration_data.source_code());
+
+    let deprecated = deprecation.is_some() || doc_message.is_some();
+    let message = deprecation.flatten().or(doc_message).filter(|message| !message.is_empty());
+
+    python_object.set_deprecated(deprecated);
+    python_object.set_deprecation_message(message);
+}
+
+fn get_last_name_segment(node: &Node, declaration_data: &DeclarationData) -> String {
+
+    /* `deprecated`, `typing_extensions.deprecated`, `warnings.warn` */
+    let name = unwrap_or_empty_string!(get_node_value(node, declaration_data));
+    name.rsplit('.').next().unwrap_or(KeyWords::EMPTY_STRING).to_string()
+}
+
 fn get_child_node_by_kind<'time>(node: &'time Node, kind: &'time str) -> Option<Node<'time>> {
     node
         .children(&mut node.walk())
@@ -379,25 +1467,30 @@ fn get_node_value(node: &Node, declaration_data: &DeclarationData) -> Option<Str
             declaration_data.path_file())
         );
 
-    if node_string.len() < MAX_TOKEN_LENGTH {
+    if node_string.len() < declaration_data.options().max_token_length() {
         Some(node_string)
     } else {
         None
     }
 }
 
-fn get_line_number(node: &Node) -> usize {
-    node.start_position().row + 1
+fn get_node_text(node: &Node, declaration_data: &DeclarationData) -> String {
+
+    let source = declaration_data.source_code();
+    source[node.start_byte()..node.end_byte()].to_string()
 }
 
-fn convert_path_to_package(path: &String) -> String {
+fn get_body_from_node(node: &Node, declaration_data: &DeclarationData) -> Option<String> {
 
-    const PY_EXTENSION: &str = ".py";
-    const EMPTY_STRING: &str = "";
-    const SLASH: &str = "/";
-    const DOT: &str = ".";
+    if !declaration_data.options().capture_body() {
+        return None;
+    }
+
+    Some(get_node_text(node, declaration_data))
+}
 
-    return path.replace(PY_EXTENSION, EMPTY_STRING).replace(SLASH, DOT);
+fn get_line_number(node: &Node) -> usize {
+    node.start_position().row + 1
 }
 
 
@@ -408,6 +1501,21 @@ mod python_code_declaration_tests {
     use tree_sitter::Parser;
     use std::fs;
 
+    fn parse_python(code: &str, path: &str, options: &ParseOptions) -> Vec<RepositoryMethodDto> {
+        let mut parser = Parser::new();
+        parser.set_language(tree_sitter_python::language()).expect("ERROR: Unable to load PYTHON grammar");
+        let tree = parser.parse(code, None).unwrap();
+        get_repository_method_dto(code.to_string(), tree, path.to_string(), 0, options)
+    }
+
+    fn parse_python_json(code: &str, path: &str) -> Vec<serde_json::Value> {
+        parse_python(code, path, &ParseOptions::default()).iter().map(|x| serde_json::to_value(x).unwrap()).collect()
+    }
+
+    fn find_method<'a>(json: &'a [serde_json::Value], method_name: &str) -> &'a serde_json::Value {
+        json.iter().find(|x| x["methodName"] == method_name).unwrap()
+    }
+
     #[test]
     pub fn test_get_repository_method_dto() {
         let mut code = fs::read_to_string("../../resources/test_files/python/2.py.txt").unwrap();
@@ -421,11 +1529,394 @@ mod python_code_declaration_tests {
         println!("{},  ", tree.root_node().to_sexp());
 
         let repository_method_dto =
-            get_repository_method_dto(code, tree, "test".to_string(), 0);
+            get_repository_method_dto(code, tree, "test".to_string(), 0, &ParseOptions::default());
 
         for dto in repository_method_dto {
             let json = serde_json::to_string_pretty(&dto).unwrap();
             print!("{}", json);
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    pub fn test_get_documentation() {
+        let code = String::from(
+            "\"\"\"Geometry helpers.\"\"\"\n\
+             class Circle:\n\
+             \x20   \"\"\"A circle.\"\"\"\n\
+             \n\
+             \x20   def area(self, precision):\n\
+             \x20       \"\"\"Computes area of the circle.\n\
+             \n\
+             \x20       Args:\n\
+             \x20           precision: number of digits\n\
+             \n\
+             \x20       Returns:\n\
+             \x20           area value\n\
+             \x20       \"\"\"\n\
+             \x20       return 0\n\
+             \n\
+             def helper():\n\
+             \x20   return 1\n"
+        );
+        let json = parse_python_json(&code, "test");
+
+        let package_dto = json.iter().find(|x| x["type"] == "PYTHON_PACKAGE").unwrap();
+        assert_eq!(package_dto["documentation"]["summary"], "Geometry helpers.");
+
+        let class_dto = json.iter().find(|x| x["type"] == "PYTHON_CLASS").unwrap();
+        assert_eq!(class_dto["documentation"]["summary"], "A circle.");
+
+        let area_dto = find_method(&json, "area");
+        assert_eq!(area_dto["documentation"]["summary"], "Computes area of the circle.");
+        assert_eq!(area_dto["documentation"]["params"][0]["name"], "precision");
+        assert_eq!(area_dto["documentation"]["returns"], "area value");
+
+        let helper_dto = find_method(&json, "helper");
+        assert!(helper_dto.get("documentation").is_none());
+    }
+
+    #[test]
+    pub fn test_get_deprecation() {
+        let code = String::from(
+            "import warnings\n\
+             from typing_extensions import deprecated\n\
+             \n\
+             @deprecated(\"use load\")\n\
+             def read(path):\n\
+             \x20   return load(path)\n\
+             \n\
+             def parse(text):\n\
+             \x20   warnings.warn(\"parse is obsolete\", category=DeprecationWarning)\n\
+             \x20   return text\n\
+             \n\
+             def load(path):\n\
+             \x20   \"\"\"Loads file.\n\
+             \n\
+             \x20   .. deprecated:: 2.0\n\
+             \x20   \"\"\"\n\
+             \x20   warnings.warn(\"load\", UserWarning)\n\
+             \n\
+             def dump(data):\n\
+             \x20   pass\n"
+        );
+        let json = parse_python_json(&code, "test");
+        let find = |method_name: &str| find_method(&json, method_name).clone();
+
+        assert_eq!(find("read")["deprecated"], true);
+        assert_eq!(find("read")["deprecationMessage"], "use load");
+        assert_eq!(find("parse")["deprecated"], true);
+        assert_eq!(find("parse")["deprecationMessage"], "parse is obsolete");
+        assert_eq!(find("load")["deprecated"], true);
+        assert_eq!(find("load")["deprecationMessage"], "2.0");
+        assert_eq!(find("dump")["deprecated"], false);
+    }
+
+    #[test]
+    pub fn test_get_visibility() {
+        let code = String::from(
+            "class Cache:\n\
+             \x20   def __init__(self):\n\
+             \x20       pass\n\
+             \n\
+             \x20   def get(self, key):\n\
+             \x20       pass\n\
+             \n\
+             \x20   def _evict(self):\n\
+             \x20       pass\n\
+             \n\
+             \x20   def __hash(self, key):\n\
+             \x20       pass\n\
+             \n\
+             def _build_cache():\n\
+             \x20   pass\n"
+        );
+        let json = parse_python_json(&code, "test");
+        let find = |method_name: &str| json.iter()
+            .rfind(|x| x["methodName"] == method_name).unwrap()["visibility"].clone();
+
+        assert_eq!(find("Cache"), "public");
+        assert_eq!(find("get"), "public");
+        assert_eq!(find("_evict"), "protected");
+        assert_eq!(find("__hash"), "private");
+        assert_eq!(find("_build_cache"), "internal");
+    }
+
+    #[test]
+    pub fn test_get_modifiers() {
+        let code = String::from(
+            "async def fetch(url):\n\
+             \x20   pass\n\
+             \n\
+             def parse(text):\n\
+             \x20   pass\n"
+        );
+        let json = parse_python_json(&code, "test");
+        let find = |method_name: &str| find_method(&json, method_name)["modifiers"].clone();
+
+        assert_eq!(find("fetch"), serde_json::json!(["async"]));
+        assert_eq!(find("parse"), serde_json::json!([]));
+    }
+
+    #[test]
+    pub fn test_get_module_name() {
+        let parse = |path: &str, options: &ParseOptions|
+            serde_json::to_value(&parse_python("class Service:\n    pass\n", path, options)[1]).unwrap()["packageName"].clone();
+
+        assert_eq!(parse("src/mypkg/service.py", &ParseOptions::default()), "mypkg.service");
+        assert_eq!(parse("mypkg/__init__.py", &ParseOptions::default()), "mypkg");
+
+        let options = ParseOptions::from_json(r#"{ "sourceRoots": ["services/api"] }"#).unwrap();
+        assert_eq!(parse("services/api/app/main.py", &options), "app.main");
+    }
+
+    #[test]
+    pub fn test_get_reexports() {
+        let parse = |source: &str| parse_python_json(source, "src/mypkg/__init__.py").iter()
+            .filter(|x| x["type"] == "PYTHON_REEXPORT")
+            .map(|x| (x["className"].as_str().unwrap().to_string(), x["reexportedFrom"].as_str().unwrap().to_string()))
+            .collect::<Vec<(String, String)>>();
+
+        let reexports = parse(
+            "from .engine import Engine, Wheel as W\n\
+             from mypkg.parts import Bolt\n\
+             from os import path\n"
+        );
+        assert_eq!(reexports, vec![
+            ("Engine".to_string(), "mypkg.engine.Engine".to_string()),
+            ("W".to_string(), "mypkg.engine.Wheel".to_string()),
+            ("Bolt".to_string(), "mypkg.parts.Bolt".to_string()),
+        ]);
+
+        let reexports = parse(
+            "from .engine import Engine, Wheel\n\
+             from os import path\n\
+             __all__ = [\"Engine\", 'path']\n"
+        );
+        assert_eq!(reexports, vec![
+            ("Engine".to_string(), "mypkg.engine.Engine".to_string()),
+            ("path".to_string(), "os.path".to_string()),
+        ]);
+    }
+
+    #[test]
+    pub fn test_get_method_kind() {
+        let code = String::from(
+            "@dataclass\n\
+             class Order:\n\
+             \x20   def pay(self):\n\
+             \x20       pass\n\
+             \x20   @staticmethod\n\
+             \x20   def create():\n\
+             \x20       pass\n\
+             \x20   @classmethod\n\
+             \x20   def load(cls, key):\n\
+             \x20       pass\n\
+             \x20   @property\n\
+             \x20   def total(self) -> int:\n\
+             \x20       pass\n\
+             \x20   @total.setter\n\
+             \x20   def total(self, value):\n\
+             \x20       pass\n\
+             \x20   @abc.abstractmethod\n\
+             \x20   def ship(self):\n\
+             \x20       pass\n\
+             @app.route(\"/orders\")\n\
+             def orders():\n\
+             \x20   pass\n"
+        );
+        let json = parse_python_json(&code, "test");
+        let find = |method_name: &str, line_code: usize| json.iter()
+            .find(|x| x["methodName"] == method_name && x["lineCode"] == line_code).unwrap().clone();
+
+        let class = json.iter().find(|x| x["type"] == "PYTHON_CLASS").unwrap();
+        assert_eq!(class["decorators"], serde_json::json!(["dataclass"]));
+        assert_eq!(find("pay", 3)["methodKind"], "instance");
+        assert_eq!(find("pay", 3).get("decorators"), None);
+        assert_eq!(find("create", 6)["methodKind"], "static");
+        assert_eq!(find("create", 6)["modifiers"], serde_json::json!(["static"]));
+        assert_eq!(find("load", 9)["methodKind"], "class");
+        assert_eq!(find("total", 12)["methodKind"], "property");
+        assert_eq!(find("total", 12)["modifiers"], serde_json::json!(["getter"]));
+        assert_eq!(find("total", 15)["modifiers"], serde_json::json!(["setter"]));
+        assert_eq!(find("ship", 18)["methodKind"], "abstract");
+        assert_eq!(find("ship", 18)["modifiers"], serde_json::json!(["abstract"]));
+        assert_eq!(find("orders", 21)["decorators"], serde_json::json!(["app.route(\"/orders\")"]));
+        assert_eq!(find("orders", 21).get("methodKind"), None);
+    }
+
+    #[test]
+    pub fn test_get_routes() {
+        let parse = |source: &str, path: &str, routes: bool| {
+            let options = ParseOptions::from_json(&format!(r#"{{ "extractors": {{ "routes": {} }} }}"#, routes)).unwrap();
+            serde_json::to_value(parse_python(source, path, &options)).unwrap().as_array().unwrap().iter()
+                .filter(|x| x["type"] == "PYTHON_ROUTE")
+                .map(|x| x["route"].clone())
+                .collect::<Vec<serde_json::Value>>()
+        };
+
+        let code = "@app.route(\"/users\", methods=[\"get\", \"POST\"])\n\
+                    def users():\n\
+                    \x20   pass\n\
+                    class Api:\n\
+                    \x20   @router.get(\"/users/{id}\")\n\
+                    \x20   async def user(self, id):\n\
+                    \x20       pass\n\
+                    @cache.get(\"key\")\n\
+                    def cached():\n\
+                    \x20   pass\n\
+                    @app.route(handler, \"/health\")\n\
+                    def health():\n\
+                    \x20   pass\n";
+        assert!(parse(code, "src/shop/api.py", false).is_empty());
+        assert_eq!(parse(code, "src/shop/api.py", true), vec![
+            serde_json::json!({ "path": "/users", "httpMethods": ["GET", "POST"], "handler": "shop.api.users" }),
+            serde_json::json!({ "path": "/users/{id}", "httpMethods": ["GET"], "handler": "shop.api.Api.user" }),
+            serde_json::json!({ "path": "/health", "httpMethods": ["GET"], "handler": "shop.api.health" }),
+        ]);
+
+        let code = "from django.urls import path, re_path, include\n\
+                    from . import views\n\
+                    from .views import UserList as Users\n\
+                    urlpatterns = [\n\
+                    \x20   path(\"users/<int:pk>/\", views.user_detail, name=\"user\"),\n\
+                    \x20   re_path(r\"^users/$\", Users.as_view()),\n\
+                    \x20   path(\"api/\", include(\"shop.api.urls\")),\n\
+                    \x20   path(\"health/\", health),\n\
+                    ]\n";
+        assert_eq!(parse(code, "src/shop/urls.py", true), vec![
+            serde_json::json!({ "path": "users/<int:pk>/", "httpMethods": [], "handler": "shop.views.user_detail" }),
+            serde_json::json!({ "path": "^users/$", "httpMethods": [], "handler": "shop.views.UserList" }),
+            serde_json::json!({ "path": "health/", "httpMethods": [], "handler": "shop.urls.health" }),
+        ]);
+    }
+
+    #[test]
+    pub fn test_get_variables() {
+        let code = String::from(
+            "from typing import Final, NewType, TypeAlias, TypeVar\n\
+             MAX_RETRIES = 3\n\
+             MAX_RETRIES = 5\n\
+             timeout: Final[int] = 10\n\
+             registry: dict = {}\n\
+             host, port = \"localhost\", 80\n\
+             T = TypeVar(\"T\")\n\
+             UserId = NewType(\"UserId\", int)\n\
+             Vector: TypeAlias = \"list[float]\"\n\
+             class Color(Enum):\n\
+             \x20   RED = 1\n\
+             def main():\n\
+             \x20   local = 1\n"
+        );
+        let json = parse_python_json(&code, "src/app/config.py");
+        let variables: Vec<(String, String, String)> = json.iter()
+            .filter(|x| x["type"] == "PYTHON_VARIABLE" || x["type"] == "PYTHON_CONSTANT" || x["type"] == "PYTHON_TYPE_ALIAS")
+            .map(|x| (x["methodName"].as_str().unwrap().to_string(), x["className"].as_str().unwrap().to_string(),
+                      x["type"].as_str().unwrap().to_string()))
+            .collect();
+
+        let expected = [
+            ("MAX_RETRIES", "", "PYTHON_CONSTANT"),
+            ("timeout", "", "PYTHON_CONSTANT"),
+            ("registry", "", "PYTHON_VARIABLE"),
+            ("host", "", "PYTHON_VARIABLE"),
+            ("port", "", "PYTHON_VARIABLE"),
+            ("T", "", "PYTHON_TYPE_ALIAS"),
+            ("UserId", "", "PYTHON_TYPE_ALIAS"),
+            ("Vector", "", "PYTHON_TYPE_ALIAS"),
+            ("RED", "Color", "PYTHON_CONSTANT"),
+        ];
+        assert_eq!(variables, expected.iter()
+            .map(|(name, class_name, type_code)| (name.to_string(), class_name.to_string(), type_code.to_string()))
+            .collect::<Vec<(String, String, String)>>());
+
+        let find = |name: &str| find_method(&json, name).clone();
+        assert_eq!(find("MAX_RETRIES")["lineCode"], 2);
+        assert_eq!(find("timeout")["annotationType"], "Final[int]");
+        assert_eq!(find("registry")["annotationType"], "dict");
+        assert_eq!(find("UserId")["aliasedType"], "int");
+        assert_eq!(find("Vector")["aliasedType"], "list[float]");
+        assert!(find("T")["aliasedType"].is_null());
+    }
+
+    #[test]
+    pub fn test_get_class_kinds() {
+        let code = String::from(
+            "@dataclass(frozen=True)\n\
+             class Base:\n\
+             \x20   id: int\n\
+             \x20   registry: ClassVar[dict] = {}\n\
+             @dataclasses.dataclass\n\
+             class Order(Base):\n\
+             \x20   total: float = 0.0\n\
+             \x20   items: list = field(default_factory=list, init=False)\n\
+             \x20   _: KW_ONLY\n\
+             \x20   note: str = \"\"\n\
+             @dataclass(init=False)\n\
+             class Lazy:\n\
+             \x20   key: str\n\
+             class Point(NamedTuple):\n\
+             \x20   x: int\n\
+             \x20   y: int = 0\n\
+             class Movie(TypedDict, total=False):\n\
+             \x20   title: str\n\
+             class Closeable(Protocol[T]):\n\
+             \x20   def close(self): ...\n\
+             class Repository(metaclass=ABCMeta):\n\
+             \x20   pass\n\
+             class AppError(Exception):\n\
+             \x20   pass\n\
+             class NotFound(AppError):\n\
+             \x20   pass\n\
+             class InvalidOrder(ValueError):\n\
+             \x20   pass\n\
+             class Mirror(ReflectionError):\n\
+             \x20   pass\n\
+             class RushOrder(Order):\n\
+             \x20   priority: int = 0\n\
+             class Point3(Point):\n\
+             \x20   pass\n\
+             class Plain:\n\
+             \x20   pass\n\
+             Pair = namedtuple(\"Pair\", \"left, right\")\n"
+        );
+        let json = parse_python_json(&code, "src/shop/models.py");
+        let class = |name: &str| json.iter()
+            .find(|x| x["className"] == name && x["methodName"] == "").unwrap().clone();
+        let constructor = |name: &str| json.iter()
+            .find(|x| x["className"] == name && x["type"] == "PYTHON_CONSTRUCTOR").unwrap()["countOfParameters"].clone();
+
+        assert_eq!(class("Base")["classKind"], "dataclass");
+        assert_eq!(class("Order")["classKind"], "dataclass");
+        assert_eq!(class("Point")["classKind"], "namedtuple");
+        assert_eq!(class("Movie")["classKind"], "typeddict");
+        assert_eq!(class("Closeable")["classKind"], "protocol");
+        assert_eq!(class("Repository")["classKind"], "abstract");
+        assert_eq!(class("Repository")["modifiers"], serde_json::json!(["abstract"]));
+        assert_eq!(class("AppError")["classKind"], "exception");
+        assert_eq!(class("NotFound")["classKind"], "exception");
+        assert_eq!(class("InvalidOrder")["classKind"], "exception");
+        /* Unknown base is not an exception by its name */
+        assert!(class("Mirror")["classKind"].is_null());
+        assert!(class("Plain")["classKind"].is_null());
+
+        /* `id`, `total` and `note` */
+        assert_eq!(constructor("Base"), 1);
+        assert_eq!(constructor("Order"), 3);
+        assert_eq!(constructor("Lazy"), 0);
+        assert_eq!(constructor("Point"), 2);
+        assert_eq!(constructor("Movie"), 1);
+        assert_eq!(constructor("Plain"), 0);
+        /* Undecorated subclasses take `__init__` of their base */
+        assert_eq!(constructor("RushOrder"), 3);
+        assert_eq!(constructor("Point3"), 2);
+
+        assert_eq!(class("Pair")["classKind"], "namedtuple");
+        assert_eq!(class("Pair")["type"], "PYTHON_CLASS");
+        assert_eq!(constructor("Pair"), 2);
+        let fields: Vec<&serde_json::Value> = json.iter()
+            .filter(|x| x["className"] == "Pair" && x["type"] == "PYTHON_VARIABLE")
+            .map(|x| &x["methodName"])
+            .collect();
+        assert_eq!(fields, vec!["left", "right"]);
+    }
+}
@@ -1,12 +1,16 @@
 use std::io::Read;
+use std::collections::{HashMap, HashSet};
 use tree_sitter::{Node, Tree, TreeCursor};
-use crate::dto::invocation_structure::{InvocationStructure, RepositoryImportDeclaration};
+use crate::dto::invocation_structure::{DeprecatedMethod, InvocationStructure, RepositoryImportDeclaration};
 use crate::dto::object_description::{Description, MethodDescription, PackageDescription, VarDescription};
 use crate::model::python_object::CodeType;
 use crate::unwrap_or_empty_string;
 use crate::unwrap_or_return;
+use crate::dto::parse_options::ParseOptions;
+use crate::utils::python_module::{module_name, module_package, resolve_relative_import};
+use crate::utils::node::get_child_node_by_kind;
+use crate::utils::python_deprecation::{get_count_of_params_range, is_deprecated};
 
-const MAX_TOKEN_LENGTH: usize = 250;
 
 struct NodeKinds;
 impl NodeKinds {
@@ -15,12 +19,34 @@ impl NodeKinds {
     const TYPED_PARAMETER:&'static str = "typed_parameter";
     const TYPED_DEFAULT_PARAMETER:&'static str = "typed_default_parameter";
     const CALL:&'static str = "call";
-    const PARAMETERS:&'static str = "parameters";
     const IMPORT_STATEMENT:&'static str = "import_statement";
     const IMPORT_FROM_STATEMENT:&'static str = "import_from_statement";
     const DOTTED_NAME:&'static str = "dotted_name";
     const ALIASED_IMPORT: &'static str = "aliased_import";
     const ATTRIBUTE:&'static str = "attribute";
+    const FUNCTION_DEFINITION:&'static str = "function_definition";
+    const ASSIGNMENT:&'static str = "assignment";
+    const WITH_ITEM:&'static str = "with_item";
+    const FOR_STATEMENT:&'static str = "for_statement";
+    const DECORATED_DEFINITION:&'static str = "decorated_definition";
+    const DECORATOR:&'static str = "decorator";
+    const AUGMENTED_ASSIGNMENT:&'static str = "augmented_assignment";
+    const PATTERN_LIST:&'static str = "pattern_list";
+    const TUPLE_PATTERN:&'static str = "tuple_pattern";
+    const KEYWORD_ARGUMENT:&'static str = "keyword_argument";
+    const DEFAULT_PARAMETER:&'static str = "default_parameter";
+    const GLOBAL_STATEMENT:&'static str = "global_statement";
+    const NONLOCAL_STATEMENT:&'static str = "nonlocal_statement";
+    const PARAMETERS:&'static str = "parameters";
+    const LAMBDA:&'static str = "lambda";
+    const LIST_COMPREHENSION:&'static str = "list_comprehension";
+    const SET_COMPREHENSION:&'static str = "set_comprehension";
+    const DICTIONARY_COMPREHENSION:&'static str = "dictionary_comprehension";
+    const GENERATOR_EXPRESSION:&'static str = "generator_expression";
+    const FOR_IN_CLAUSE:&'static str = "for_in_clause";
+    const EXCEPT_CLAUSE:&'static str = "except_clause";
+    const LIST_SPLAT_PATTERN:&'static str = "list_splat_pattern";
+    const DICTIONARY_SPLAT_PATTERN:&'static str = "dictionary_splat_pattern";
 }
 
 struct NodeNames;
@@ -30,37 +56,114 @@ impl NodeNames {
     const TYPE:&'static str = "type";
     const FUNCTION:&'static str = "function";
     const ARGUMENTS:&'static str = "arguments";
+    const PARAMETERS:&'static str = "parameters";
+    const RETURN_TYPE:&'static str = "return_type";
+    const BODY:&'static str = "body";
+    const LEFT:&'static str = "left";
+    const RIGHT:&'static str = "right";
+    const VALUE:&'static str = "value";
+    const OBJECT:&'static str = "object";
+    const ATTRIBUTE:&'static str = "attribute";
 }
 
 struct KeyWords;
 impl KeyWords {
     const SELF_SPECIFIER:&'static str = "self";
+    const CONSTRUCTOR_NAME:&'static str = "__init__";
     const EMPTY_STRING:&'static str = "";
+    const OPTIONAL:&'static str = "Optional";
+    const AS:&'static str = "as";
+    const PROPERTY:&'static str = "property";
+    const CACHED_PROPERTY:&'static str = "cached_property";
+    const NAMED_TUPLE:&'static str = "NamedTuple";
+    const NAMED_TUPLE_FACTORY:&'static str = "namedtuple";
+}
+
+/* Bound on chains of variables typed by other variables, `a = b` and `b = a` make a cycle */
+const MAX_TYPE_DEPTH: usize = 8;
+
+/* Source of local variable type, resolved when the variable is used */
+#[derive(Clone)]
+enum TypeSource {
+    /* `x: Foo = ...` or `x: Foo` parameter */
+    Annotation(String),
+    /* `x = Foo()` constructor or `x = make()` function with return annotation */
+    Call(String),
+    /* `x = y` */
+    Variable(String),
+    /* `for x in items` element of `List[Foo]` */
+    ElementOf(Box<TypeSource>),
+}
+
+struct LocalType {
+    scope: usize,
+    var_name: String,
+    source: TypeSource,
+}
+
+/* `self.repo = Repository()` of a method or `repo: Repository` of class body (dataclass field) */
+struct AttributeType {
+    class_name: String,
+    attribute_name: String,
+    source: TypeSource,
+    scope_chain: ScopeChain,
+}
+
+/* Scopes visible from a statement, innermost first, and the class `self` belongs to */
+#[derive(Clone)]
+struct ScopeChain {
+    scopes: Vec<usize>,
+    class_name: Option<String>,
 }
 
 struct InvocationData {
     import_declarations: Vec<RepositoryImportDeclaration>,
     package_descriptions: Vec<PackageDescription>,
-    var_descriptions: Vec<VarDescription>,
+    local_types: Vec<LocalType>,
+    attribute_types: Vec<AttributeType>,
+    return_types: HashMap<String, String>,
+    local_classes: HashSet<String>,
+    properties: HashSet<(String, String)>,
+    module_scope: usize,
+    module_variables: HashSet<String>,
+    local_names: HashSet<(usize, String)>,
+    imported_modules: HashSet<String>,
     links: Vec<MethodDescription>,
+    /* Deprecated functions and constructors of deprecated classes declared in this file */
+    deprecated_methods: Vec<DeprecatedMethod>,
     current_package: String,
     source_code: String,
     path: String,
+    options: ParseOptions,
 }
 
 impl InvocationData {
-    fn new(source_code: String, path: String) -> Self {
+    fn new(source_code: String, path: String, options: ParseOptions) -> Self {
         Self {
             import_declarations: vec![],
             current_package: String::new(),
             package_descriptions: vec![],
-            var_descriptions: vec![],
+            local_types: vec![],
+            attribute_types: vec![],
+            return_types: HashMap::new(),
+            local_classes: HashSet::new(),
+            properties: HashSet::new(),
+            module_scope: 0,
+            module_variables: HashSet::new(),
+            local_names: HashSet::new(),
+            imported_modules: HashSet::new(),
             links: vec![],
+            deprecated_methods: vec![],
             source_code,
             path,
+            options,
         }
     }
 
+    fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+
     fn source_code(&self) -> &String {
         &self.source_code
     }
@@ -69,6 +172,10 @@ impl InvocationData {
         &self.path
     }
 
+    fn set_current_package(&mut self, current_package: String) {
+        self.current_package = current_package;
+    }
+
     fn get_current_package(&self) -> String {
         self.current_package.clone()
     }
@@ -81,49 +188,124 @@ impl InvocationData {
         &self.package_descriptions
     }
 
-    fn var_descriptions(&self) -> &Vec<VarDescription> {
-        &self.var_descriptions
+    fn local_types(&self) -> &Vec<LocalType> {
+        &self.local_types
+    }
+
+    fn attribute_types(&self) -> &Vec<AttributeType> {
+        &self.attribute_types
+    }
+
+    fn return_types(&self) -> &HashMap<String, String> {
+        &self.return_types
+    }
+
+    fn local_classes(&self) -> &HashSet<String> {
+        &self.local_classes
     }
 
     fn mut_package_descriptions(&mut self) -> &mut Vec<PackageDescription> {
         &mut self.package_descriptions
     }
 
-    fn mut_var_descriptions(&mut self) -> &mut Vec<VarDescription> {
-        &mut self.var_descriptions
+    fn mut_local_types(&mut self) -> &mut Vec<LocalType> {
+        &mut self.local_types
+    }
+
+    fn mut_attribute_types(&mut self) -> &mut Vec<AttributeType> {
+        &mut self.attribute_types
+    }
+
+    fn mut_return_types(&mut self) -> &mut HashMap<String, String> {
+        &mut self.return_types
+    }
+
+    fn mut_local_classes(&mut self) -> &mut HashSet<String> {
+        &mut self.local_classes
+    }
+
+    fn properties(&self) -> &HashSet<(String, String)> {
+        &self.properties
+    }
+
+    fn mut_properties(&mut self) -> &mut HashSet<(String, String)> {
+        &mut self.properties
+    }
+
+    fn set_module_scope(&mut self, module_scope: usize) {
+        self.module_scope = module_scope;
+    }
+
+    fn module_variables(&self) -> &HashSet<String> {
+        &self.module_variables
+    }
+
+    fn local_names(&self) -> &HashSet<(usize, String)> {
+        &self.local_names
+    }
+
+    fn imported_modules(&self) -> &HashSet<String> {
+        &self.imported_modules
+    }
+
+    fn mut_imported_modules(&mut self) -> &mut HashSet<String> {
+        &mut self.imported_modules
+    }
+
+    /* Module assignment declares a module variable, any other binds a local name */
+    fn add_name(&mut self, scope: usize, name: String) {
+        if scope == self.module_scope {
+            self.module_variables.insert(name);
+        } else {
+            self.local_names.insert((scope, name));
+        }
     }
 
     fn import_index_of(&self, import_decl: &RepositoryImportDeclaration) -> Option<usize> {
         self.import_declarations.iter().position(|x| x == import_decl)
     }
 
-    fn take(self) -> (Vec<RepositoryImportDeclaration>, Vec<MethodDescription>) {
-        (self.import_declarations, self.links)
+    fn take(self) -> (Vec<RepositoryImportDeclaration>, Vec<MethodDescription>, Vec<DeprecatedMethod>) {
+        (self.import_declarations, self.links, self.deprecated_methods)
     }
 
     fn mut_navigation_links(&mut self) -> &mut Vec<MethodDescription> {
         &mut self.links
     }
 
+    fn mut_deprecated_methods(&mut self) -> &mut Vec<DeprecatedMethod> {
+        &mut self.deprecated_methods
+    }
+
 
 }
 
-pub fn get_file_structure(source_code: String, tree: Tree, path: String) -> InvocationStructure {
+pub fn get_file_structure(source_code: String, tree: Tree, path: String, options: &ParseOptions) -> InvocationStructure {
 
-    let mut invocation_data = InvocationData::new(source_code, path.clone());
+    let mut invocation_data = InvocationData::new(source_code, path.clone(), options.clone());
+    invocation_data.set_current_package(module_name(&path, options.source_roots()));
+
+    /* Local types are flow-insensitive: collected for the whole file before calls are linked */
+    let root_node = tree.root_node();
+    invocation_data.set_module_scope(root_node.id());
+    collect_local_types(root_node, root_node.id(), &mut invocation_data);
 
     traverse_tree(tree.walk(), & mut invocation_data);
 
     let (
         import_declarations,
-        method_descriptions
+        method_descriptions,
+        deprecated_methods
     ) = invocation_data.take();
 
-    return InvocationStructure::new(
+    let mut invocation_structure = InvocationStructure::new(
         import_declarations,
         method_descriptions,
         CodeType::type_codes(),
     );
+    invocation_structure.mark_deprecated_links(&deprecated_methods);
+
+    return invocation_structure;
 }
 
 fn traverse_tree(mut tree_cursor: TreeCursor, invocation_data: &mut InvocationData) {
@@ -152,12 +334,16 @@ fn traverse_tree(mut tree_cursor: TreeCursor, invocation_data: &mut InvocationDa
 }
 
 fn walk_into(node: Node, invocation_data: &mut InvocationData){
+    if invocation_data.options().extractors().deprecation() {
+        add_deprecated_method(node, invocation_data);
+    }
     match node.kind() {
         NodeKinds::IMPORT_STATEMENT => add_import_statement(node, invocation_data),
         NodeKinds::IMPORT_FROM_STATEMENT => add_import_from_statement(node, invocation_data),
         NodeKinds::CLASS_DEFINITION => add_class_definition(node, invocation_data),
-        NodeKinds::PARAMETERS => add_parameters(node, invocation_data),
         NodeKinds::CALL => add_function_call(node, invocation_data),
+        NodeKinds::ATTRIBUTE => add_attribute_access(node, invocation_data),
+        NodeKinds::IDENTIFIER => add_variable_reference(node, invocation_data),
         &_ => {}
     }
 }
@@ -184,21 +370,32 @@ fn add_import_from_statement(node: Node, invocation_data: &mut InvocationData) {
     }
 
     let module_node = unwrap_or_return!(package_node_opt);
-    let package_name = unwrap_or_empty_string!(get_node_value(&module_node, invocation_data));
+    let module_name = unwrap_or_empty_string!(get_node_value(&module_node, invocation_data));
+
+    /* `from ..core import Engine` is relative to package of this module */
+    let current_package = module_package(invocation_data.path(), invocation_data.options().source_roots());
+    let package_name = resolve_relative_import(&module_name, &current_package);
 
     let mut class_names = vec![];
     for class_node in class_nodes {
 
         let class_name = get_class_name_from_import_node(&class_node, invocation_data);
+        let imported_name = get_imported_name_from_import_node(&class_node, invocation_data);
         let position = get_position_in_line(&class_node);
         let line = get_line_number(&class_node);
-        class_names.push(class_name.clone());
-        create_package_description(package_name.clone(), class_name, line, position, invocation_data);
+
+        /* Name re-exported by package `__init__.py` belongs to the module defining it */
+        let defining_package = find_defining_module(&package_name, &imported_name, invocation_data)
+            .unwrap_or(package_name.clone());
+        class_names.push((defining_package.clone(), class_name.clone()));
+        create_package_description(defining_package, class_name, line, position, invocation_data);
     }
 
-    let import_declaration = get_or_create_import_decl(package_name.clone(), invocation_data);
-    for class_name in class_names {
-        import_declaration.add_class(class_name);
+    if class_names.is_empty() {
+        get_or_create_import_decl(package_name, invocation_data);
+    }
+    for (defining_package, class_name) in class_names {
+        get_or_create_import_decl(defining_package, invocation_data).add_class(class_name);
     }
 
 }
@@ -228,6 +425,7 @@ fn add_import_from_import_statement(node: Node, invocation_data: & mut Invocatio
     let import_declaration = get_or_create_import_decl(package_name.clone(),  invocation_data);
     import_declaration.add_class(class_name.clone());
 
+    invocation_data.mut_imported_modules().insert(class_name.clone());
     create_package_description(package_name, class_name, line, position, invocation_data);
 }
 
@@ -256,49 +454,242 @@ fn add_class_definition(node: Node, invocation_data: &mut InvocationData) {
 
 }
 
-fn add_parameters(node: Node, invocation_data: &mut InvocationData) {
-    for child in node.named_children(& mut node.walk()) {
-        add_parameter(child, invocation_data);
-    }
+/* Calls of deprecated functions are marked, `__init__` is named by its class. Deprecated class deprecates its
+   synthetic constructor, which takes any number of arguments as its parameters depend on fields and bases */
+fn add_deprecated_method(node: Node, invocation_data: &mut InvocationData) {
+
+    let deprecated_method = match node.kind() {
+        NodeKinds::FUNCTION_DEFINITION => {
+            let name_node = unwrap_or_return!(node.child_by_field_name(NodeNames::NAME));
+            let parameters_node = unwrap_or_return!(node.child_by_field_name(NodeNames::PARAMETERS));
+            if !is_deprecated(&node, invocation_data.source_code()) {
+                return;
+            }
+            let class_name = get_enclosing_class_name(&node, invocation_data);
+            let mut method_name = unwrap_or_empty_string!(get_node_value(&name_node, invocation_data));
+            if method_name == KeyWords::CONSTRUCTOR_NAME {
+                method_name = class_name.clone();
+            }
+            let (min_count_of_params, max_count_of_params) =
+                get_count_of_params_range(&parameters_node, invocation_data.source_code());
+            DeprecatedMethod::new(class_name, method_name, min_count_of_params, max_count_of_params)
+        }
+        NodeKinds::CLASS_DEFINITION => {
+            let name_node = unwrap_or_return!(node.child_by_field_name(NodeNames::NAME));
+            if !invocation_data.options().synthetic_constructors() || !is_deprecated(&node, invocation_data.source_code()) {
+                return;
+            }
+            let class_name = unwrap_or_empty_string!(get_node_value(&name_node, invocation_data));
+            DeprecatedMethod::new(class_name.clone(), class_name, 0, None)
+        }
+        _ => return
+    };
+
+    invocation_data.mut_deprecated_methods().push(deprecated_method);
 }
 
-fn add_parameter(node: Node, invocation_data: &mut InvocationData) {
+/* Class of method defined in class body, empty for functions */
+fn get_enclosing_class_name(node: &Node, invocation_data: &InvocationData) -> String {
 
-    let mut type_name = KeyWords::EMPTY_STRING.to_string();
-    let mut param_name = KeyWords::EMPTY_STRING.to_string();
+    let mut block_node = node.parent();
+    if block_node.is_some_and(|x| x.kind() == NodeKinds::DECORATED_DEFINITION) {
+        block_node = block_node.and_then(|x| x.parent());
+    }
 
-    match node.kind() {
-        NodeKinds::TYPED_DEFAULT_PARAMETER => {
-            param_name = get_name_from_typed_default_param(&node, invocation_data);
-            type_name = get_type_from_typed_param(&node, invocation_data);
-        }
-        NodeKinds::TYPED_PARAMETER => {
-            param_name = get_name_from_typed_param(&node, invocation_data);
-            type_name = get_type_from_typed_param(&node, invocation_data);
+    block_node
+        .and_then(|x| x.parent())
+        .filter(|x| x.kind() == NodeKinds::CLASS_DEFINITION)
+        .and_then(|x| x.child_by_field_name(NodeNames::NAME))
+        .and_then(|x| get_node_value(&x, invocation_data))
+        .unwrap_or_default()
+}
+
+/* Attribute read, neither called nor assigned */
+fn add_attribute_access(node: Node, invocation_data: &mut InvocationData) {
+
+    if let Some(parent) = node.parent() {
+        let is_called = is_field_of(&parent, NodeKinds::CALL, NodeNames::FUNCTION, &node);
+        let is_assigned = is_field_of(&parent, NodeKinds::ASSIGNMENT, NodeNames::LEFT, &node)
+            || is_field_of(&parent, NodeKinds::AUGMENTED_ASSIGNMENT, NodeNames::LEFT, &node);
+        if is_called || is_assigned {
+            return;
         }
-        &_ => {}
     }
 
-    if type_name != KeyWords::EMPTY_STRING && param_name != KeyWords::EMPTY_STRING {
-        add_var_description(&node, type_name, param_name, invocation_data);
+    if !add_property_access(node, invocation_data) {
+        add_module_attribute_access(node, invocation_data);
     }
+}
+
+/* `order.total` of property getter `total` */
+fn add_property_access(node: Node, invocation_data: &mut InvocationData) -> bool {
 
+    if invocation_data.properties().is_empty() {
+        return false;
+    }
+
+    let (object_node, attribute_node) = match (node.child_by_field_name(NodeNames::OBJECT), node.child_by_field_name(NodeNames::ATTRIBUTE)) {
+        (Some(object_node), Some(attribute_node)) if is_attribute_chain(&object_node) => (object_node, attribute_node),
+        _ => return false
+    };
+    let var_name = unwrap_or_empty_string!(get_node_value(&object_node, invocation_data));
+    let property_name = unwrap_or_empty_string!(get_node_value(&attribute_node, invocation_data));
+    let var_description = match find_local_type(&attribute_node, &var_name, invocation_data) {
+        Some(var_description) => var_description,
+        None => return false
+    };
+
+    let property = (var_description.get_class_name(), property_name.clone());
+    if !invocation_data.properties().contains(&property) {
+        return false;
+    }
+
+    let navigation_link = MethodDescription::new(
+        var_description.get_package_name(),
+        var_description.get_class_name(),
+        get_line_number(&attribute_node),
+        get_position_in_line(&attribute_node),
+        var_name,
+        property_name,
+        0,
+    );
+    invocation_data.mut_navigation_links().push(navigation_link);
+    true
 }
 
-fn add_var_description(node: &Node, class_name: String, param_name: String, invocation_data: &mut InvocationData) {
+/* `config.MAX_RETRIES` of imported module or `Color.RED` of class attribute */
+fn add_module_attribute_access(node: Node, invocation_data: &mut InvocationData) {
 
-    let mut var_description = VarDescription::default();
-    if let Some(package_desc) = find_package_by_class_name(&class_name, invocation_data) {
-        var_description.set_package_name(package_desc.get_package_name());
-        var_description.set_class_name(package_desc.get_class_name());
+    let object_node = unwrap_or_return!(node.child_by_field_name(NodeNames::OBJECT));
+    let attribute_node = unwrap_or_return!(node.child_by_field_name(NodeNames::ATTRIBUTE));
+    if object_node.kind() != NodeKinds::IDENTIFIER {
+        return;
+    }
+    let object_name = unwrap_or_empty_string!(get_node_value(&object_node, invocation_data));
+    let attribute_name = unwrap_or_empty_string!(get_node_value(&attribute_node, invocation_data));
+
+    /* Class references `models.User` are not variables */
+    if is_cap_words(&attribute_name) || is_local_name(&node, &object_name, invocation_data) {
+        return;
+    }
+
+    let (package_name, class_name) = if invocation_data.imported_modules().contains(&object_name) {
+        let package_description = unwrap_or_return!(find_imported_name(&object_name, invocation_data));
+        (package_description.get_package_name(), KeyWords::EMPTY_STRING.to_string())
+    } else if invocation_data.local_classes().contains(&object_name) {
+        (invocation_data.get_current_package(), object_name.clone())
     } else {
-        var_description.set_package_name(invocation_data.get_current_package());
-        var_description.set_class_name(class_name);
+        /* `from app import config` imports module, `from app import Config` imports class */
+        let package_description = unwrap_or_return!(find_imported_name(&object_name, invocation_data));
+        match is_cap_words(&object_name) {
+            true => (package_description.get_package_name(), object_name.clone()),
+            false => (format!("{}.{}", package_description.package_name(), object_name), KeyWords::EMPTY_STRING.to_string())
+        }
+    };
+
+    let navigation_link = MethodDescription::new(
+        package_name,
+        class_name,
+        get_line_number(&attribute_node),
+        get_position_in_line(&attribute_node),
+        object_name,
+        attribute_name,
+        0,
+    );
+    invocation_data.mut_navigation_links().push(navigation_link);
+}
+
+/* Use of module variable of the file or of name imported by `from config import MAX_RETRIES` */
+fn add_variable_reference(node: Node, invocation_data: &mut InvocationData) {
+
+    if !is_variable_reference(&node) {
+        return;
+    }
+    let name = unwrap_or_empty_string!(get_node_value(&node, invocation_data));
+    if is_local_name(&node, &name, invocation_data) {
+        return;
+    }
+
+    let is_attribute_object = node.parent().is_some_and(|x| x.kind() == NodeKinds::ATTRIBUTE);
+    let package_name = if invocation_data.module_variables().contains(&name) {
+        invocation_data.get_current_package()
+    } else {
+        /* Imported modules and classes are not variables, names used as attribute object may be modules */
+        if is_attribute_object || is_cap_words(&name) || invocation_data.imported_modules().contains(&name) {
+            return;
+        }
+        let package_description = unwrap_or_return!(find_imported_name(&name, invocation_data));
+        if package_description.package_name() == &invocation_data.get_current_package() {
+            return;
+        }
+        package_description.get_package_name()
+    };
+
+    let navigation_link = MethodDescription::new(
+        package_name,
+        KeyWords::EMPTY_STRING.to_string(),
+        get_line_number(&node),
+        get_position_in_line(&node),
+        KeyWords::EMPTY_STRING.to_string(),
+        name,
+        0,
+    );
+    invocation_data.mut_navigation_links().push(navigation_link);
+}
+
+/* Identifier read as value: not a definition, binding target, keyword, import or called function */
+fn is_variable_reference(node: &Node) -> bool {
+
+    let parent = match node.parent() {
+        Some(parent) => parent,
+        None => return false
+    };
+
+    /* `a, b = ...` targets */
+    let target_parent = match parent.kind() {
+        NodeKinds::PATTERN_LIST | NodeKinds::TUPLE_PATTERN => parent.parent().unwrap_or(parent),
+        &_ => parent
+    };
+    let target_node = if target_parent.id() == parent.id() { *node } else { parent };
+
+    match parent.kind() {
+        NodeKinds::ATTRIBUTE => is_field_of(&parent, NodeKinds::ATTRIBUTE, NodeNames::OBJECT, node),
+        NodeKinds::CALL => !is_field_of(&parent, NodeKinds::CALL, NodeNames::FUNCTION, node),
+        NodeKinds::KEYWORD_ARGUMENT => !is_field_of(&parent, NodeKinds::KEYWORD_ARGUMENT, NodeNames::NAME, node),
+        NodeKinds::DEFAULT_PARAMETER | NodeKinds::TYPED_DEFAULT_PARAMETER =>
+            !is_field_of(&parent, parent.kind(), NodeNames::NAME, node),
+        NodeKinds::WITH_ITEM => !is_field_of(&parent, NodeKinds::WITH_ITEM, NodeNames::ALIAS, node),
+        NodeKinds::FUNCTION_DEFINITION | NodeKinds::CLASS_DEFINITION | NodeKinds::PARAMETERS |
+        NodeKinds::TYPED_PARAMETER | NodeKinds::DOTTED_NAME | NodeKinds::ALIASED_IMPORT |
+        NodeKinds::IMPORT_FROM_STATEMENT | NodeKinds::GLOBAL_STATEMENT | NodeKinds::NONLOCAL_STATEMENT => false,
+        &_ => !is_field_of(&target_parent, NodeKinds::ASSIGNMENT, NodeNames::LEFT, &target_node)
+            && !is_field_of(&target_parent, NodeKinds::AUGMENTED_ASSIGNMENT, NodeNames::LEFT, &target_node)
+            && !is_field_of(&target_parent, NodeKinds::FOR_STATEMENT, NodeNames::LEFT, &target_node)
     }
-    var_description.set_line(get_line_number(&node));
-    var_description.set_position(get_position_in_line(&node));
-    var_description.set_var_name(param_name);
-    invocation_data.mut_var_descriptions().push(var_description);
+}
+
+fn is_field_of(parent: &Node, kind: &str, field_name: &str, node: &Node) -> bool {
+    parent.kind() == kind && parent.child_by_field_name(field_name).map(|x| x.id()) == Some(node.id())
+}
+
+/* Name bound in an enclosing function shadows module variables and imports */
+fn is_local_name(node: &Node, name: &str, invocation_data: &InvocationData) -> bool {
+
+    let scope_chain = get_scope_chain(node, invocation_data);
+    scope_chain.scopes
+        .iter()
+        .any(|x| invocation_data.local_names().contains(&(*x, name.to_string())))
+}
+
+fn find_imported_name<'time>(name: &str, invocation_data: &'time InvocationData) -> Option<&'time PackageDescription> {
+
+    invocation_data.package_descriptions()
+        .iter()
+        .find(|x| x.class_name() == name)
+}
+
+fn is_cap_words(name: &str) -> bool {
+    name.chars().next().is_some_and(|x| x.is_uppercase()) && name.chars().any(|x| x.is_lowercase())
 }
 
 fn add_function_call(node: Node, invocation_data: &mut InvocationData) {
@@ -327,7 +718,7 @@ fn add_link_from_var(node: &Node, var_name: &String, method_name: &String, count
                      invocation_data: &mut InvocationData) -> bool {
 
     if var_name == KeyWords::SELF_SPECIFIER { return false; }
-    let var_description_opt = find_var_desc_by_name(&var_name, invocation_data);
+    let var_description_opt = find_local_type(&node, &var_name, invocation_data);
     if var_description_opt.is_none() { return false; }
     let var_description = var_description_opt.unwrap();
 
@@ -349,7 +740,22 @@ fn add_link(node: &Node, var_name: &String, method_name: &String,
             count_of_params: usize, invocation_data: &mut InvocationData) -> bool {
 
     let mut navigation_link = MethodDescription::default();
-    if let Some(parent_description) = find_package_by_class_name(&var_name, invocation_data) {
+    let defining_package = find_package_by_class_name(&var_name, invocation_data)
+        .and_then(|x| find_defining_module(x.package_name(), method_name, invocation_data));
+
+    if let Some(defining_package) = defining_package {
+        /* `pkg.Engine()` of package re-exporting `Engine` links to the defining module */
+        navigation_link.set_package_name(defining_package);
+        navigation_link.set_class_name(method_name.clone());
+        navigation_link.set_var_name(var_name.clone());
+        navigation_link.set_method_name(method_name.clone());
+    } else if let Some(package_name) = find_constructor_package(&var_name, method_name, invocation_data) {
+        /* `Point(1, 2)` and `models.Order(1)` call constructor named by its class */
+        navigation_link.set_package_name(package_name);
+        navigation_link.set_class_name(method_name.clone());
+        navigation_link.set_var_name(var_name.clone());
+        navigation_link.set_method_name(method_name.clone());
+    } else if let Some(parent_description) = find_package_by_class_name(&var_name, invocation_data) {
         navigation_link.set_package_name(parent_description.get_package_name());
         navigation_link.set_class_name(parent_description.get_class_name());
         navigation_link.set_var_name(var_name.clone());
@@ -369,6 +775,483 @@ fn add_link(node: &Node, var_name: &String, method_name: &String,
     return true;
 }
 
+/* Package of class of the file or imported class, None for calls of functions and unknown classes */
+fn find_constructor_package(var_name: &String, method_name: &String, invocation_data: &InvocationData) -> Option<String> {
+
+    if !is_class_name(method_name, invocation_data) {
+        return None;
+    }
+
+    if var_name == KeyWords::SELF_SPECIFIER {
+        if invocation_data.local_classes().contains(method_name) {
+            return Some(invocation_data.get_current_package());
+        }
+        return find_imported_name(method_name, invocation_data).map(|x| x.get_package_name());
+    }
+
+    if invocation_data.imported_modules().contains(var_name) {
+        return find_imported_name(var_name, invocation_data).map(|x| x.get_package_name());
+    }
+
+    None
+}
+
+/* Local types */
+fn collect_local_types(node: Node, scope: usize, invocation_data: &mut InvocationData) {
+
+    for child in node.named_children(&mut node.walk()) {
+        match child.kind() {
+            NodeKinds::FUNCTION_DEFINITION => {
+                add_function_types(child, invocation_data);
+                if let Some(body_node) = child.child_by_field_name(NodeNames::BODY) {
+                    collect_local_types(body_node, child.id(), invocation_data);
+                }
+            }
+            NodeKinds::CLASS_DEFINITION => {
+                if let Some(name_node) = child.child_by_field_name(NodeNames::NAME) {
+                    let class_name = unwrap_or_empty_string!(get_node_value(&name_node, invocation_data));
+                    invocation_data.mut_local_classes().insert(class_name);
+                }
+                if let Some(body_node) = child.child_by_field_name(NodeNames::BODY) {
+                    collect_local_types(body_node, child.id(), invocation_data);
+                }
+            }
+            NodeKinds::ASSIGNMENT | NodeKinds::AUGMENTED_ASSIGNMENT => {
+                add_target_names(child.child_by_field_name(NodeNames::LEFT), scope, invocation_data);
+                add_named_tuple_class(child, invocation_data);
+                add_assignment_type(child, scope, invocation_data);
+                collect_local_types(child, scope, invocation_data);
+            }
+            NodeKinds::WITH_ITEM => {
+                add_target_names(child.child_by_field_name(NodeNames::ALIAS), scope, invocation_data);
+                add_with_item_type(child, scope, invocation_data);
+                collect_local_types(child, scope, invocation_data);
+            }
+            NodeKinds::FOR_STATEMENT => {
+                add_target_names(child.child_by_field_name(NodeNames::LEFT), scope, invocation_data);
+                add_for_statement_type(child, scope, invocation_data);
+                collect_local_types(child, scope, invocation_data);
+            }
+
+            /* Comprehension variables, lambda parameters and `except ... as e` are bound in their own scope */
+            NodeKinds::LIST_COMPREHENSION | NodeKinds::SET_COMPREHENSION | NodeKinds::DICTIONARY_COMPREHENSION
+            | NodeKinds::GENERATOR_EXPRESSION => {
+                for clause in child.named_children(&mut child.walk()).filter(|x| x.kind() == NodeKinds::FOR_IN_CLAUSE) {
+                    add_target_names(clause.child_by_field_name(NodeNames::LEFT), child.id(), invocation_data);
+                }
+                collect_local_types(child, child.id(), invocation_data);
+            }
+            NodeKinds::LAMBDA => {
+                if let Some(params_node) = child.child_by_field_name(NodeNames::PARAMETERS) {
+                    for param in params_node.named_children(&mut params_node.walk()) {
+                        add_target_names(get_parameter_name(&param), child.id(), invocation_data);
+                    }
+                }
+                collect_local_types(child, child.id(), invocation_data);
+            }
+            NodeKinds::EXCEPT_CLAUSE => {
+                let alias_node = child.children(&mut child.walk()).skip_while(|x| x.kind() != KeyWords::AS).nth(1);
+                add_target_names(alias_node, child.id(), invocation_data);
+                collect_local_types(child, scope, invocation_data);
+            }
+            &_ => collect_local_types(child, scope, invocation_data)
+        }
+    }
+}
+
+fn add_function_types(node: Node, invocation_data: &mut InvocationData) {
+
+    let is_method = is_in_class_body(&node);
+    if is_method && is_property(&node, invocation_data) {
+        add_property(&node, invocation_data);
+    }
+
+    /* Return annotations of module and nested functions, methods are called through their instance */
+    if let (Some(name_node), Some(return_node), false) =
+        (node.child_by_field_name(NodeNames::NAME), node.child_by_field_name(NodeNames::RETURN_TYPE), is_method) {
+
+        let name = unwrap_or_empty_string!(get_node_value(&name_node, invocation_data));
+        let return_type = unwrap_or_empty_string!(get_node_value(&return_node, invocation_data));
+        invocation_data.mut_return_types().insert(name, return_type);
+    }
+
+    let params_node = unwrap_or_return!(node.child_by_field_name(NodeNames::PARAMETERS));
+    for child in params_node.named_children(&mut params_node.walk()) {
+        add_target_names(get_parameter_name(&child), node.id(), invocation_data);
+
+        let param_name = match child.kind() {
+            NodeKinds::TYPED_DEFAULT_PARAMETER => get_name_from_typed_default_param(&child, invocation_data),
+            NodeKinds::TYPED_PARAMETER => get_name_from_typed_param(&child, invocation_data),
+            &_ => continue
+        };
+        let type_name = get_type_from_typed_param(&child, invocation_data);
+        add_local_type(node.id(), param_name, TypeSource::Annotation(type_name), invocation_data);
+    }
+}
+
+/* `Point = namedtuple("Point", "x y")` declares class `Point` */
+fn add_named_tuple_class(node: Node, invocation_data: &mut InvocationData) {
+
+    let left_node = unwrap_or_return!(node.child_by_field_name(NodeNames::LEFT));
+    let right_node = unwrap_or_return!(node.child_by_field_name(NodeNames::RIGHT));
+    let function_node = unwrap_or_return!(right_node.child_by_field_name(NodeNames::FUNCTION));
+    if left_node.kind() != NodeKinds::IDENTIFIER || right_node.kind() != NodeKinds::CALL {
+        return;
+    }
+
+    let function_name = unwrap_or_empty_string!(get_node_value(&function_node, invocation_data));
+    match function_name.rsplit('.').next() {
+        Some(KeyWords::NAMED_TUPLE_FACTORY) | Some(KeyWords::NAMED_TUPLE) => {
+            let class_name = unwrap_or_empty_string!(get_node_value(&left_node, invocation_data));
+            invocation_data.mut_local_classes().insert(class_name);
+        }
+        _ => {}
+    }
+}
+
+/* `x`, `x=1`, `x: int`, `*args` and `**kwargs` of function or lambda parameters */
+fn get_parameter_name<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+
+    match node.kind() {
+        NodeKinds::IDENTIFIER => Some(*node),
+        NodeKinds::DEFAULT_PARAMETER | NodeKinds::TYPED_DEFAULT_PARAMETER => node.child_by_field_name(NodeNames::NAME),
+        NodeKinds::TYPED_PARAMETER | NodeKinds::LIST_SPLAT_PATTERN | NodeKinds::DICTIONARY_SPLAT_PATTERN
+        => get_child_node_by_kind(node, NodeKinds::IDENTIFIER),
+        &_ => None
+    }
+}
+
+fn add_target_names(node: Option<Node>, scope: usize, invocation_data: &mut InvocationData) {
+
+    let node = unwrap_or_return!(node);
+    match node.kind() {
+        NodeKinds::IDENTIFIER => {
+            let name = unwrap_or_empty_string!(get_node_value(&node, invocation_data));
+            invocation_data.add_name(scope, name);
+        }
+        NodeKinds::PATTERN_LIST | NodeKinds::TUPLE_PATTERN => {
+            for child in node.named_children(&mut node.walk()) {
+                add_target_names(Some(child), scope, invocation_data);
+            }
+        }
+        &_ => {}
+    }
+}
+
+/* Property getter is typed as attribute of its class by return annotation */
+fn add_property(node: &Node, invocation_data: &mut InvocationData) {
+
+    let name_node = unwrap_or_return!(node.child_by_field_name(NodeNames::NAME));
+    let name = unwrap_or_empty_string!(get_node_value(&name_node, invocation_data));
+    let scope_chain = get_scope_chain(node, invocation_data);
+    let class_name = unwrap_or_return!(scope_chain.class_name.clone());
+
+    if let Some(return_node) = node.child_by_field_name(NodeNames::RETURN_TYPE) {
+        let return_type = unwrap_or_empty_string!(get_node_value(&return_node, invocation_data));
+        add_attribute_type(class_name.clone(), name.clone(), TypeSource::Annotation(return_type), scope_chain, invocation_data);
+    }
+    invocation_data.mut_properties().insert((class_name, name));
+}
+
+fn is_property(node: &Node, invocation_data: &InvocationData) -> bool {
+
+    let parent = match node.parent() {
+        Some(parent) if parent.kind() == NodeKinds::DECORATED_DEFINITION => parent,
+        _ => return false
+    };
+
+    parent
+        .named_children(&mut parent.walk())
+        .filter(|x| x.kind() == NodeKinds::DECORATOR)
+        .filter_map(|x| x.named_child(0))
+        .filter_map(|x| get_node_value(&x, invocation_data))
+        .any(|x| matches!(x.rsplit('.').next(), Some(KeyWords::PROPERTY) | Some(KeyWords::CACHED_PROPERTY)))
+}
+
+fn add_assignment_type(node: Node, scope: usize, invocation_data: &mut InvocationData) {
+
+    let left_node = unwrap_or_return!(node.child_by_field_name(NodeNames::LEFT));
+
+    /* Annotation wins over the assigned value, `x: Foo = make()` */
+    let source = match node.child_by_field_name(NodeNames::TYPE) {
+        Some(type_node) => TypeSource::Annotation(unwrap_or_empty_string!(get_node_value(&type_node, invocation_data))),
+        None => {
+            let right_node = unwrap_or_return!(node.child_by_field_name(NodeNames::RIGHT));
+            unwrap_or_return!(get_type_source_from_expression(&right_node, invocation_data))
+        }
+    };
+
+    match left_node.kind() {
+        NodeKinds::IDENTIFIER => {
+            let var_name = unwrap_or_empty_string!(get_node_value(&left_node, invocation_data));
+            let scope_chain = get_scope_chain(&node, invocation_data);
+            if let (true, Some(class_name)) = (is_in_class_body(&node), scope_chain.class_name.clone()) {
+                add_attribute_type(class_name, var_name.clone(), source.clone(), scope_chain, invocation_data);
+            }
+            add_local_type(scope, var_name, source, invocation_data);
+        }
+        NodeKinds::ATTRIBUTE => {
+            /* `self.repo = ...` */
+            let object_node = unwrap_or_return!(left_node.child_by_field_name(NodeNames::OBJECT));
+            let attribute_node = unwrap_or_return!(left_node.child_by_field_name(NodeNames::ATTRIBUTE));
+            if unwrap_or_empty_string!(get_node_value(&object_node, invocation_data)) != KeyWords::SELF_SPECIFIER {
+                return;
+            }
+            let attribute_name = unwrap_or_empty_string!(get_node_value(&attribute_node, invocation_data));
+            let scope_chain = get_scope_chain(&node, invocation_data);
+            let class_name = unwrap_or_return!(scope_chain.class_name.clone());
+            add_attribute_type(class_name, attribute_name, source, scope_chain, invocation_data);
+        }
+        &_ => {}
+    }
+}
+
+fn add_with_item_type(node: Node, scope: usize, invocation_data: &mut InvocationData) {
+
+    /* `with Foo() as f` */
+    let alias_node = unwrap_or_return!(node.child_by_field_name(NodeNames::ALIAS));
+    let value_node = unwrap_or_return!(node.child_by_field_name(NodeNames::VALUE));
+    if alias_node.kind() != NodeKinds::IDENTIFIER {
+        return;
+    }
+    let var_name = unwrap_or_empty_string!(get_node_value(&alias_node, invocation_data));
+    let source = unwrap_or_return!(get_type_source_from_expression(&value_node, invocation_data));
+    add_local_type(scope, var_name, source, invocation_data);
+}
+
+fn add_for_statement_type(node: Node, scope: usize, invocation_data: &mut InvocationData) {
+
+    let left_node = unwrap_or_return!(node.child_by_field_name(NodeNames::LEFT));
+    let right_node = unwrap_or_return!(node.child_by_field_name(NodeNames::RIGHT));
+    if left_node.kind() != NodeKinds::IDENTIFIER {
+        return;
+    }
+    let var_name = unwrap_or_empty_string!(get_node_value(&left_node, invocation_data));
+    let source = unwrap_or_return!(get_type_source_from_expression(&right_node, invocation_data));
+    add_local_type(scope, var_name, TypeSource::ElementOf(Box::new(source)), invocation_data);
+}
+
+fn add_local_type(scope: usize, var_name: String, source: TypeSource, invocation_data: &mut InvocationData) {
+
+    if var_name.is_empty() {
+        return;
+    }
+    invocation_data.mut_local_types().push(LocalType { scope, var_name, source });
+}
+
+fn add_attribute_type(class_name: String, attribute_name: String, source: TypeSource, scope_chain: ScopeChain,
+                      invocation_data: &mut InvocationData) {
+
+    if attribute_name.is_empty() {
+        return;
+    }
+    invocation_data.mut_attribute_types().push(AttributeType { class_name, attribute_name, source, scope_chain });
+}
+
+fn get_type_source_from_expression(node: &Node, invocation_data: &InvocationData) -> Option<TypeSource> {
+
+    match node.kind() {
+        NodeKinds::CALL => {
+            let function_node = node.child_by_field_name(NodeNames::FUNCTION)?;
+            match function_node.kind() {
+                NodeKinds::IDENTIFIER | NodeKinds::ATTRIBUTE =>
+                    Some(TypeSource::Call(get_node_value(&function_node, invocation_data)?)),
+                &_ => None
+            }
+        }
+        NodeKinds::IDENTIFIER | NodeKinds::ATTRIBUTE =>
+            Some(TypeSource::Variable(get_node_value(node, invocation_data)?)),
+        &_ => None
+    }
+}
+
+/* Variable of the innermost scope enclosing the call, class body is visible only to its own statements */
+fn find_local_type(node: &Node, var_name: &String, invocation_data: &InvocationData) -> Option<VarDescription> {
+
+    let scope_chain = get_scope_chain(node, invocation_data);
+    let type_name = find_local_type_name(&scope_chain, var_name, 0, invocation_data)?;
+    if type_name.is_empty() {
+        return None;
+    }
+
+    let mut var_description = VarDescription::default();
+    let (package_name, class_name) = get_package_of_type(&type_name, invocation_data);
+    var_description.set_package_name(package_name);
+    var_description.set_class_name(class_name);
+    var_description.set_line(get_line_number(node));
+    var_description.set_position(get_position_in_line(node));
+    var_description.set_var_name(var_name.clone());
+    Some(var_description)
+}
+
+fn find_local_type_name(scope_chain: &ScopeChain, var_name: &str, depth: usize,
+                        invocation_data: &InvocationData) -> Option<String> {
+
+    if depth > MAX_TYPE_DEPTH {
+        return None;
+    }
+
+    /* `self.repo.session` is typed attribute by attribute */
+    let mut segments = var_name.split('.');
+    let base_name = segments.next()?;
+    let mut type_name = if base_name == KeyWords::SELF_SPECIFIER {
+        scope_chain.class_name.clone()?
+    } else {
+        find_variable_type_name(scope_chain, base_name, depth, invocation_data)?
+    };
+
+    for attribute_name in segments {
+        type_name = find_attribute_type_name(&type_name, attribute_name, depth + 1, invocation_data)?;
+    }
+    Some(type_name)
+}
+
+fn find_variable_type_name(scope_chain: &ScopeChain, var_name: &str, depth: usize,
+                           invocation_data: &InvocationData) -> Option<String> {
+
+    for scope in scope_chain.scopes.iter() {
+        let type_name = invocation_data.local_types()
+            .iter()
+            .filter(|x| x.scope == *scope && x.var_name == var_name)
+            .find_map(|x| resolve_type_source(&x.source, scope_chain, depth, invocation_data));
+        if type_name.is_some() {
+            return type_name;
+        }
+    }
+
+    None
+}
+
+/* Attributes are collected for classes of the file only */
+fn find_attribute_type_name(type_name: &str, attribute_name: &str, depth: usize,
+                            invocation_data: &InvocationData) -> Option<String> {
+
+    let class_name = type_name.rsplit('.').next()?;
+    invocation_data.attribute_types()
+        .iter()
+        .filter(|x| x.class_name == class_name && x.attribute_name == attribute_name)
+        .find_map(|x| resolve_type_source(&x.source, &x.scope_chain, depth, invocation_data))
+}
+
+fn resolve_type_source(source: &TypeSource, scope_chain: &ScopeChain, depth: usize,
+                       invocation_data: &InvocationData) -> Option<String> {
+
+    let type_name = match source {
+        TypeSource::Annotation(type_name) => Some(type_name.clone()),
+        TypeSource::Call(function_name) => {
+            if let Some(return_type) = invocation_data.return_types().get(function_name) {
+                return Some(strip_optional(return_type));
+            }
+            match is_class_name(function_name, invocation_data) {
+                true => Some(function_name.clone()),
+                false => None
+            }
+        }
+        TypeSource::Variable(name) => find_local_type_name(scope_chain, name, depth + 1, invocation_data),
+        TypeSource::ElementOf(source) => {
+            let iterable_type = resolve_type_source(source, scope_chain, depth + 1, invocation_data)?;
+            get_first_type_argument(&iterable_type)
+        }
+    };
+    type_name.map(|x| strip_optional(&x))
+}
+
+fn is_in_class_body(node: &Node) -> bool {
+
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        match parent.kind() {
+            NodeKinds::CLASS_DEFINITION => return true,
+            NodeKinds::FUNCTION_DEFINITION => return false,
+            &_ => current = parent.parent()
+        }
+    }
+    false
+}
+
+fn get_scope_chain(node: &Node, invocation_data: &InvocationData) -> ScopeChain {
+
+    let mut scopes = vec![];
+    let mut class_name = None;
+    let mut current = node.parent();
+    while let Some(parent) = current {
+        match parent.kind() {
+            NodeKinds::FUNCTION_DEFINITION | NodeKinds::LAMBDA | NodeKinds::LIST_COMPREHENSION
+            | NodeKinds::SET_COMPREHENSION | NodeKinds::DICTIONARY_COMPREHENSION | NodeKinds::GENERATOR_EXPRESSION
+            | NodeKinds::EXCEPT_CLAUSE => scopes.push(parent.id()),
+            NodeKinds::CLASS_DEFINITION if class_name.is_none() => {
+                if scopes.is_empty() {
+                    scopes.push(parent.id());
+                }
+                class_name = parent.child_by_field_name(NodeNames::NAME)
+                    .and_then(|x| get_node_value(&x, invocation_data));
+            }
+            &_ => {}
+        }
+        if parent.parent().is_none() {
+            scopes.push(parent.id());
+        }
+        current = parent.parent();
+    }
+    ScopeChain { scopes, class_name }
+}
+
+/* Classes of the file, otherwise CapWords names of PEP 8 */
+fn is_class_name(function_name: &str, invocation_data: &InvocationData) -> bool {
+
+    let name = function_name.rsplit('.').next().unwrap_or_default();
+    invocation_data.local_classes().contains(name) || name.chars().next().is_some_and(|x| x.is_uppercase())
+}
+
+/* `Optional[Foo]` and forward reference `"Foo"` are typed as `Foo` */
+fn strip_optional(type_name: &str) -> String {
+
+    let type_name = type_name.trim().trim_matches(|c| c == '"' || c == '\'');
+    let optional_prefix = format!("{}[", KeyWords::OPTIONAL);
+    match type_name.strip_prefix(&optional_prefix).and_then(|x| x.strip_suffix(']')) {
+        Some(inner) => strip_optional(inner),
+        None => type_name.to_string()
+    }
+}
+
+/* `Foo` of `List[Foo]`, `Iterator[Foo]` or `dict[Foo, Bar]` */
+fn get_first_type_argument(type_name: &str) -> Option<String> {
+
+    let start = type_name.find('[')?;
+    let arguments = type_name[start + 1..].strip_suffix(']')?;
+    let mut level = 0;
+    for (index, c) in arguments.char_indices() {
+        match c {
+            '[' => level += 1,
+            ']' => level -= 1,
+            ',' if level == 0 => return Some(arguments[..index].trim().to_string()),
+            _ => {}
+        }
+    }
+    Some(arguments.trim().to_string())
+}
+
+fn get_package_of_type(type_name: &String, invocation_data: &InvocationData) -> (String, String) {
+
+    /* `models.User` of `import models` */
+    if let Some((qualifier, class_name)) = type_name.rsplit_once('.') {
+        let package_name = invocation_data.package_descriptions()
+            .iter()
+            .find(|x| x.class_name() == qualifier)
+            .map(|x| x.get_package_name())
+            .unwrap_or(qualifier.to_string());
+        return (package_name, class_name.to_string());
+    }
+
+    if invocation_data.local_classes().contains(type_name) {
+        return (invocation_data.get_current_package(), type_name.clone());
+    }
+    match find_package_by_class_name(type_name, invocation_data) {
+        Some(package_description) => (package_description.get_package_name(), type_name.clone()),
+        None => (invocation_data.get_current_package(), type_name.clone())
+    }
+}
+
 /* Helpers */
 fn create_package_description(package_name:String, class_name: String, line: usize,
                               position: usize, invocation_data: &mut InvocationData){
@@ -418,15 +1301,19 @@ fn function_name_var_name_from_function(node: &Node, invocation_data: &mut Invoc
 
         NodeKinds::ATTRIBUTE => {
 
-            if let Some(attribute_node) = node.child_by_field_name("attribute") {
+            if let Some(attribute_node) = node.child_by_field_name(NodeNames::ATTRIBUTE) {
                 function_name = unwrap_or_empty_string!(get_node_value(&attribute_node, invocation_data));
             }
 
-            if let Some(object_node) = node.child_by_field_name("object") {
+            if let Some(object_node) = node.child_by_field_name(NodeNames::OBJECT) {
                 match object_node.kind() {
                     NodeKinds::IDENTIFIER => {
                         var_name = unwrap_or_empty_string!(get_node_value(&object_node, invocation_data))
                     }
+                    /* Attribute chain `self.repo` of `self.repo.save()` */
+                    NodeKinds::ATTRIBUTE if is_attribute_chain(&object_node) => {
+                        var_name = unwrap_or_empty_string!(get_node_value(&object_node, invocation_data))
+                    }
                     &_ => {
                         var_name = KeyWords::EMPTY_STRING.to_string();
                     }
@@ -445,6 +1332,16 @@ fn function_name_var_name_from_function(node: &Node, invocation_data: &mut Invoc
 
 }
 
+fn is_attribute_chain(node: &Node) -> bool {
+
+    match node.kind() {
+        NodeKinds::IDENTIFIER => true,
+        NodeKinds::ATTRIBUTE => node.child_by_field_name(NodeNames::OBJECT)
+            .is_some_and(|x| is_attribute_chain(&x)),
+        &_ => false
+    }
+}
+
 fn find_package_by_class_name<'time>(class_name: &'time String, invocation_data: &'time InvocationData) -> Option<&'time PackageDescription> {
 
     let package_descriptions = invocation_data.package_descriptions();
@@ -482,7 +1379,7 @@ fn get_node_value(node: &Node, declaration_data: & InvocationData) -> Option<Str
             declaration_data.path())
         );
 
-    if node_string.len() < MAX_TOKEN_LENGTH {
+    if node_string.len() < declaration_data.options().max_token_length() {
         Some(node_string)
     } else {
         None
@@ -535,20 +1432,29 @@ fn get_class_name_from_import_node(node: &Node, invocation_data: &InvocationData
     return class_name;
 }
 
-fn get_child_node_by_kind<'time>(node: &'time Node, kind: &'time str) -> Option<Node<'time>> {
-    node
-        .children(&mut node.walk())
-        .filter(|x| { x.kind() == kind })
-        .next()
+/* Name in the imported module, `Engine` for `Engine as Motor` */
+fn get_imported_name_from_import_node(node: &Node, invocation_data: &InvocationData) -> String {
+
+    match node.kind() {
+        NodeKinds::DOTTED_NAME => unwrap_or_empty_string!(get_node_value(&node, invocation_data)),
+        NodeKinds::ALIASED_IMPORT => match node.child_by_field_name(NodeNames::NAME) {
+            Some(name_node) => unwrap_or_empty_string!(get_node_value(&name_node, invocation_data)),
+            None => KeyWords::EMPTY_STRING.to_string()
+        },
+        _ => KeyWords::EMPTY_STRING.to_string()
+    }
 }
 
-fn find_var_desc_by_name<'spec>(name: &'spec String, invocation_data: &'spec InvocationData) -> Option<&'spec VarDescription> {
+/* Symbol table maps qualified name `mypkg.Engine` of class re-exported by package to the file defining it */
+fn find_defining_module(package_name: &String, name: &String, invocation_data: &InvocationData) -> Option<String> {
 
-    invocation_data
-        .var_descriptions()
-        .iter()
-        .rev()
-        .find(|&x| x.class_name() == name)
+    if name.is_empty() {
+        return None;
+    }
+    let qualified_name = format!("{}.{}", package_name, name);
+    invocation_data.options().symbol_table()
+        .get(&qualified_name)
+        .map(|path| module_name(path, invocation_data.options().source_roots()))
 }
 
 
@@ -559,6 +1465,18 @@ mod python_method_invocation_tests {
     use std::fs;
     use tree_sitter::Parser;
 
+    fn parse_python_json(code: &str, path: &str, options: &ParseOptions) -> serde_json::Value {
+        let mut parser = Parser::new();
+        parser.set_language(tree_sitter_python::language()).expect("ERROR: Unable to load Python grammar");
+        let tree = parser.parse(code, None).unwrap();
+        let structure = get_file_structure(code.to_string(), tree, path.to_string(), options);
+        serde_json::to_value(&structure).unwrap()
+    }
+
+    fn find_method<'a>(json: &'a serde_json::Value, method_name: &str) -> &'a serde_json::Value {
+        json["methodDescriptions"].as_array().unwrap().iter().find(|x| x["methodName"] == method_name).unwrap()
+    }
+
     #[test]
     pub fn test_get_file_structure() {
 
@@ -572,8 +1490,238 @@ mod python_method_invocation_tests {
         let tree = parser.parse(&mut code, None).unwrap();
 
 
-        let fs = get_file_structure(code, tree, "test".to_string());
+        let fs = get_file_structure(code, tree, "test".to_string(), &ParseOptions::default());
         println!("{}", serde_json::to_string_pretty(&fs).unwrap())
 
     }
+
+    #[test]
+    pub fn test_get_module_name() {
+        let code = String::from(
+            "from mypkg.util import helper\n\
+             class Service:\n\
+             \x20   pass\n\
+             Service.create()\n"
+        );
+        let json = parse_python_json(&code, "src/mypkg/service.py", &ParseOptions::default());
+
+        assert_eq!(json["repositoryImportDeclarations"][0]["packageName"], "mypkg.util");
+        let link = find_method(&json, "create").clone();
+        /* Same module name as declarations of the file */
+        assert_eq!(link["descriptionData"]["packageName"], "mypkg.service");
+        assert_eq!(link["descriptionData"]["className"], "Service");
+    }
+
+    #[test]
+    pub fn test_get_relative_imports() {
+        let code = String::from(
+            "from ..core import Engine\n\
+             from . import utils\n\
+             import mypkg\n\
+             from mypkg import Wheel\n\
+             Engine.start()\n\
+             mypkg.Motor()\n"
+        );
+        let options = ParseOptions::from_json(r#"{
+            "symbolTable": { "mypkg.Wheel": "src/mypkg/parts/wheel.py", "mypkg.Motor": "src/mypkg/engine.py" }
+        }"#).unwrap();
+        let json = parse_python_json(&code, "src/mypkg/sub/service.py", &options);
+
+        let packages: Vec<String> = json["repositoryImportDeclarations"].as_array().unwrap().iter()
+            .map(|x| x["packageName"].as_str().unwrap().to_string()).collect();
+        assert!(packages.contains(&"mypkg.core".to_string()));
+        assert!(packages.contains(&"mypkg.sub".to_string()));
+        /* Re-exported by `mypkg/__init__.py`, attributed to the defining module */
+        assert!(packages.contains(&"mypkg.parts.wheel".to_string()));
+
+        let find = |method_name: &str| find_method(&json, method_name)["descriptionData"].clone();
+        assert_eq!(find("start")["packageName"], "mypkg.core");
+        assert_eq!(find("Motor")["packageName"], "mypkg.engine");
+        assert_eq!(find("Motor")["className"], "Motor");
+    }
+
+    #[test]
+    pub fn test_get_local_types() {
+        let code = String::from(
+            "from typing import List, Optional\n\
+             from shop.models import Order\n\
+             import shop.db as db\n\
+             def load() -> List[Order]:\n\
+             \x20   pass\n\
+             def find(key) -> Optional[\"Order\"]:\n\
+             \x20   pass\n\
+             class Cart:\n\
+             \x20   pass\n\
+             def checkout(items: List[Order], session: db.Session):\n\
+             \x20   cart = Cart()\n\
+             \x20   order: Order = find(1)\n\
+             \x20   other = order\n\
+             \x20   helper = make_helper()\n\
+             \x20   with db.Connection() as conn:\n\
+             \x20       conn.commit()\n\
+             \x20   for item in items:\n\
+             \x20       item.ship()\n\
+             \x20   for loaded in load():\n\
+             \x20       loaded.cancel()\n\
+             \x20   cart.add(order)\n\
+             \x20   other.pay()\n\
+             \x20   session.flush()\n\
+             \x20   helper.run()\n\
+             def unrelated():\n\
+             \x20   cart.clear()\n"
+        );
+        let json = parse_python_json(&code, "src/shop/service.py", &ParseOptions::default());
+        let find = |method_name: &str| {
+            let data = find_method(&json, method_name)["descriptionData"].clone();
+            (data["packageName"].as_str().unwrap().to_string(), data["className"].as_str().unwrap().to_string())
+        };
+
+        assert_eq!(find("commit"), ("shop.db".to_string(), "Connection".to_string()));
+        assert_eq!(find("ship"), ("shop.models".to_string(), "Order".to_string()));
+        assert_eq!(find("cancel"), ("shop.models".to_string(), "Order".to_string()));
+        assert_eq!(find("add"), ("shop.service".to_string(), "Cart".to_string()));
+        assert_eq!(find("pay"), ("shop.models".to_string(), "Order".to_string()));
+        assert_eq!(find("flush"), ("shop.db".to_string(), "Session".to_string()));
+        /* Functions without return annotation and variables of other functions are not typed */
+        assert_eq!(find("run").1, "");
+        assert_eq!(find("clear").1, "");
+    }
+
+    #[test]
+    pub fn test_get_self_attribute_types() {
+        let code = String::from(
+            "from dataclasses import dataclass\n\
+             from shop.db import Repository, Session\n\
+             @dataclass\n\
+             class Config:\n\
+             \x20   session: Session\n\
+             class Service:\n\
+             \x20   cache = Repository()\n\
+             \x20   def __init__(self, config: Config):\n\
+             \x20       self.repo = Repository()\n\
+             \x20       self.config = config\n\
+             \x20   def run(self):\n\
+             \x20       self.repo.save()\n\
+             \x20       self.config.session.flush()\n\
+             \x20       self.cache.clear()\n\
+             \x20       self.unknown.go()\n\
+             def main(service: Service):\n\
+             \x20   service.repo.load()\n"
+        );
+        let json = parse_python_json(&code, "src/shop/service.py", &ParseOptions::default());
+        let find = |method_name: &str| find_method(&json, method_name).clone();
+
+        assert_eq!(find("save")["descriptionData"]["packageName"], "shop.db");
+        assert_eq!(find("save")["descriptionData"]["className"], "Repository");
+        assert_eq!(find("save")["varName"], "self.repo");
+        assert_eq!(find("flush")["descriptionData"]["className"], "Session");
+        assert_eq!(find("clear")["descriptionData"]["className"], "Repository");
+        assert_eq!(find("load")["descriptionData"]["className"], "Repository");
+        assert_eq!(find("go")["descriptionData"]["className"], "");
+    }
+
+    #[test]
+    pub fn test_get_property_links() {
+        let code = String::from(
+            "class Customer:\n\
+             \x20   def notify(self):\n\
+             \x20       pass\n\
+             class Order:\n\
+             \x20   @property\n\
+             \x20   def customer(self) -> Customer:\n\
+             \x20       pass\n\
+             \x20   def pay(self):\n\
+             \x20       print(self.customer)\n\
+             \x20       self.customer = None\n\
+             def main(order: Order):\n\
+             \x20   order.customer.notify()\n\
+             \x20   order.missing\n"
+        );
+        let json = parse_python_json(&code, "src/shop/order.py", &ParseOptions::default());
+        let links: Vec<serde_json::Value> = json["methodDescriptions"].as_array().unwrap().iter()
+            .filter(|x| x["methodName"] == "customer").cloned().collect();
+
+        /* Read in `pay` and `main`, assignment is not a getter access */
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0]["descriptionData"]["className"], "Order");
+        assert_eq!(links[0]["countParamInput"], 0);
+        let notify = find_method(&json, "notify");
+        assert_eq!(notify["descriptionData"]["className"], "Customer");
+        assert!(!json["methodDescriptions"].as_array().unwrap().iter().any(|x| x["methodName"] == "missing"));
+    }
+
+    #[test]
+    pub fn test_get_variable_links() {
+        let code = String::from(
+            "import config\n\
+             from app import settings\n\
+             from app.limits import TIMEOUT\n\
+             registry = {}\n\
+             class Color:\n\
+             \x20   RED = 1\n\
+             def main(timeout=TIMEOUT):\n\
+             \x20   print(config.MAX_RETRIES, settings.DEBUG, Color.RED)\n\
+             \x20   registry[\"key\"] = timeout\n\
+             def other(registry):\n\
+             \x20   return registry\n\
+             logger = make_logger()\n\
+             def run(items):\n\
+             \x20   logger.info(registry.get(\"key\"))\n\
+             \x20   names = [registry for registry in items]\n\
+             \x20   pick = lambda registry, *rest: registry\n\
+             \x20   try:\n\
+             \x20       pass\n\
+             \x20   except KeyError as registry:\n\
+             \x20       print(registry)\n"
+        );
+        let json = parse_python_json(&code, "src/app/main.py", &ParseOptions::default());
+        let link = |name: &str| json["methodDescriptions"].as_array().unwrap().iter()
+            .filter(|x| x["methodName"] == name)
+            .map(|x| (x["descriptionData"]["packageName"].as_str().unwrap().to_string(),
+                      x["descriptionData"]["className"].as_str().unwrap().to_string(),
+                      x["descriptionData"]["line"].as_u64().unwrap()))
+            .collect::<Vec<(String, String, u64)>>();
+
+        assert_eq!(link("MAX_RETRIES"), vec![("config".to_string(), "".to_string(), 8)]);
+        assert_eq!(link("DEBUG"), vec![("app.settings".to_string(), "".to_string(), 8)]);
+        assert_eq!(link("RED"), vec![("app.main".to_string(), "Color".to_string(), 8)]);
+        assert_eq!(link("TIMEOUT"), vec![("app.limits".to_string(), "".to_string(), 7)]);
+        /* Parameter `registry` of `other`, comprehension variable, lambda parameter and exception name shadow
+           module variable, object of called attribute `registry.get()` is linked */
+        assert_eq!(link("registry"), vec![("app.main".to_string(), "".to_string(), 9),
+                                          ("app.main".to_string(), "".to_string(), 14)]);
+        assert_eq!(link("logger"), vec![("app.main".to_string(), "".to_string(), 14)]);
+        assert!(link("timeout").is_empty());
+    }
+
+    #[test]
+    pub fn test_get_constructor_links() {
+        let code = String::from(
+            "import shop.models\n\
+             from shop.orders import Order\n\
+             from collections import namedtuple\n\
+             Pair = namedtuple(\"Pair\", \"left right\")\n\
+             class Point:\n\
+             \x20   pass\n\
+             def main():\n\
+             \x20   Point(1, 2)\n\
+             \x20   Order(total=1)\n\
+             \x20   shop.models.Customer()\n\
+             \x20   Pair(1, 2)\n\
+             \x20   build()\n"
+        );
+        let json = parse_python_json(&code, "src/app/main.py", &ParseOptions::default());
+        let link = |name: &str| json["methodDescriptions"].as_array().unwrap().iter()
+            .find(|x| x["methodName"] == name)
+            .map(|x| (x["descriptionData"]["packageName"].as_str().unwrap().to_string(),
+                      x["descriptionData"]["className"].as_str().unwrap().to_string(),
+                      x["countParamInput"].as_u64().unwrap()))
+            .unwrap();
+
+        assert_eq!(link("Point"), ("app.main".to_string(), "Point".to_string(), 2));
+        assert_eq!(link("Order"), ("shop.orders".to_string(), "Order".to_string(), 1));
+        assert_eq!(link("Customer"), ("shop.models".to_string(), "Customer".to_string(), 0));
+        assert_eq!(link("Pair"), ("app.main".to_string(), "Pair".to_string(), 2));
+        assert_eq!(link("build"), ("app.main".to_string(), "".to_string(), 0));
+    }
 }